@@ -2,6 +2,8 @@
 @pushd "%~dp0.."
 @call :cargo test --all-features
 @call :cargo test
+@call :cargo build --no-default-features
+@call :cargo build --no-default-features --features alloc
 @call :cargo doc
 @popd
 @exit /b %ERRORS%
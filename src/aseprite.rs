@@ -0,0 +1,89 @@
+//! [Aseprite](https://www.aseprite.org/) slice import.
+//! 
+//! Aseprite's sprite sheet JSON export lists slices under `meta.slices`, with a `center` for 9-slices:
+//! 
+//! ```json
+//! { "meta": { "slices": [
+//!     { "name": "button", "keys": [{ "frame": 0, "bounds": { "x": 0, "y": 0, "w": 32, "h": 16 }, "center": { "x": 4, "y": 4, "w": 24, "h": 8 } }] }
+//! ] } }
+//! ```
+//! 
+//! `bounds` are relative to the sprite's canvas, and `center` is relative to `bounds`.  Add the position of the frame
+//! within the sheet (`frames[..].frame`) to sample the slice from a packed sheet.
+
+use super::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+/// A single 9-slice key of an Aseprite slice.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slice {
+    /// The name of the slice, as set by the artist.
+    pub name:   String,
+
+    /// The frame this key starts applying at.
+    pub frame:  u32,
+
+    /// The slice's `bounds` (`outer`) and `center` (`inner`), relative to the sprite canvas.
+    pub src:    ValidDimensions<i32>,
+}
+
+/// Load all 9-slices from Aseprite sprite sheet JSON.  Slices without a `center` (not 9-slices) are skipped.
+/// 
+/// ```rust
+/// let slices = nines::aseprite::from_json(r##"{ "frames": [], "meta": { "slices": [
+///     { "name": "button", "color": "#0000ffff", "keys": [
+///         { "frame": 0, "bounds": { "x": 0, "y": 0, "w": 32, "h": 16 }, "center": { "x": 4, "y": 4, "w": 24, "h": 8 } }
+///     ] }
+/// ] } }"##).unwrap();
+/// 
+/// assert_eq!(slices[0].name, "button");
+/// assert_eq!(slices[0].src.inner, nines::Rect { left: 4, right: 28, top: 4, bottom: 12 });
+/// ```
+/// 
+/// Returns `Err` if the JSON is malformed, or if a `center` doesn't fit within its `bounds`.
+pub fn from_json(json: &str) -> Result<Vec<Slice>, serde_json::Error> {
+    let sheet : Sheet = serde_json::from_str(json)?;
+    let mut slices = Vec::new();
+    for slice in sheet.meta.slices {
+        for key in slice.keys {
+            let center = match key.center { Some(c) => c, None => continue };
+            let outer = Rect::xywh(key.bounds.x, key.bounds.y, key.bounds.w, key.bounds.h);
+            let inner = Rect::xywh(outer.left + center.x, outer.top + center.y, center.w, center.h);
+            let src = Dimensions { outer, inner }.validate().map_err(serde::de::Error::custom)?;
+            slices.push(Slice { name: slice.name.clone(), frame: key.frame, src });
+        }
+    }
+    Ok(slices)
+}
+
+#[derive(Deserialize)] struct Sheet      { meta: Meta }
+#[derive(Deserialize)] struct Meta       { #[serde(default)] slices: Vec<AseSlice> }
+#[derive(Deserialize)] struct AseSlice   { name: String, keys: Vec<Key> }
+#[derive(Deserialize)] struct Key        { frame: u32, bounds: Xywh, center: Option<Xywh> }
+#[derive(Deserialize)] struct Xywh       { x: i32, y: i32, w: i32, h: i32 }
+
+
+
+#[test] fn aseprite_test() {
+    let slices = from_json(r##"{ "meta": { "app": "https://www.aseprite.org/", "slices": [
+        { "name": "panel", "color": "#0000ffff", "keys": [
+            { "frame": 0, "bounds": { "x": 10, "y": 20, "w": 30, "h": 40 }, "center": { "x": 5, "y": 6, "w": 20, "h": 28 } },
+            { "frame": 3, "bounds": { "x": 10, "y": 20, "w": 30, "h": 40 }, "center": { "x": 1, "y": 1, "w": 28, "h": 38 } }
+        ] },
+        { "name": "hitbox", "color": "#ff0000ff", "keys": [
+            { "frame": 0, "bounds": { "x": 0, "y": 0, "w": 8, "h": 8 } }
+        ] }
+    ] } }"##).unwrap();
+    assert_eq!(slices.len(), 2);
+    assert_eq!(slices[0].src.outer, Rect::xywh(10, 20, 30, 40));
+    assert_eq!(slices[0].src.inner, Rect::xywh(15, 26, 20, 28));
+    assert_eq!(slices[1].frame, 3);
+    assert_eq!(slices[1].src.borders(), Borders { left: 1, right: 1, top: 1, bottom: 1 });
+
+    assert!(from_json(r##"{ "meta": {} }"##).unwrap().is_empty());
+    assert!(from_json(r##"{ "meta": { "slices": [{ "name": "bad", "keys": [
+        { "frame": 0, "bounds": { "x": 0, "y": 0, "w": 8, "h": 8 }, "center": { "x": 4, "y": 4, "w": 8, "h": 1 } }
+    ] }] } }"##).is_err());
+}
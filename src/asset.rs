@@ -0,0 +1,136 @@
+//! Self-describing nine-patch skin definitions, for sharing skins between tools and games.
+//! 
+//! ```rust
+//! # #[cfg(feature = "json")] {
+//! use nines::*;
+//! 
+//! let asset : asset::NinePatchAsset<i32> = asset::from_json(r#"{
+//!     "texture":  "button.png",
+//!     "src":      { "outer": { "left": 0, "right": 32, "top": 0, "bottom": 32 },
+//!                   "inner": { "left": 8, "right": 24, "top": 8, "bottom": 24 } },
+//!     "style":    { "center": { "horizontal": "Round", "vertical": "Round" } }
+//! }"#).unwrap();
+//! 
+//! assert_eq!(asset.texture, "button.png");
+//! assert_eq!(asset.style.center.horizontal, Scale::Round);
+//! assert_eq!(asset.style.border, Style::default().border);
+//! # }
+//! ```
+
+use super::*;
+use alloc::string::String;
+
+/// A nine-patch skin:  which `texture` to sample, where its slices are (`src`), and how to scale them (`style`).
+/// 
+/// `src` is validated on deserialization, so a corrupt asset file is rejected up front.  `style` may be omitted or
+/// partially specified, with missing fields taken from [Style::default].
+/// 
+/// [Style::default]:   ../struct.Style.html#impl-Default
+#[derive(Clone, Debug, PartialEq)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NinePatchAsset<S: Scalar> {
+    pub src:        ValidDimensions<S>,
+    #[serde(default)]
+    pub style:      Style,
+    pub texture:    String,
+}
+
+impl<S: Scalar> NinePatchAsset<S> {
+    /// Create a [Layout] drawing this asset's `src` into `dst`.
+    /// 
+    /// [Layout]:   ../struct.Layout.html
+    pub fn layout(&self, dst: ValidDimensions<S>) -> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+        Layout { dst, src: self.src, style: self.style }
+    }
+}
+
+/// Load a [NinePatchAsset] from JSON.
+/// 
+/// [NinePatchAsset]:   struct.NinePatchAsset.html
+#[cfg(feature = "json")] pub fn from_json<S: Scalar + serde::de::DeserializeOwned>(json: &str) -> Result<NinePatchAsset<S>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Save a [NinePatchAsset] as (pretty-printed) JSON.
+/// 
+/// [NinePatchAsset]:   struct.NinePatchAsset.html
+#[cfg(feature = "json")] pub fn to_json<S: Scalar + serde::Serialize>(asset: &NinePatchAsset<S>) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(asset)
+}
+
+/// Load a [NinePatchAsset] from [RON](https://github.com/ron-rs/ron).
+/// 
+/// [NinePatchAsset]:   struct.NinePatchAsset.html
+#[cfg(feature = "ron")] pub fn from_ron<S: Scalar + serde::de::DeserializeOwned>(ron: &str) -> Result<NinePatchAsset<S>, serde_ron::error::SpannedError> {
+    serde_ron::from_str(ron)
+}
+
+/// Save a [NinePatchAsset] as (pretty-printed) [RON](https://github.com/ron-rs/ron).
+/// 
+/// [NinePatchAsset]:   struct.NinePatchAsset.html
+#[cfg(feature = "ron")] pub fn to_ron<S: Scalar + serde::Serialize>(asset: &NinePatchAsset<S>) -> Result<String, serde_ron::Error> {
+    serde_ron::ser::to_string_pretty(asset, Default::default())
+}
+
+/// Load a [NinePatchAsset] from [TOML](https://toml.io/).
+/// 
+/// [NinePatchAsset]:   struct.NinePatchAsset.html
+#[cfg(feature = "toml")] pub fn from_toml<S: Scalar + serde::de::DeserializeOwned>(toml: &str) -> Result<NinePatchAsset<S>, serde_toml::de::Error> {
+    serde_toml::from_str(toml)
+}
+
+/// Save a [NinePatchAsset] as (pretty-printed) [TOML](https://toml.io/).
+/// 
+/// [NinePatchAsset]:   struct.NinePatchAsset.html
+#[cfg(feature = "toml")] pub fn to_toml<S: Scalar + serde::Serialize>(asset: &NinePatchAsset<S>) -> Result<String, serde_toml::ser::Error> {
+    serde_toml::to_string_pretty(asset)
+}
+
+
+
+#[cfg(feature = "json")] #[test] fn asset_json_test() {
+    let asset = NinePatchAsset {
+        src:        Dimensions { outer: Rect::xywh(0.0, 0.0, 32.0, 32.0), inner: Rect::xywh(8.0, 8.0, 16.0, 16.0) }.validate().unwrap(),
+        style:      Style::new(Scale::Repeat(Partial::Split)),
+        texture:    String::from("skins/panel.png"),
+    };
+    let json = to_json(&asset).unwrap();
+    assert_eq!(from_json::<f32>(&json).unwrap(), asset);
+
+    let missing_style = r#"{ "texture": "a.png", "src": { "outer": { "left": 0, "right": 3, "top": 0, "bottom": 3 }, "inner": { "left": 1, "right": 2, "top": 1, "bottom": 2 } } }"#;
+    assert_eq!(from_json::<i32>(missing_style).unwrap().style, Style::default());
+
+    let invalid_src = r#"{ "texture": "a.png", "src": { "outer": { "left": 0, "right": 3, "top": 0, "bottom": 3 }, "inner": { "left": 1, "right": 4, "top": 1, "bottom": 2 } } }"#;
+    assert!(from_json::<i32>(invalid_src).is_err());
+}
+
+#[cfg(feature = "ron")] #[test] fn asset_ron_test() {
+    let asset : NinePatchAsset<i32> = from_ron(r#"(
+        texture: "panel.png",
+        src: (
+            outer: (left: 0, right: 32, top: 0, bottom: 32),
+            inner: (left: 8, right: 24, top: 8, bottom: 24),
+        ),
+        style: (center: (horizontal: Repeat(Split), vertical: Round), cells: [Top, Center]),
+    )"#).unwrap();
+    assert_eq!(asset.style.center, Axises { horizontal: Scale::Repeat(Partial::Split), vertical: Scale::Round });
+    assert_eq!(asset.style.cells, CellMask::from(Cell::Top) | Cell::Center);
+    assert_eq!(from_ron::<i32>(&to_ron(&asset).unwrap()).unwrap(), asset);
+}
+
+#[cfg(feature = "toml")] #[test] fn asset_toml_test() {
+    let asset : NinePatchAsset<i32> = from_toml(r#"
+        texture = "panel.png"
+
+        [src]
+        outer = { left = 0, right = 32, top = 0, bottom = 32 }
+        inner = { left = 8, right = 24, top = 8, bottom = 24 }
+
+        [style]
+        center = { horizontal = { Repeat = "Split" }, vertical = "Round" }
+        fill = false
+    "#).unwrap();
+    assert_eq!(asset.style.center, Axises { horizontal: Scale::Repeat(Partial::Split), vertical: Scale::Round });
+    assert!(!asset.style.fill);
+    assert_eq!(from_toml::<i32>(&to_toml(&asset).unwrap()).unwrap(), asset);
+}
@@ -0,0 +1,313 @@
+//! Named 9-slice regions of a texture atlas, e.g. a whole UI skin sheet.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A single named region of a [NineSliceAtlas].
+/// 
+/// [NineSliceAtlas]:   struct.NineSliceAtlas.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AtlasRegion {
+    /// The region's rectangle within the atlas texture.
+    pub frame:  ValidRect<i32>,
+
+    /// The region's 9-slice, in atlas texture coordinates.  `src.outer == frame`.
+    /// Regions without 9-slice data have no borders (`src.inner == frame`.)
+    pub src:    ValidDimensions<i32>,
+
+    /// The area to place content (e.g. text) within, in atlas texture coordinates.  Defaults to `src.inner`.
+    pub content: ValidRect<i32>,
+
+    /// The index of the atlas page (texture) this region is on.  See [NineSliceAtlas::pages].
+    /// 
+    /// [NineSliceAtlas::pages]:    struct.NineSliceAtlas.html#method.pages
+    pub page:   usize,
+}
+
+/// A texture atlas of named 9-slice regions.
+/// 
+/// ```rust
+/// # #[cfg(feature = "texturepacker")] {
+/// use nines::*;
+/// 
+/// let atlas = atlas::NineSliceAtlas::from_texturepacker_json(r#"{ "frames": {
+///     "button": { "frame": { "x": 32, "y": 0, "w": 32, "h": 16 }, "rotated": false, "borders": { "x": 4, "y": 4, "w": 24, "h": 8 } },
+///     "icon":   { "frame": { "x": 0,  "y": 0, "w": 16, "h": 16 }, "rotated": false }
+/// } }"#).unwrap();
+/// 
+/// let (src, frame) = atlas.get("button").unwrap();
+/// assert_eq!(frame, Rect::xywh(32, 0, 32, 16));
+/// assert_eq!(src.inner, Rect::xywh(36, 4, 24, 8));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NineSliceAtlas {
+    pages:   Vec<String>,
+    regions: BTreeMap<String, AtlasRegion>,
+}
+
+impl NineSliceAtlas {
+    /// Create an empty atlas.
+    #[must_use] pub fn new() -> Self { Self::default() }
+
+    /// Add a page (texture), returning its index for use in [AtlasRegion::page].
+    /// 
+    /// [AtlasRegion::page]:    struct.AtlasRegion.html#structfield.page
+    pub fn add_page(&mut self, texture: impl Into<String>) -> usize { self.pages.push(texture.into()); self.pages.len() - 1 }
+
+    /// The texture file names of each page, if known.
+    #[must_use] pub fn pages(&self) -> &[String] { &self.pages }
+
+    /// Add or replace a named region.
+    pub fn insert(&mut self, name: impl Into<String>, region: AtlasRegion) -> Option<AtlasRegion> { self.regions.insert(name.into(), region) }
+
+    /// Get a region's 9-slice and frame rectangle by name.
+    #[must_use] pub fn get(&self, name: &str) -> Option<(ValidDimensions<i32>, ValidRect<i32>)> {
+        self.regions.get(name).map(|r| (r.src, r.frame))
+    }
+
+    /// Get a region by name.
+    #[must_use] pub fn region(&self, name: &str) -> Option<&AtlasRegion> { self.regions.get(name) }
+
+    /// Iterate over all regions, sorted by name.
+    pub fn regions(&self) -> impl Iterator<Item = (&str, &AtlasRegion)> { self.regions.iter().map(|(n, r)| (n.as_str(), r)) }
+
+    #[must_use] pub fn len(&self) -> usize { self.regions.len() }
+    #[must_use] pub fn is_empty(&self) -> bool { self.regions.is_empty() }
+}
+
+impl NineSliceAtlas {
+    /// Load a [libGDX](https://libgdx.com/wiki/tools/texture-packer) `.atlas` file, in either the legacy (`xy:` +
+    /// `size:`) or current (`bounds:`) layout.
+    /// 
+    /// 9-slice borders are read from each region's `split: left, right, top, bottom`, and the content area from its
+    /// `pad: left, right, top, bottom` (in pixels from the region's edges.)  Returns `Err` on malformed files, rotated
+    /// 9-slice regions (unsupported), or splits/pads larger than their region.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let atlas = atlas::NineSliceAtlas::from_libgdx_atlas("
+    /// skin.png
+    /// size: 64, 64
+    /// format: RGBA8888
+    /// filter: Linear, Linear
+    /// repeat: none
+    /// button
+    ///   rotate: false
+    ///   xy: 2, 2
+    ///   size: 32, 16
+    ///   split: 4, 4, 3, 3
+    ///   pad: 6, 6, 2, 2
+    ///   orig: 32, 16
+    ///   offset: 0, 0
+    ///   index: -1
+    /// ").unwrap();
+    /// 
+    /// let button = atlas.region("button").unwrap();
+    /// assert_eq!(atlas.pages()[button.page], "skin.png");
+    /// assert_eq!(button.src.inner, Rect { left: 6, right: 30, top: 5, bottom: 15 });
+    /// assert_eq!(button.content,   Rect { left: 8, right: 28, top: 4, bottom: 16 });
+    /// ```
+    pub fn from_libgdx_atlas(text: &str) -> Result<Self, Error> {
+        let mut atlas = NineSliceAtlas::new();
+        let mut page = None;
+        let mut region : Option<LibGdxRegion> = None;
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                if let Some(r) = region.take() { r.finish(&mut atlas)?; }
+                page = None;
+                continue;
+            }
+
+            match (trimmed.split_once(':'), page) {
+                (None, None) => page = Some(atlas.add_page(trimmed)),
+                (None, Some(page)) => {
+                    if let Some(r) = region.take() { r.finish(&mut atlas)?; }
+                    region = Some(LibGdxRegion { name: String::from(trimmed), page, ..LibGdxRegion::default() });
+                },
+                (Some(_), None) => return err("Expected an atlas page name before its fields"),
+                (Some((key, value)), Some(_)) => {
+                    let r = match region.as_mut() { Some(r) => r, None => continue }; // page fields
+                    match key.trim() {
+                        "rotate"    => r.rotate = !matches!(value.trim(), "false" | "0"),
+                        "xy"        => { let [x, y] = ints(value)?; r.bounds[0] = x; r.bounds[1] = y; },
+                        "size"      => { let [w, h] = ints(value)?; r.bounds[2] = w; r.bounds[3] = h; },
+                        "bounds"    => r.bounds = ints(value)?,
+                        "split"     => r.split  = Some(ints(value)?),
+                        "pad"       => r.pad    = Some(ints(value)?),
+                        _           => {},
+                    }
+                },
+            }
+        }
+        if let Some(r) = region.take() { r.finish(&mut atlas)?; }
+        Ok(atlas)
+    }
+}
+
+#[derive(Default)] struct LibGdxRegion {
+    name:   String,
+    page:   usize,
+    rotate: bool,
+    bounds: [i32; 4],
+    split:  Option<[i32; 4]>,
+    pad:    Option<[i32; 4]>,
+}
+
+impl LibGdxRegion {
+    fn finish(self, atlas: &mut NineSliceAtlas) -> Result<(), Error> {
+        let [x, y, w, h] = self.bounds;
+        let frame = Rect::xywh(x, y, w, h).validate()?;
+        if self.rotate && self.split.is_some() { return err("Rotated 9-slice atlas regions are not supported"); }
+        let shrink = |[left, right, top, bottom]: [i32; 4]| frame.shrink(&Borders { left, right, top, bottom });
+        let src = Dimensions { outer: *frame, inner: self.split.map_or(*frame, shrink) }.validate()?;
+        let split = src.borders();
+        let or_split = |pad: i32, split: i32| if pad < 0 { split } else { pad }; // libGDX uses -1 for "same as split"
+        let content = match self.pad {
+            None                => src.inner,
+            Some([l, r, t, b])  => shrink([or_split(l, split.left), or_split(r, split.right), or_split(t, split.top), or_split(b, split.bottom)]),
+        };
+        let content = Dimensions { outer: *frame, inner: content }.validate()?.inner();
+        atlas.insert(self.name, AtlasRegion { frame, src, content, page: self.page });
+        Ok(())
+    }
+}
+
+/// Parse exactly `N` comma separated integers.
+fn ints<const N: usize>(value: &str) -> Result<[i32; N], Error> {
+    let mut r = [0; N];
+    let mut values = value.split(',');
+    for r in r.iter_mut() {
+        *r = match values.next().map(|v| v.trim().parse()) {
+            Some(Ok(v)) => v,
+            _           => return err("Expected comma separated integers in atlas field"),
+        };
+    }
+    if values.next().is_some() { return err("Too many comma separated integers in atlas field"); }
+    Ok(r)
+}
+
+#[cfg(feature = "texturepacker")] mod texturepacker {
+    use super::*;
+    use alloc::vec::Vec;
+    use serde::Deserialize;
+
+    impl NineSliceAtlas {
+        /// Load a [TexturePacker](https://www.codeandweb.com/texturepacker) JSON atlas, in either the "JSON (Hash)" or
+        /// "JSON (Array)" layout.
+        /// 
+        /// 9-slice data is read from each frame's `borders` (or `scale9Borders`) - the stretchable center, relative to
+        /// the frame.  Returns `Err` on malformed JSON, rotated 9-slice frames (unsupported), or borders outside their
+        /// frame.
+        pub fn from_texturepacker_json(json: &str) -> Result<Self, serde_json::Error> {
+            let sheet : Sheet = serde_json::from_str(json)?;
+            let frames : Vec<(String, Frame)> = match sheet.frames {
+                Frames::Hash(frames)    => frames.into_iter().collect(),
+                Frames::Array(frames)   => frames.into_iter().map(|f| (f.filename, f.frame)).collect(),
+            };
+
+            let mut atlas = NineSliceAtlas::new();
+            let page = atlas.add_page(sheet.meta.and_then(|m| m.image).unwrap_or_default());
+            for (name, frame) in frames {
+                let outer = Rect::xywh(frame.frame.x, frame.frame.y, frame.frame.w, frame.frame.h);
+                let inner = match frame.borders {
+                    Some(_) if frame.rotated    => return Err(serde::de::Error::custom("Rotated 9-slice frames are not supported")),
+                    Some(b)                     => Rect::xywh(outer.left + b.x, outer.top + b.y, b.w, b.h),
+                    None                        => outer,
+                };
+                let src = Dimensions { outer, inner }.validate().map_err(serde::de::Error::custom)?;
+                atlas.insert(name, AtlasRegion { frame: src.outer(), src, content: src.inner(), page });
+            }
+            Ok(atlas)
+        }
+    }
+
+    #[derive(Deserialize)] struct Sheet { frames: Frames, meta: Option<Meta> }
+    #[derive(Deserialize)] struct Meta { image: Option<String> }
+    #[derive(Deserialize)] #[serde(untagged)] enum Frames { Hash(BTreeMap<String, Frame>), Array(Vec<NamedFrame>) }
+    #[derive(Deserialize)] struct NamedFrame { filename: String, #[serde(flatten)] frame: Frame }
+    #[derive(Deserialize)] struct Frame { frame: Xywh, #[serde(default)] rotated: bool, #[serde(alias = "scale9Borders")] borders: Option<Xywh> }
+    #[derive(Deserialize)] struct Xywh { x: i32, y: i32, w: i32, h: i32 }
+}
+
+
+
+#[cfg(feature = "texturepacker")] #[test] fn atlas_texturepacker_test() {
+    let hash = NineSliceAtlas::from_texturepacker_json(r#"{ "frames": {
+        "panel": { "frame": { "x": 10, "y": 20, "w": 30, "h": 40 }, "rotated": false, "trimmed": false, "pivot": { "x": 0.5, "y": 0.5 }, "borders": { "x": 2, "y": 3, "w": 26, "h": 34 } },
+        "plain": { "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "rotated": true }
+    }, "meta": { "app": "https://www.codeandweb.com/texturepacker" } }"#).unwrap();
+    assert_eq!(hash.len(), 2);
+    let (src, frame) = hash.get("panel").unwrap();
+    assert_eq!(frame, Rect::xywh(10, 20, 30, 40));
+    assert_eq!(src.borders(), Borders { left: 2, top: 3, right: 2, bottom: 3 });
+    assert_eq!(hash.get("plain").unwrap().0.borders(), Borders { left: 0, top: 0, right: 0, bottom: 0 });
+    assert!(hash.get("missing").is_none());
+
+    let array = NineSliceAtlas::from_texturepacker_json(r#"{ "frames": [
+        { "filename": "panel", "frame": { "x": 10, "y": 20, "w": 30, "h": 40 }, "scale9Borders": { "x": 2, "y": 3, "w": 26, "h": 34 } },
+        { "filename": "plain", "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "rotated": true }
+    ] }"#).unwrap();
+    assert_eq!(array, hash);
+
+    assert!(NineSliceAtlas::from_texturepacker_json(r#"{ "frames": { "bad": { "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "borders": { "x": 4, "y": 0, "w": 8, "h": 8 } } } }"#).is_err());
+    assert!(NineSliceAtlas::from_texturepacker_json(r#"{ "frames": { "rot": { "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "rotated": true, "borders": { "x": 1, "y": 1, "w": 6, "h": 6 } } } }"#).is_err());
+}
+
+#[test] fn atlas_libgdx_test() {
+    let atlas = NineSliceAtlas::from_libgdx_atlas("
+page1.png
+size: 64, 64
+format: RGBA8888
+filter: Nearest, Nearest
+repeat: none
+plain
+  rotate: true
+  xy: 40, 0
+  size: 8, 16
+  orig: 8, 16
+  offset: 0, 0
+  index: -1
+panel
+  rotate: false
+  xy: 0, 10
+  size: 30, 20
+  split: 2, 3, 4, 5
+  pad: -1, -1, 1, 1
+  orig: 30, 20
+  offset: 0, 0
+  index: -1
+
+page2.png
+size:32,32
+filter:Linear,Linear
+modern
+bounds:1,2,10,10
+split:1,1,1,1
+").unwrap();
+
+    assert_eq!(atlas.pages(), ["page1.png", "page2.png"]);
+    assert_eq!(atlas.len(), 3);
+
+    let plain = atlas.region("plain").unwrap();
+    assert_eq!(plain.frame, Rect::xywh(40, 0, 8, 16));
+    assert_eq!(plain.src.inner, plain.frame);
+
+    let panel = atlas.region("panel").unwrap();
+    assert_eq!(panel.src.borders(), Borders { left: 2, right: 3, top: 4, bottom: 5 });
+    assert_eq!(panel.content, Rect { left: 2, right: 27, top: 11, bottom: 29 });
+    assert_eq!(panel.page, 0);
+
+    let modern = atlas.region("modern").unwrap();
+    assert_eq!(modern.page, 1);
+    assert_eq!(modern.src.inner, Rect::xywh(2, 3, 8, 8));
+
+    assert!(NineSliceAtlas::from_libgdx_atlas("p.png\nbad\n  xy: 0, 0\n  size: 4, 4\n  split: 3, 3, 0, 0\n").is_err());
+    assert!(NineSliceAtlas::from_libgdx_atlas("p.png\nbad\n  xy: 0, 0, 0\n").is_err());
+    assert!(NineSliceAtlas::from_libgdx_atlas("p.png\nrot\n  rotate: true\n  xy: 0, 0\n  size: 4, 4\n  split: 1, 1, 1, 1\n").is_err());
+}
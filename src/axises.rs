@@ -0,0 +1,85 @@
+use super::*;
+use core::fmt::Debug;
+use core::ops::{Add, Sub, Mul, Div};
+
+/// A { horizontal, vertical } pair.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Axises<V: Debug> {
+    /// A horizontal (x-axis) value
+    pub horizontal: V,
+
+    /// A vertical (y-axis) value
+    pub vertical:   V,
+}
+
+impl<V: Debug> Axises<V> {
+    /// The same value on both axises.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Axises::splat(Scale::Round), Axises { horizontal: Scale::Round, vertical: Scale::Round });
+    /// ```
+    #[must_use] pub fn splat(value: V) -> Self where V: Clone { Self { horizontal: value.clone(), vertical: value } }
+
+    /// Convert both values with `f`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let size = Axises { horizontal: 3, vertical: 4 };
+    /// assert_eq!(size.map(|v| v as f32 * 0.5), Axises { horizontal: 1.5, vertical: 2.0 });
+    /// ```
+    #[must_use] pub fn map<T: Debug>(self, mut f: impl FnMut(V) -> T) -> Axises<T> {
+        Axises { horizontal: f(self.horizontal), vertical: f(self.vertical) }
+    }
+
+    /// Pair up the values of two `Axises`, axis by axis.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let scale = Axises { horizontal: Scale::Round, vertical: Scale::Stretch };
+    /// let len   = Axises { horizontal: 10, vertical: 20 };
+    /// assert_eq!(scale.zip(len).map(|(scale, len)| scale.tile_count(len, 3)), Axises { horizontal: 3, vertical: 1 });
+    /// ```
+    #[must_use] pub fn zip<U: Debug>(self, other: Axises<U>) -> Axises<(V, U)> {
+        Axises { horizontal: (self.horizontal, other.horizontal), vertical: (self.vertical, other.vertical) }
+    }
+
+    /// Transpose:  swap the horizontal and vertical values, e.g. to reuse horizontal logic for a vertical layout.
+    #[must_use] pub fn swap(self) -> Self { Self { horizontal: self.vertical, vertical: self.horizontal } }
+
+    /// `[horizontal, vertical]`
+    #[must_use] pub fn as_array(&self) -> [V; 2] where V: Copy { [self.horizontal, self.vertical] }
+}
+
+impl<V: Debug> From<[V; 2]> for Axises<V> { fn from(value: [V; 2]) -> Self { let [horizontal, vertical] = value; Self { horizontal, vertical } } }
+impl<V: Debug> From<Axises<V>> for [V; 2] { fn from(value: Axises<V>) -> Self { [value.horizontal, value.vertical] } }
+
+impl<S: Scalar> Add for Axises<S> { type Output = Self; fn add(self, rhs: Self) -> Self { Self { horizontal: self.horizontal + rhs.horizontal, vertical: self.vertical + rhs.vertical } } }
+impl<S: Scalar> Sub for Axises<S> { type Output = Self; fn sub(self, rhs: Self) -> Self { Self { horizontal: self.horizontal - rhs.horizontal, vertical: self.vertical - rhs.vertical } } }
+impl<S: RatioScalar> Mul for Axises<S> { type Output = Self; fn mul(self, rhs: Self) -> Self { Self { horizontal: self.horizontal * rhs.horizontal, vertical: self.vertical * rhs.vertical } } }
+impl<S: RatioScalar> Div for Axises<S> { type Output = Self; fn div(self, rhs: Self) -> Self { Self { horizontal: self.horizontal / rhs.horizontal, vertical: self.vertical / rhs.vertical } } }
+impl<S: RatioScalar> Mul<S> for Axises<S> { type Output = Self; fn mul(self, rhs: S) -> Self { self.map(|v| v * rhs) } }
+impl<S: RatioScalar> Div<S> for Axises<S> { type Output = Self; fn div(self, rhs: S) -> Self { self.map(|v| v / rhs) } }
+
+
+
+#[test] fn axises_test() {
+    let a = Axises { horizontal: 6, vertical: 8 };
+    let b = Axises::from([2, 4]);
+    assert_eq!(a + b, Axises { horizontal: 8, vertical: 12 });
+    assert_eq!(a - b, Axises { horizontal: 4, vertical: 4 });
+    assert_eq!(a * b, Axises { horizontal: 12, vertical: 32 });
+    assert_eq!(a / b, Axises { horizontal: 3, vertical: 2 });
+    assert_eq!(a * 2, Axises { horizontal: 12, vertical: 16 });
+    assert_eq!(a / 2, Axises { horizontal: 3, vertical: 4 });
+    assert_eq!(a.swap(), Axises { horizontal: 8, vertical: 6 });
+    assert_eq!(a.swap().swap(), a);
+    assert_eq!(a.as_array(), [6, 8]);
+    assert_eq!(<[i32; 2]>::from(a), [6, 8]);
+    assert_eq!(Axises::splat(1.5), Axises { horizontal: 1.5, vertical: 1.5 });
+    assert_eq!(a.zip(b).map(|(a, b)| a % b), Axises { horizontal: 0, vertical: 0 });
+}
@@ -0,0 +1,608 @@
+//! Software 9-slice blitting between raw pixel buffers, for users with a framebuffer and no rendering crate.
+//! 
+//! ```rust
+//! use nines::*;
+//! use nines::blit::{Image, ImageMut};
+//! 
+//! // A 3x3 frame:  opaque white border, transparent center
+//! let mut frame = [255u8; 3 * 3 * 4];
+//! frame[4*4 .. 5*4].copy_from_slice(&[0, 0, 0, 0]);
+//! let src = Image::new(&frame[..], 3, 3, 3 * 4).unwrap();
+//! 
+//! let mut pixels = vec![0u8; 8 * 6 * 4];
+//! let mut dst = ImageMut::new(&mut pixels[..], 8, 6, 8 * 4).unwrap();
+//! 
+//! let layout = Layout {
+//!     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+//!     dst: Dimensions { outer: Rect::xywh(1, 1, 6, 4), inner: Rect::xywh(2, 2, 4, 2) },
+//!     style: Style::default(),
+//! }.validate().unwrap();
+//! blit::blit(&layout, &src, &mut dst).unwrap();
+//! 
+//! assert_eq!(dst.pixel(1, 1), [255, 255, 255, 255]);  // border
+//! assert_eq!(dst.pixel(3, 3), [0, 0, 0, 0]);          // center
+//! assert_eq!(dst.pixel(0, 0), [0, 0, 0, 0]);          // untouched
+//! ```
+
+use crate::*;
+
+/// The byte layout of a single pixel in an [Image] or [ImageMut].
+/// 
+/// Pixels are converted to and from RGBA8 as they're read and written.  Formats without alpha read as opaque.
+/// 
+/// [Image]:    struct.Image.html
+/// [ImageMut]: struct.ImageMut.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// 4 bytes:  red, green, blue, alpha.
+    Rgba8,
+
+    /// 4 bytes:  blue, green, red, alpha - e.g. Win32 DIBs, X11 images, most framebuffer devices.
+    Bgra8,
+
+    /// 3 bytes:  red, green, blue.
+    Rgb8,
+
+    /// 3 bytes:  blue, green, red - e.g. 24-bit Win32 DIBs.
+    Bgr8,
+
+    /// 1 byte:  luma.  Written using Rec. 601 weights.
+    Gray8,
+}
+
+impl core::default::Default for PixelFormat { fn default() -> Self { PixelFormat::Rgba8 } }
+
+impl PixelFormat {
+    /// The number of bytes per pixel.
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelFormat::Rgba8 | PixelFormat::Bgra8 => 4,
+            PixelFormat::Rgb8  | PixelFormat::Bgr8  => 3,
+            PixelFormat::Gray8                      => 1,
+        }
+    }
+
+    fn read(self, p: &[u8]) -> [u8; 4] {
+        match self {
+            PixelFormat::Rgba8  => [p[0], p[1], p[2], p[3]],
+            PixelFormat::Bgra8  => [p[2], p[1], p[0], p[3]],
+            PixelFormat::Rgb8   => [p[0], p[1], p[2], 255],
+            PixelFormat::Bgr8   => [p[2], p[1], p[0], 255],
+            PixelFormat::Gray8  => [p[0], p[0], p[0], 255],
+        }
+    }
+
+    fn write(self, p: &mut [u8], [r, g, b, a]: [u8; 4]) {
+        match self {
+            PixelFormat::Rgba8  => p[.. 4].copy_from_slice(&[r, g, b, a]),
+            PixelFormat::Bgra8  => p[.. 4].copy_from_slice(&[b, g, r, a]),
+            PixelFormat::Rgb8   => p[.. 3].copy_from_slice(&[r, g, b]),
+            PixelFormat::Bgr8   => p[.. 3].copy_from_slice(&[b, g, r]),
+            PixelFormat::Gray8  => p[0] = ((77 * u32::from(r) + 150 * u32::from(g) + 29 * u32::from(b) + 128) >> 8) as u8,
+        }
+    }
+}
+
+/// A read-only pixel buffer.
+#[derive(Clone, Copy, Debug)]
+pub struct Image<'a> {
+    data:   &'a [u8],
+    geo:    Geometry,
+}
+
+/// A writable pixel buffer.
+#[derive(Debug)]
+pub struct ImageMut<'a> {
+    data:   &'a mut [u8],
+    geo:    Geometry,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Geometry {
+    width:  usize,
+    height: usize,
+    stride: isize,
+    origin: usize,
+    format: PixelFormat,
+}
+
+impl<'a> Image<'a> {
+    /// Wrap `width` × `height` RGBA8 pixels, with rows starting every `stride` bytes.
+    /// 
+    /// Returns `Err` if `stride` is smaller than a row, or if `data` is too small.
+    pub fn new(data: &'a [u8], width: usize, height: usize, stride: usize) -> Result<Self, Error> {
+        Self::with_format(data, width, height, stride_isize(stride)?, PixelFormat::Rgba8)
+    }
+
+    /// Wrap `width` × `height` pixels of `format`, with rows starting every `stride` bytes.
+    /// 
+    /// A negative `stride` describes a bottom-up image:  row 0 is the *last* row of `data`, as with Win32 DIBs.
+    /// 
+    /// Returns `Err` if `stride` is smaller than a row, or if `data` is too small.
+    pub fn with_format(data: &'a [u8], width: usize, height: usize, stride: isize, format: PixelFormat) -> Result<Self, Error> {
+        Ok(Self { geo: Geometry::new(data.len(), width, height, stride, format)?, data })
+    }
+
+    #[must_use] pub fn width(&self)  -> usize { self.geo.width }
+    #[must_use] pub fn height(&self) -> usize { self.geo.height }
+    #[must_use] pub fn stride(&self) -> isize { self.geo.stride }
+    #[must_use] pub fn format(&self) -> PixelFormat { self.geo.format }
+
+    /// The RGBA of the pixel at `[x, y]`.  Panics if out of bounds.
+    #[must_use] pub fn pixel(&self, x: usize, y: usize) -> [u8; 4] { self.geo.format.read(&self.data[self.geo.offset(x, y) ..]) }
+}
+
+impl<'a> ImageMut<'a> {
+    /// Wrap `width` × `height` RGBA8 pixels, with rows starting every `stride` bytes.
+    /// 
+    /// Returns `Err` if `stride` is smaller than a row, or if `data` is too small.
+    pub fn new(data: &'a mut [u8], width: usize, height: usize, stride: usize) -> Result<Self, Error> {
+        Self::with_format(data, width, height, stride_isize(stride)?, PixelFormat::Rgba8)
+    }
+
+    /// Wrap `width` × `height` pixels of `format`, with rows starting every `stride` bytes.
+    /// 
+    /// A negative `stride` describes a bottom-up image:  row 0 is the *last* row of `data`, as with Win32 DIBs.
+    /// 
+    /// Returns `Err` if `stride` is smaller than a row, or if `data` is too small.
+    pub fn with_format(data: &'a mut [u8], width: usize, height: usize, stride: isize, format: PixelFormat) -> Result<Self, Error> {
+        Ok(Self { geo: Geometry::new(data.len(), width, height, stride, format)?, data })
+    }
+
+    #[must_use] pub fn width(&self)  -> usize { self.geo.width }
+    #[must_use] pub fn height(&self) -> usize { self.geo.height }
+    #[must_use] pub fn stride(&self) -> isize { self.geo.stride }
+    #[must_use] pub fn format(&self) -> PixelFormat { self.geo.format }
+
+    /// The RGBA of the pixel at `[x, y]`.  Panics if out of bounds.
+    #[must_use] pub fn pixel(&self, x: usize, y: usize) -> [u8; 4] { self.geo.format.read(&self.data[self.geo.offset(x, y) ..]) }
+
+    /// Overwrite the pixel at `[x, y]` with `rgba`, converted to this image's format.  Panics if out of bounds.
+    pub fn set_pixel(&mut self, x: usize, y: usize, rgba: [u8; 4]) { let o = self.geo.offset(x, y); self.geo.format.write(&mut self.data[o ..], rgba) }
+
+    /// A read-only view of the same pixels.
+    #[must_use] pub fn as_image(&self) -> Image<'_> { Image { data: self.data, geo: self.geo } }
+}
+
+/// How [blit_with] samples `src` pixels for scaled tiles.
+/// 
+/// [blit_with]:    fn.blit_with.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Filter {
+    /// Use the single closest source pixel.  Crisp, but blocky when stretched.
+    Nearest,
+
+    /// Linearly interpolate between the 2x2 closest source pixels of the same tile.  Unscaled axes remain exact.
+    Bilinear,
+}
+
+impl core::default::Default for Filter { fn default() -> Self { Filter::Nearest } }
+
+/// How [blit_with] combines (tinted) `src` pixels with existing `dst` pixels.
+/// 
+/// [blit_with]:    fn.blit_with.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Blend {
+    /// Overwrite `dst`, alpha included.
+    Copy,
+
+    /// Composite straight (non-premultiplied) alpha `src` over straight alpha `dst`.
+    AlphaBlend,
+
+    /// Composite premultiplied alpha `src` over premultiplied alpha `dst`.  `tint` should also be premultiplied.
+    PremultipliedBlend,
+}
+
+impl core::default::Default for Blend { fn default() -> Self { Blend::Copy } }
+
+/// Options for [blit_with].
+/// 
+/// [blit_with]:    fn.blit_with.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Options {
+    pub filter: Filter,
+
+    /// Multiplied into every sampled `src` pixel, per channel.  `[255, 255, 255, 255]` (the default) leaves pixels unchanged.
+    pub tint:   [u8; 4],
+    pub blend:  Blend,
+}
+
+impl core::default::Default for Options { fn default() -> Self { Options { filter: Filter::Nearest, tint: [255; 4], blend: Blend::Copy } } }
+
+/// Copy `src`, 9-sliced per `layout`, into `dst` with nearest-neighbor sampling, honoring `layout.style`.
+/// 
+/// `layout.src` is in `src` pixels, `layout.dst` in `dst` pixels.  Destination pixels outside `dst` are clipped.
+/// 
+/// Returns `Err` (without writing anything) if `layout.src.outer` extends outside of `src`.
+pub fn blit(layout: &Layout<ValidDimensions<i32>, ValidDimensions<i32>>, src: &Image, dst: &mut ImageMut) -> Result<(), Error> {
+    blit_with(layout, src, dst, &Options::default())
+}
+
+/// Copy `src`, 9-sliced per `layout`, into `dst` as configured by `options`.  See [blit].
+/// 
+/// Bilinear filtering never samples across tile boundaries, so neighboring cells don't bleed into each other.
+/// 
+/// [blit]:     fn.blit.html
+pub fn blit_with(layout: &Layout<ValidDimensions<i32>, ValidDimensions<i32>>, src: &Image, dst: &mut ImageMut, options: &Options) -> Result<(), Error> {
+    let all = Rect { left: 0, top: 0, right: clamp_i32(dst.width()), bottom: clamp_i32(dst.height()) };
+    blit_clipped(layout, src, dst, options, &all)
+}
+
+/// Redraw only the `damage`d area of `dst`, as [blit_with] would draw it.
+/// 
+/// Tiles that don't intersect `damage` are skipped entirely, and the rest are clipped to it - handy for CPU-rendered UIs that redraw small regions of large panels.
+/// 
+/// ```rust
+/// # use nines::*;
+/// # use nines::blit::*;
+/// # let frame = [255u8; 3 * 3 * 4];
+/// # let src = Image::new(&frame[..], 3, 3, 3 * 4).unwrap();
+/// # let mut pixels = vec![0u8; 8 * 6 * 4];
+/// # let mut dst = ImageMut::new(&mut pixels[..], 8, 6, 8 * 4).unwrap();
+/// # let layout = Layout {
+/// #     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+/// #     dst: Dimensions { outer: Rect::xywh(0, 0, 8, 6), inner: Rect::xywh(1, 1, 6, 4) },
+/// #     style: Style::default(),
+/// # }.validate().unwrap();
+/// let damage = Rect::xywh(2, 2, 3, 1).validate().unwrap();
+/// blit::blit_damage(&layout, &src, &mut dst, &Options::default(), &damage).unwrap();
+/// assert_eq!(dst.pixel(2, 2), [255; 4]);
+/// assert_eq!(dst.pixel(1, 2), [0; 4]);
+/// ```
+/// 
+/// [blit_with]:    fn.blit_with.html
+pub fn blit_damage(layout: &Layout<ValidDimensions<i32>, ValidDimensions<i32>>, src: &Image, dst: &mut ImageMut, options: &Options, damage: &ValidRect<i32>) -> Result<(), Error> {
+    blit_clipped(layout, src, dst, options, damage)
+}
+
+fn blit_clipped(layout: &Layout<ValidDimensions<i32>, ValidDimensions<i32>>, src: &Image, dst: &mut ImageMut, options: &Options, clip: &Rect<i32>) -> Result<(), Error> {
+    let src_outer = layout.src.outer;
+    if src_outer.left < 0 || src_outer.top < 0 || src.width() < src_outer.right as usize || src.height() < src_outer.bottom as usize {
+        return err("Expected layout.src.outer within the source image");
+    }
+
+    // Same 32-bit format in and out, with no per-channel math:  copy whole rows instead of converting pixel by pixel.
+    let raw = options.filter == Filter::Nearest && options.blend == Blend::Copy && options.tint == [255; 4]
+        && src.format() == dst.format() && src.format().bytes_per_pixel() == 4;
+
+    let (dst_w, dst_h) = (clamp_i32(dst.width()), clamp_i32(dst.height()));
+    let (l, r) = (clip.left.max(0), clip.right .min(dst_w));
+    let (t, b) = (clip.top .max(0), clip.bottom.min(dst_h));
+    let mut tiles = layout.tiles();
+    while let Some(tile) = tiles.next_tile() {
+        let (l, r) = (tile.dst.left.max(l), tile.dst.right .min(r));
+        let (t, b) = (tile.dst.top .max(t), tile.dst.bottom.min(b));
+        if l >= r || t >= b || tile.src.width() <= 0 || tile.src.height() <= 0 { continue; }
+        for y in t .. b {
+            if raw && l < r {
+                raw_row(&tile, src, dst, l .. r, y);
+                continue;
+            }
+            for x in l .. r {
+                let rgba = match options.filter {
+                    Filter::Nearest => {
+                        let [sx, sy] = tile.nearest_src(x, y);
+                        src.pixel(sx as usize, sy as usize)
+                    },
+                    Filter::Bilinear => {
+                        let (x0, x1, fx) = bilinear_axis(x, tile.dst.left, tile.dst.right,  tile.src.left, tile.src.right,  tile.mirror.horizontal);
+                        let (y0, y1, fy) = bilinear_axis(y, tile.dst.top,  tile.dst.bottom, tile.src.top,  tile.src.bottom, tile.mirror.vertical);
+                        let (p00, p10) = (src.pixel(x0, y0), src.pixel(x1, y0));
+                        let (p01, p11) = (src.pixel(x0, y1), src.pixel(x1, y1));
+                        let (w00, w10, w01, w11) = ((256-fx)*(256-fy), fx*(256-fy), (256-fx)*fy, fx*fy);
+                        let mut rgba = [0u8; 4];
+                        for c in 0 .. 4 {
+                            let sum = u32::from(p00[c]) * w00 + u32::from(p10[c]) * w10 + u32::from(p01[c]) * w01 + u32::from(p11[c]) * w11;
+                            rgba[c] = ((sum + 0x8000) >> 16) as u8;
+                        }
+                        rgba
+                    },
+                };
+                let (x, y) = (x as usize, y as usize);
+                let rgba = compose(options, rgba, dst.pixel(x, y));
+                dst.set_pixel(x, y, rgba);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Copy `xs` of row `y` of `tile` between identically formatted 32-bit images.
+/// 
+/// Only unscaled rows and single-pixel stretches go through [copy_row]/[fill_row] (and thus the `simd` fast paths.)
+/// Other stretches sample pixel by pixel:  SSE2/NEON have no gather to vectorize them with.
+fn raw_row(tile: &Tile<i32>, src: &Image, dst: &mut ImageMut, xs: core::ops::Range<i32>, y: i32) {
+    let [sx, sy] = tile.nearest_src(xs.start, y);
+    let o = dst.geo.offset(xs.start as usize, y as usize);
+    let out = &mut dst.data[o .. o + 4 * (xs.end - xs.start) as usize];
+    if tile.src.width() == tile.dst.width() && !tile.mirror.horizontal {
+        let i = src.geo.offset(sx as usize, sy as usize);
+        copy_row(out, &src.data[i .. i + out.len()]);
+    } else if tile.src.width() == 1 {
+        let i = src.geo.offset(sx as usize, sy as usize);
+        fill_row(out, [src.data[i], src.data[i+1], src.data[i+2], src.data[i+3]]);
+    } else {
+        for (x, px) in xs.zip(out.chunks_exact_mut(4)) {
+            let i = src.geo.offset(tile.nearest_src(x, y)[0] as usize, sy as usize);
+            px.copy_from_slice(&src.data[i .. i + 4]);
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))] fn copy_row(dst: &mut [u8], src: &[u8]) {
+    use core::arch::x86_64::*;
+    assert_eq!(dst.len(), src.len());
+    let mut dst = dst.chunks_exact_mut(16);
+    let mut src = src.chunks_exact(16);
+    for (d, s) in (&mut dst).zip(&mut src) {
+        // SAFETY:  SSE2 is part of the x86_64 baseline, and both chunks are exactly 16 bytes.  Unaligned loads/stores are used.
+        unsafe { _mm_storeu_si128(d.as_mut_ptr().cast(), _mm_loadu_si128(s.as_ptr().cast())) };
+    }
+    dst.into_remainder().copy_from_slice(src.remainder());
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))] fn fill_row(dst: &mut [u8], rgba: [u8; 4]) {
+    use core::arch::x86_64::*;
+    // SAFETY:  SSE2 is part of the x86_64 baseline.
+    let v = unsafe { _mm_set1_epi32(i32::from_ne_bytes(rgba)) };
+    let mut dst = dst.chunks_exact_mut(16);
+    for d in &mut dst {
+        // SAFETY:  `d` is exactly 16 bytes.  An unaligned store is used.
+        unsafe { _mm_storeu_si128(d.as_mut_ptr().cast(), v) };
+    }
+    for d in dst.into_remainder().chunks_exact_mut(4) { d.copy_from_slice(&rgba); }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))] fn copy_row(dst: &mut [u8], src: &[u8]) {
+    use core::arch::aarch64::*;
+    assert_eq!(dst.len(), src.len());
+    let mut dst = dst.chunks_exact_mut(16);
+    let mut src = src.chunks_exact(16);
+    for (d, s) in (&mut dst).zip(&mut src) {
+        // SAFETY:  NEON is part of the aarch64 baseline, and both chunks are exactly 16 bytes.
+        unsafe { vst1q_u8(d.as_mut_ptr(), vld1q_u8(s.as_ptr())) };
+    }
+    dst.into_remainder().copy_from_slice(src.remainder());
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))] fn fill_row(dst: &mut [u8], rgba: [u8; 4]) {
+    use core::arch::aarch64::*;
+    // SAFETY:  NEON is part of the aarch64 baseline.
+    let v = unsafe { vreinterpretq_u8_u32(vdupq_n_u32(u32::from_ne_bytes(rgba))) };
+    let mut dst = dst.chunks_exact_mut(16);
+    for d in &mut dst {
+        // SAFETY:  `d` is exactly 16 bytes.
+        unsafe { vst1q_u8(d.as_mut_ptr(), v) };
+    }
+    for d in dst.into_remainder().chunks_exact_mut(4) { d.copy_from_slice(&rgba); }
+}
+
+#[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))] fn copy_row(dst: &mut [u8], src: &[u8]) {
+    dst.copy_from_slice(src);
+}
+
+#[cfg(not(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64"))))] fn fill_row(dst: &mut [u8], rgba: [u8; 4]) {
+    for d in dst.chunks_exact_mut(4) { d.copy_from_slice(&rgba); }
+}
+
+fn compose(options: &Options, rgba: [u8; 4], mut dst: [u8; 4]) -> [u8; 4] {
+    let mut s = [0u32; 4];
+    for c in 0 .. 4 { s[c] = mul255(u32::from(rgba[c]), u32::from(options.tint[c])); }
+    match options.blend {
+        Blend::Copy => for c in 0 .. 4 { dst[c] = s[c] as u8; },
+        Blend::AlphaBlend => {
+            let (sa, da) = (s[3], mul255(u32::from(dst[3]), 255 - s[3]));
+            let a = sa + da;
+            if a == 0 { return dst; }
+            for c in 0 .. 3 { dst[c] = ((s[c] * sa + u32::from(dst[c]) * da + a / 2) / a) as u8; }
+            dst[3] = a as u8;
+        },
+        Blend::PremultipliedBlend => {
+            let inv = 255 - s[3];
+            for c in 0 .. 4 { dst[c] = (s[c] + mul255(u32::from(dst[c]), inv)).min(255) as u8; }
+        },
+    }
+    dst
+}
+
+/// `a * b / 255`, rounded.
+fn mul255(a: u32, b: u32) -> u32 { let t = a * b + 128; (t + (t >> 8)) >> 8 }
+
+/// The two source pixels bracketing the center of destination pixel `v`, and the 8-bit weight of the second.
+fn bilinear_axis(v: i32, dst_start: i32, dst_end: i32, src_start: i32, src_end: i32, mirror: bool) -> (usize, usize, u32) {
+    let (dw, sw) = (i64::from(dst_end - dst_start), i64::from(src_end - src_start));
+    let p = ((i64::from(v - dst_start) * 2 + 1) * sw * 256 / (dw * 2) - 128).max(0).min((sw - 1) * 256);
+    let (o0, o1, f) = (p >> 8, ((p >> 8) + 1).min(sw - 1), (p & 0xFF) as u32);
+    let at = |o: i64| (if mirror { i64::from(src_end) - 1 - o } else { i64::from(src_start) + o }) as usize;
+    (at(o0), at(o1), f)
+}
+
+impl Geometry {
+    fn new(len: usize, width: usize, height: usize, stride: isize, format: PixelFormat) -> Result<Self, Error> {
+        let row = match width.checked_mul(format.bytes_per_pixel()) { Some(row) => row, None => return err("Expected image row size to fit in usize") };
+        if stride.unsigned_abs() < row { return err("Expected |image stride| ≥ width * bytes per pixel"); }
+        let origin = if stride < 0 { stride.unsigned_abs() * height.saturating_sub(1) } else { 0 };
+        if height == 0 { return Ok(Self { width, height, stride, origin, format }); }
+        match stride.unsigned_abs().checked_mul(height - 1).and_then(|n| n.checked_add(row)) {
+            Some(needed) if needed <= len   => Ok(Self { width, height, stride, origin, format }),
+            _                               => err("Expected image data to contain height rows of stride bytes"),
+        }
+    }
+
+    fn offset(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "pixel ({}, {}) out of bounds of {}x{} image", x, y, self.width, self.height);
+        let row = if self.stride < 0 { self.origin - y * self.stride.unsigned_abs() } else { y * self.stride.unsigned_abs() };
+        row + x * self.format.bytes_per_pixel()
+    }
+}
+
+fn stride_isize(stride: usize) -> Result<isize, Error> {
+    if stride > isize::MAX as usize { err("Expected image stride to fit in isize") } else { Ok(stride as isize) }
+}
+
+fn clamp_i32(n: usize) -> i32 { if n > i32::MAX as usize { i32::MAX } else { n as i32 } }
+
+
+
+#[test] fn blit_test() {
+    // 4x3 source:  pixel (x, y) = [x, y, 0, 255]
+    let mut pixels = [0u8; 4 * 3 * 4];
+    for (i, p) in pixels.chunks_mut(4).enumerate() { p.copy_from_slice(&[(i % 4) as u8, (i / 4) as u8, 0, 255]); }
+    let src = Image::new(&pixels[..], 4, 3, 4 * 4).unwrap();
+
+    // 10 wide, with padding bytes at the end of each row
+    let mut out = [0xAAu8; 48 * 4];
+    let mut dst = ImageMut::new(&mut out[..], 10, 4, 48).unwrap();
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 4, 3), inner: Rect::xywh(1, 1, 2, 1) },
+        dst: Dimensions { outer: Rect::xywh(-1, 0, 12, 3), inner: Rect::xywh(0, 1, 10, 1) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Center)),
+    }.validate().unwrap();
+    blit(&layout, &src, &mut dst).unwrap();
+
+    let xs = |y| (0 .. 10).map(|x| dst.pixel(x, y)[0]).collect::<Vec<_>>();
+    let ys = |y| (0 .. 10).map(|x| dst.pixel(x, y)[1]).collect::<Vec<_>>();
+    assert_eq!(xs(1), vec![1, 2, 2, 1, 1, 2, 2, 1, 1, 2]);  // left border clipped, mirrored repeats
+    assert_eq!(ys(0), vec![0; 10]);
+    assert_eq!(ys(1), vec![1; 10]);
+    assert_eq!(dst.pixel(0, 3), [0xAA; 4]);                 // untouched
+    assert!(out[40 .. 48].iter().all(|b| *b == 0xAA));       // row padding untouched
+
+    let mut small = [0u8; 4];
+    assert!(blit(&layout, &src, &mut ImageMut::new(&mut small[..], 1, 1, 4).unwrap()).is_ok());
+    assert!(blit(&layout, &Image::new(&pixels[..], 3, 3, 16).unwrap(), &mut ImageMut::new(&mut small[..], 1, 1, 4).unwrap()).is_err());
+    assert!(Image::new(&pixels[..], 4, 3, 12).is_err());
+    assert!(Image::new(&pixels[..], 4, 4, 16).is_err());
+}
+
+#[test] fn blit_bilinear_test() {
+    // 4x1 source:  black, black, red, white
+    let pixels = [0,0,0,255,  0,0,0,255,  255,0,0,255,  255,255,255,255];
+    let src = Image::new(&pixels[..], 4, 1, 16).unwrap();
+
+    let mut out = [0u8; 10 * 4];
+    let mut dst = ImageMut::new(&mut out[..], 10, 1, 40).unwrap();
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 4, 1), inner: Rect::xywh(1, 0, 2, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 10, 1), inner: Rect::xywh(1, 0, 8, 1) },
+        style: Style::default(),
+    }.validate().unwrap();
+    blit_with(&layout, &src, &mut dst, &Options { filter: Filter::Bilinear, ..Options::default() }).unwrap();
+
+    let reds = (0 .. 10).map(|x| dst.pixel(x, 0)[0]).collect::<Vec<_>>();
+    assert_eq!(reds, vec![0, 0, 0, 32, 96, 159, 223, 255, 255, 255]);  // exact 1:1 corners, clamped within the center tile
+    assert!((0 .. 10).all(|x| dst.pixel(x, 0)[1] == if x == 9 { 255 } else { 0 }));
+
+    // 1:1 bilinear matches nearest exactly
+    let mut a = [0u8; 4 * 4];
+    let mut b = [0u8; 4 * 4];
+    let same = Layout { dst: layout.src, src: layout.src, style: Style::default() };
+    blit_with(&same, &src, &mut ImageMut::new(&mut a[..], 4, 1, 16).unwrap(), &Options { filter: Filter::Bilinear, ..Options::default() }).unwrap();
+    blit_with(&same, &src, &mut ImageMut::new(&mut b[..], 4, 1, 16).unwrap(), &Options { filter: Filter::Nearest,  ..Options::default() }).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a, pixels);
+}
+
+#[test] fn blit_blend_test() {
+    let pixels = [255, 0, 0, 128];
+    let src = Image::new(&pixels[..], 1, 1, 4).unwrap();
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 1, 1), inner: Rect::xywh(0, 0, 1, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 1, 1), inner: Rect::xywh(0, 0, 1, 1) },
+        style: Style::default(),
+    }.validate().unwrap();
+    let run = |blend, tint, under: [u8; 4]| {
+        let mut out = under;
+        blit_with(&layout, &src, &mut ImageMut::new(&mut out[..], 1, 1, 4).unwrap(), &Options { blend, tint, ..Options::default() }).unwrap();
+        out
+    };
+
+    assert_eq!(run(Blend::Copy,                 [255; 4],               [0, 0, 255, 255]), [255, 0, 0, 128]);
+    assert_eq!(run(Blend::Copy,                 [128, 255, 255, 255],   [0, 0, 255, 255]), [128, 0, 0, 128]);
+    assert_eq!(run(Blend::AlphaBlend,           [255; 4],               [0, 0, 255, 255]), [128, 0, 127, 255]);
+    assert_eq!(run(Blend::AlphaBlend,           [255; 4],               [0, 0, 0, 0]),     [255, 0, 0, 128]);
+    assert_eq!(run(Blend::AlphaBlend,           [255, 255, 255, 0],     [1, 2, 3, 4]),     [1, 2, 3, 4]);
+    assert_eq!(run(Blend::PremultipliedBlend,   [128, 0, 0, 128],       [0, 0, 255, 255]), [128, 0, 191, 255]);
+}
+
+#[test] fn blit_format_test() {
+    // 2x2 bottom-up BGR source with 2 bytes of row padding:  memory holds row 1 first
+    let bgr = [
+        0, 0, 3,  0, 0, 4,  9, 9,   // row 1:  red 3, red 4
+        0, 0, 1,  0, 0, 2,  9, 9,   // row 0:  red 1, red 2
+    ];
+    let src = Image::with_format(&bgr[..], 2, 2, -8, PixelFormat::Bgr8).unwrap();
+    assert_eq!(src.pixel(0, 0), [1, 0, 0, 255]);
+    assert_eq!(src.pixel(1, 1), [4, 0, 0, 255]);
+
+    let mut bgra = [0u8; 2 * 2 * 4];
+    let mut dst = ImageMut::with_format(&mut bgra[..], 2, 2, 8, PixelFormat::Bgra8).unwrap();
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 2, 2), inner: Rect::xywh(1, 1, 0, 0) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 2, 2), inner: Rect::xywh(1, 1, 0, 0) },
+        style: Style::default(),
+    }.validate().unwrap();
+    blit(&layout, &src, &mut dst).unwrap();
+    assert_eq!(bgra, [0, 0, 1, 255,  0, 0, 2, 255,  0, 0, 3, 255,  0, 0, 4, 255]);
+
+    let mut gray = [0u8; 2 * 2];
+    blit(&layout, &Image::with_format(&bgra[..], 2, 2, 8, PixelFormat::Bgra8).unwrap(), &mut ImageMut::with_format(&mut gray[..], 2, 2, -2, PixelFormat::Gray8).unwrap()).unwrap();
+    assert_eq!(gray, [1, 1, 0, 1]);  // bottom-up, red * 77 / 256
+
+    assert!(Image::with_format(&bgr[..], 2, 2, -5, PixelFormat::Bgr8).is_err());
+    assert!(Image::with_format(&bgr[..], 3, 2, -8, PixelFormat::Bgr8).is_err());
+    assert!(Image::with_format(&bgr[..], 2, 3, 8, PixelFormat::Bgr8).is_err());
+}
+
+#[test] fn blit_raw_test() {
+    // 5x3 source:  pixel (x, y) = [x, y, x ^ y, 255]
+    let mut pixels = [0u8; 5 * 3 * 4];
+    for (i, p) in pixels.chunks_mut(4).enumerate() { let (x, y) = ((i % 5) as u8, (i / 5) as u8); p.copy_from_slice(&[x, y, x ^ y, 255]); }
+    let src = Image::new(&pixels[..], 5, 3, 5 * 4).unwrap();
+
+    for style in [Style::default(), Style::new(Scale::Repeat(Partial::Center)), Style::new(Scale::MirrorRepeat(Partial::Center))].iter() {
+        for center in [1, 3].iter() {
+            let layout = Layout {
+                src: Dimensions { outer: Rect::xywh(0, 0, 5, 3), inner: Rect::xywh(1, 1, *center, 1) },
+                dst: Dimensions { outer: Rect::xywh(-2, 0, 27, 5), inner: Rect::xywh(0, 2, 23, 2) },
+                style: *style,
+            }.validate().unwrap();
+
+            // Fast RGBA => RGBA rows vs. per-pixel RGBA => BGRA conversion
+            let mut fast = [0u8; 24 * 5 * 4];
+            let mut slow = [0u8; 24 * 5 * 4];
+            blit(&layout, &src, &mut ImageMut::new(&mut fast[..], 24, 5, 24 * 4).unwrap()).unwrap();
+            blit(&layout, &src, &mut ImageMut::with_format(&mut slow[..], 24, 5, 24 * 4, PixelFormat::Bgra8).unwrap()).unwrap();
+            for p in slow.chunks_exact_mut(4) { p.swap(0, 2); }
+            assert_eq!(&fast[..], &slow[..]);
+        }
+    }
+}
+
+#[test] fn blit_damage_test() {
+    // 4x3 source:  pixel (x, y) = [x, y, 0, 255]
+    let mut pixels = [0u8; 4 * 3 * 4];
+    for (i, p) in pixels.chunks_mut(4).enumerate() { p.copy_from_slice(&[(i % 4) as u8, (i / 4) as u8, 0, 255]); }
+    let src = Image::new(&pixels[..], 4, 3, 4 * 4).unwrap();
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 4, 3), inner: Rect::xywh(1, 1, 2, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 10, 6), inner: Rect::xywh(1, 1, 8, 4) },
+        style: Style::new(Scale::Repeat(Partial::Center)),
+    }.validate().unwrap();
+    let options = Options { filter: Filter::Bilinear, ..Options::default() };
+
+    let mut full = [0xAAu8; 10 * 6 * 4];
+    blit_with(&layout, &src, &mut ImageMut::new(&mut full[..], 10, 6, 40).unwrap(), &options).unwrap();
+
+    let mut part = [0xAAu8; 10 * 6 * 4];
+    let damage = Rect::xywh(3, -2, 9, 4).validate().unwrap();
+    blit_damage(&layout, &src, &mut ImageMut::new(&mut part[..], 10, 6, 40).unwrap(), &options, &damage).unwrap();
+
+    for y in 0 .. 6 {
+        for x in 0 .. 10 {
+            let o = (y * 10 + x) * 4;
+            if 3 <= x && y < 2  { assert_eq!(part[o .. o+4], full[o .. o+4], "({}, {})", x, y); }
+            else                { assert_eq!(part[o .. o+4], [0xAA; 4],      "({}, {})", x, y); }
+        }
+    }
+}
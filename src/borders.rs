@@ -0,0 +1,82 @@
+use super::*;
+use core::fmt::Debug;
+
+/// The sizes of a 9-slice's borders - that is, how far each edge of the inner rect is from the matching edge of the
+/// outer rect.  Unlike a [Rect], these are distances rather than coordinates:  `right` is measured leftwards from the
+/// outer rect's right edge, and `bottom` upwards from its bottom edge.
+/// 
+/// See [ValidDimensions::borders].
+/// 
+/// [Rect]:                     struct.Rect.html
+/// [ValidDimensions::borders]:  struct.ValidDimensions.html#method.borders
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Borders<V: Debug> {
+    pub left:   V,
+    pub right:  V,
+    pub top:    V,
+    pub bottom: V,
+}
+
+impl<V: Debug> Borders<V> {
+    /// The same size on every side.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Borders::uniform(8), Borders { left: 8, right: 8, top: 8, bottom: 8 });
+    /// ```
+    #[must_use] pub fn uniform(size: V) -> Self where V: Clone {
+        Self { left: size.clone(), right: size.clone(), top: size.clone(), bottom: size }
+    }
+
+    /// `horizontal` for the left and right borders, and `vertical` for the top and bottom borders.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Borders::symmetric(4, 2), Borders { left: 4, right: 4, top: 2, bottom: 2 });
+    /// ```
+    #[must_use] pub fn symmetric(horizontal: V, vertical: V) -> Self where V: Clone {
+        Self { left: horizontal.clone(), right: horizontal, top: vertical.clone(), bottom: vertical }
+    }
+
+    /// Convert every border with `f`.
+    #[must_use] pub fn map<T: Debug>(self, mut f: impl FnMut(V) -> T) -> Borders<T> {
+        Borders { left: f(self.left), right: f(self.right), top: f(self.top), bottom: f(self.bottom) }
+    }
+}
+
+impl<S: Scalar> Borders<S> {
+    /// `left + right`:  the width taken up by the borders.
+    #[must_use] pub fn horizontal(&self) -> S { self.left + self.right }
+
+    /// `top + bottom`:  the height taken up by the borders.
+    #[must_use] pub fn vertical(&self) -> S { self.top + self.bottom }
+
+    /// Returns Err if any border is negative (or NaN.)
+    pub fn validate(&self) -> Result<Self, Error> {
+        let zero = S::default();
+        if !(zero <= self.left  ) { return err("Expected 0 ≤ borders.left"); }
+        if !(zero <= self.right ) { return err("Expected 0 ≤ borders.right"); }
+        if !(zero <= self.top   ) { return err("Expected 0 ≤ borders.top"); }
+        if !(zero <= self.bottom) { return err("Expected 0 ≤ borders.bottom"); }
+        Ok(*self)
+    }
+}
+
+
+
+#[test] fn borders_test() {
+    let b = Borders { left: 1, right: 2, top: 3, bottom: 4 };
+    assert_eq!(b.horizontal(), 3);
+    assert_eq!(b.vertical(), 7);
+    assert_eq!(b.map(|v| v * 2), Borders { left: 2, right: 4, top: 6, bottom: 8 });
+    assert!(b.validate().is_ok());
+    assert!(Borders { left: 0, right: 0, top: -1, bottom: 0 }.validate().is_err());
+    assert!(Borders::uniform(f32::NAN).validate().is_err());
+
+    let rect = Rect::xywh(10, 10, 20, 20);
+    assert_eq!(rect.shrink(&b), Rect { left: 11, right: 28, top: 13, bottom: 26 });
+    assert_eq!(rect.shrink(&b).grow(&b), rect);
+}
@@ -0,0 +1,171 @@
+use core::ops::{BitAnd, BitOr, Not};
+
+/// One of the nine cells of a nine-square layout.
+/// 
+/// ```text
+/// ┌────────────┬────────┬─────────────┐
+/// │ TopLeft    │ Top    │ TopRight    │
+/// ├────────────┼────────┼─────────────┤
+/// │ Left       │ Center │ Right       │
+/// ├────────────┼────────┼─────────────┤
+/// │ BottomLeft │ Bottom │ BottomRight │
+/// └────────────┴────────┴─────────────┘
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cell {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Cell {
+    /// All nine cells, in row-major order.
+    pub const ALL : [Cell; 9] = [
+        Cell::TopLeft,      Cell::Top,      Cell::TopRight,
+        Cell::Left,         Cell::Center,   Cell::Right,
+        Cell::BottomLeft,   Cell::Bottom,   Cell::BottomRight,
+    ];
+
+    /// The `[column, row]` of this cell, each in the range `0 ..= 2`.
+    pub fn xy(self) -> [usize; 2] {
+        let i = self as usize;
+        [i % 3, i / 3]
+    }
+
+    /// The cell on the opposite side horizontally - e.g. [Cell::TopLeft] ↔ [Cell::TopRight].  Center column cells map to themselves.
+    /// 
+    /// [Cell::TopLeft]:    enum.Cell.html#variant.TopLeft
+    /// [Cell::TopRight]:   enum.Cell.html#variant.TopRight
+    pub fn mirror_horizontal(self) -> Cell {
+        let [x, y] = self.xy();
+        Cell::ALL[y * 3 + (2 - x)]
+    }
+}
+
+/// The order in which a layout emits its [Cell]s.  See [Style::order].
+/// 
+/// [Cell]:         enum.Cell.html
+/// [Style::order]: struct.Style.html#structfield.order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmitOrder {
+    /// Top to bottom, left to right.
+    RowMajor,
+
+    /// The center first, followed by the border cells in row-major order - e.g. to draw a frame over its background.
+    CenterFirst,
+
+    /// The border cells in row-major order, followed by the center - e.g. to batch all border cells together.
+    CenterLast,
+}
+
+impl core::default::Default for EmitOrder { fn default() -> Self { EmitOrder::RowMajor } }
+
+impl EmitOrder {
+    /// All nine cells, in this order.
+    pub fn cells(self) -> [Cell; 9] {
+        use Cell::*;
+        match self {
+            EmitOrder::RowMajor     => Cell::ALL,
+            EmitOrder::CenterFirst  => [Center, TopLeft, Top, TopRight, Left, Right, BottomLeft, Bottom, BottomRight],
+            EmitOrder::CenterLast   => [TopLeft, Top, TopRight, Left, Right, BottomLeft, Bottom, BottomRight, Center],
+        }
+    }
+}
+
+/// A set of [Cell]s.  See [Style::cells].
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let header = CellMask::TOP_ROW;
+/// assert!( header.contains(Cell::TopLeft));
+/// assert!( header.contains(Cell::Top));
+/// assert!(!header.contains(Cell::Center));
+/// 
+/// let frame = !CellMask::from(Cell::Center);
+/// assert_eq!(frame, CellMask::CORNERS | CellMask::EDGES);
+/// ```
+/// 
+/// [Cell]:         enum.Cell.html
+/// [Style::cells]: struct.Style.html#structfield.cells
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CellMask(u16);
+
+impl CellMask {
+    pub const NONE          : CellMask = CellMask(0);
+    pub const ALL           : CellMask = CellMask(0b111_111_111);
+    pub const CORNERS       : CellMask = CellMask(0b101_000_101);
+    pub const EDGES         : CellMask = CellMask(0b010_101_010);
+    pub const CENTER        : CellMask = CellMask(0b000_010_000);
+    pub const TOP_ROW       : CellMask = CellMask(0b000_000_111);
+    pub const BOTTOM_ROW    : CellMask = CellMask(0b111_000_000);
+    pub const LEFT_COLUMN   : CellMask = CellMask(0b001_001_001);
+    pub const RIGHT_COLUMN  : CellMask = CellMask(0b100_100_100);
+
+    /// Check if `cell` is part of this set.
+    #[must_use] pub fn contains(self, cell: Cell) -> bool { self.0 & CellMask::from(cell).0 != 0 }
+
+    /// Check if this set is empty.
+    #[must_use] pub fn is_empty(self) -> bool { self.0 == 0 }
+}
+
+impl core::default::Default for CellMask { fn default() -> Self { CellMask::ALL } }
+
+impl From<Cell> for CellMask { fn from(cell: Cell) -> Self { CellMask(1 << (cell as u16)) } }
+
+impl BitOr  for CellMask { type Output = Self; fn bitor (self, other: Self) -> Self { CellMask(self.0 | other.0) } }
+impl BitAnd for CellMask { type Output = Self; fn bitand(self, other: Self) -> Self { CellMask(self.0 & other.0) } }
+impl Not    for CellMask { type Output = Self; fn not(self) -> Self { CellMask(!self.0 & CellMask::ALL.0) } }
+
+impl BitOr<Cell> for CellMask { type Output = Self; fn bitor(self, other: Cell) -> Self { self | CellMask::from(other) } }
+
+/// Serialized as a list of [Cell]s, e.g. `["TopLeft", "Top", "TopRight"]`, rather than raw bits.
+/// 
+/// [Cell]:         enum.Cell.html
+#[cfg(feature = "serde")] impl serde::Serialize for CellMask {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(Cell::ALL.iter().copied().filter(|cell| self.contains(*cell)))
+    }
+}
+
+#[cfg(feature = "serde")] impl<'de> serde::Deserialize<'de> for CellMask {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = CellMask;
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result { f.write_str("a list of cells") }
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<CellMask, A::Error> {
+                let mut mask = CellMask::NONE;
+                while let Some(cell) = seq.next_element::<Cell>()? { mask = mask | cell; }
+                Ok(mask)
+            }
+        }
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
+
+
+#[test] fn cell_mask_test() {
+    for (i, cell) in Cell::ALL.iter().copied().enumerate() {
+        assert_eq!(cell.xy(), [i % 3, i / 3]);
+        assert!(CellMask::ALL.contains(cell));
+        assert!(!CellMask::NONE.contains(cell));
+        assert_eq!(CellMask::from(cell) | !CellMask::from(cell), CellMask::ALL);
+    }
+
+    assert_eq!(CellMask::CORNERS, CellMask::from(Cell::TopLeft) | Cell::TopRight | Cell::BottomLeft | Cell::BottomRight);
+    assert_eq!(CellMask::EDGES,   CellMask::from(Cell::Top) | Cell::Left | Cell::Right | Cell::Bottom);
+    assert_eq!(CellMask::TOP_ROW & CellMask::LEFT_COLUMN, CellMask::from(Cell::TopLeft));
+    assert_eq!(CellMask::BOTTOM_ROW & CellMask::RIGHT_COLUMN, CellMask::from(Cell::BottomRight));
+    assert_eq!(CellMask::CORNERS | CellMask::EDGES | CellMask::CENTER, CellMask::ALL);
+    assert!((CellMask::CORNERS & CellMask::EDGES).is_empty());
+}
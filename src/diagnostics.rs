@@ -0,0 +1,377 @@
+use super::*;
+#[cfg(feature = "alloc")] use alloc::string::String;
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Distinct, colorblind-friendly-ish debug colors for each [Cell], in row-major order.
+const CELL_RGB : [[u8; 3]; 9] = [
+    [0xE6, 0x19, 0x4B], [0x3C, 0xB4, 0x4B], [0x43, 0x63, 0xD8],
+    [0xF5, 0x82, 0x31], [0x91, 0x1E, 0xB4], [0x42, 0xD4, 0xF4],
+    [0xF0, 0x32, 0xE6], [0x9A, 0x63, 0x24], [0x46, 0x99, 0x90],
+];
+
+impl<S: RatioScalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Render an SVG outlining and labeling every destination tile with its [Cell] and source rectangle - e.g. for bug reports.
+    /// 
+    /// [Cell]: enum.Cell.html
+    /// 
+    /// ```rust
+    /// # use nines::*;
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 8, 6), inner: Rect::xywh(1, 1, 6, 4) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// let svg = layout.to_debug_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains(">TopLeft<"));
+    /// ```
+    #[cfg(feature = "alloc")] pub fn to_debug_svg(&self) -> String { self.debug_svg(false) }
+
+    /// Like [to_debug_svg], but also draws the source cells to the right of the destination.
+    /// 
+    /// [to_debug_svg]: #method.to_debug_svg
+    #[cfg(feature = "alloc")] pub fn to_debug_svg_with_src(&self) -> String { self.debug_svg(true) }
+
+    #[cfg(feature = "alloc")] fn debug_svg(&self, with_src: bool) -> String {
+        let (zero, one) = (S::default(), S::from_count(1));
+        let (dst, src) = (self.dst.outer, self.src.outer);
+        let (dst_w, dst_h, src_w, src_h) = (dst.right - dst.left, dst.bottom - dst.top, src.right - src.left, src.bottom - src.top);
+        let mut gap = (dst_w + src_w) / S::from_count(8);
+        if !(gap > zero) { gap = one; }
+        let (dx, dy) = (dst.right + gap - src.left, dst.top - src.top);
+
+        let mut width  = dst_w;
+        let mut height = dst_h;
+        if with_src {
+            width = width + gap + src_w;
+            if height < src_h { height = src_h; }
+        }
+        let mut font = height / S::from_count(24);
+        if !(font > zero) { font = one; }
+
+        let mut svg = String::new();
+        let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{:?} {:?} {:?} {:?}" font-family="monospace" font-size="{:?}">"#, dst.left, dst.top, width, height, font);
+        let _ = writeln!(svg, r#"<rect x="{:?}" y="{:?}" width="{:?}" height="{:?}" fill="none" stroke="gray" stroke-dasharray="4" vector-effect="non-scaling-stroke"/>"#, dst.left, dst.top, dst_w, dst_h);
+        self.each_tile(|tile| {
+            let (d, s) = (tile.dst, tile.src);
+            let mirror = match (tile.mirror.horizontal, tile.mirror.vertical) { (false, false) => "", (true, false) => " ↔", (false, true) => " ↕", (true, true) => " ↔↕" };
+            write_rect(&mut svg, tile.cell, &d, "0.25");
+            let _ = writeln!(svg, r#"<text x="{:?}" y="{:?}"><tspan>{:?}</tspan><tspan x="{:?}" dy="1.2em">src {:?},{:?} {:?}x{:?}{}</tspan></text>"#,
+                d.left, d.top + font, tile.cell, d.left, s.left, s.top, s.width(), s.height(), mirror);
+        });
+
+        if with_src {
+            let _ = writeln!(svg, r#"<g transform="translate({:?} {:?})">"#, dx, dy);
+            for cell in Cell::ALL.iter().copied().filter(|c| self.style.emits(*c)) {
+                let s = self.src.cell(cell);
+                write_rect(&mut svg, cell, &s, "0.5");
+                let _ = writeln!(svg, r#"<text x="{:?}" y="{:?}">{:?}</text>"#, s.left, s.top + font, cell);
+            }
+            let _ = writeln!(svg, "</g>");
+        }
+        let _ = writeln!(svg, "</svg>");
+        svg
+    }
+}
+
+#[cfg(feature = "alloc")] fn write_rect<S: RatioScalar>(svg: &mut String, cell: Cell, r: &Rect<S>, fill_opacity: &str) {
+    let [red, green, blue] = CELL_RGB[cell as usize];
+    let _ = writeln!(svg, r##"<rect x="{:?}" y="{:?}" width="{:?}" height="{:?}" fill="#{:02X}{:02X}{:02X}" fill-opacity="{}" stroke="#{:02X}{:02X}{:02X}" vector-effect="non-scaling-stroke"/>"##,
+        r.left, r.top, r.right - r.left, r.bottom - r.top, red, green, blue, fill_opacity, red, green, blue);
+}
+
+/// A box-drawing diagram of [Dimensions] or a [Layout], with the actual coordinates filled in.  See [Dimensions::ascii_art].
+/// 
+/// [Dimensions]:               struct.Dimensions.html
+/// [Layout]:                   struct.Layout.html
+/// [Dimensions::ascii_art]:    struct.Dimensions.html#method.ascii_art
+#[derive(Clone, Copy, Debug)]
+pub struct AsciiArt<'a, S: RatioScalar> {
+    dst: &'a Dimensions<S>,
+    src: Option<&'a Dimensions<S>>,
+}
+
+impl<S: RatioScalar> Dimensions<S> {
+    /// Pretty print these dimensions as a box-drawing diagram, e.g. for eyeballing layout bugs in terminal logs.
+    /// 
+    /// ```rust
+    /// # use nines::*;
+    /// let dims = Dimensions { outer: Rect::xywh(0, 0, 8, 6), inner: Rect::xywh(1, 1, 6, 4) };
+    /// assert_eq!(dims.ascii_art().to_string(), "\
+    /// 0  1     7  8
+    /// ┌──┬─────┬──┐ 0
+    /// │  │     │  │
+    /// ├──┼─────┼──┤ 1
+    /// │  │ 6×4 │  │
+    /// ├──┼─────┼──┤ 5
+    /// │  │     │  │
+    /// └──┴─────┴──┘ 6
+    /// ");
+    /// ```
+    #[must_use] pub fn ascii_art(&self) -> AsciiArt<'_, S> { AsciiArt { dst: self, src: None } }
+}
+
+impl<S: RatioScalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Pretty print the `dst` and `src` dimensions of this layout as box-drawing diagrams.  See [Dimensions::ascii_art].
+    /// 
+    /// [Dimensions::ascii_art]:    struct.Dimensions.html#method.ascii_art
+    #[must_use] pub fn ascii_art(&self) -> AsciiArt<'_, S> { AsciiArt { dst: &self.dst, src: Some(&self.src) } }
+}
+
+impl<S: RatioScalar> Display for AsciiArt<'_, S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.src {
+            None        => write_dimensions(f, self.dst),
+            Some(src)   => {
+                writeln!(f, "dst:")?;
+                write_dimensions(f, self.dst)?;
+                writeln!(f, "src:")?;
+                write_dimensions(f, src)
+            },
+        }
+    }
+}
+
+fn write_dimensions<S: RatioScalar>(f: &mut Formatter, d: &Dimensions<S>) -> fmt::Result {
+    let (o, i) = (&d.outer, &d.inner);
+    let xs = [Label::new(o.left), Label::new(i.left), Label::new(i.right), Label::new(o.right)];
+    let ys = [Label::new(o.top),  Label::new(i.top),  Label::new(i.bottom), Label::new(o.bottom)];
+    let mut size = Label::default();
+    let _ = write!(size, "{:?}×{:?}", i.right - i.left, i.bottom - i.top);
+    let w = [xs[0].chars().max(2), xs[1].chars().max(size.chars() + 2), xs[2].chars().max(2)];
+
+    for c in 0 .. 3 { write!(f, "{}", xs[c].as_str())?; pad(f, ' ', w[c] + 1 - xs[c].chars())?; }
+    writeln!(f, "{}", xs[3].as_str())?;
+
+    let center = (w[1] - size.chars()) / 2;
+    let row = |f: &mut Formatter, [l, m, r]: [char; 3], fill: char, y: Option<&Label>| -> fmt::Result {
+        f.write_char(l)?;
+        pad(f, fill, w[0])?; f.write_char(m)?;
+        pad(f, fill, w[1])?; f.write_char(m)?;
+        pad(f, fill, w[2])?; f.write_char(r)?;
+        match y { Some(y) => writeln!(f, " {}", y.as_str()), None => writeln!(f) }
+    };
+    row(f, ['┌', '┬', '┐'], '─', Some(&ys[0]))?;
+    row(f, ['│', '│', '│'], ' ', None)?;
+    row(f, ['├', '┼', '┤'], '─', Some(&ys[1]))?;
+    write!(f, "│")?; pad(f, ' ', w[0])?; write!(f, "│")?;
+    pad(f, ' ', center)?; write!(f, "{}", size.as_str())?; pad(f, ' ', w[1] - center - size.chars())?;
+    write!(f, "│")?; pad(f, ' ', w[2])?; writeln!(f, "│")?;
+    row(f, ['├', '┼', '┤'], '─', Some(&ys[2]))?;
+    row(f, ['│', '│', '│'], ' ', None)?;
+    row(f, ['└', '┴', '┘'], '─', Some(&ys[3]))
+}
+
+fn pad(f: &mut Formatter, c: char, n: usize) -> fmt::Result {
+    for _ in 0 .. n { f.write_char(c)?; }
+    Ok(())
+}
+
+/// A short, stack allocated, formatted label.  Truncated if too long.
+#[derive(Default)]
+struct Label { buf: [u8; 32], len: usize }
+
+impl Label {
+    fn new<S: RatioScalar>(value: S) -> Self { let mut l = Self::default(); let _ = write!(l, "{:?}", value); l }
+    fn as_str(&self) -> &str { core::str::from_utf8(&self.buf[.. self.len]).unwrap_or("?") }
+    fn chars(&self) -> usize { self.as_str().chars().count() }
+}
+
+impl Write for Label {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut n = s.len().min(self.buf.len() - self.len);
+        while !s.is_char_boundary(n) { n -= 1; }
+        self.buf[self.len .. self.len + n].copy_from_slice(&s.as_bytes()[.. n]);
+        self.len += n;
+        if n < s.len() { Err(fmt::Error) } else { Ok(()) }
+    }
+}
+
+/// A [Tile] with a suggested debug overlay color and label.  See [Layout::debug_tiles].
+/// 
+/// [Tile]:                 struct.Tile.html
+/// [Layout::debug_tiles]:  struct.Layout.html#method.debug_tiles
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DebugTile<S: RatioScalar> {
+    pub tile:   Tile<S>,
+
+    /// A semi-transparent RGBA color, distinct per [Cell], alternating in brightness between neighboring tiles of the same cell.
+    /// 
+    /// [Cell]: enum.Cell.html
+    pub color:  [u8; 4],
+
+    /// The 0-based index of this tile within `tile.cell`.
+    pub index:  usize,
+
+    /// The number of tiles emitted for `tile.cell`.
+    pub count:  usize,
+}
+
+impl<S: RatioScalar> DebugTile<S> {
+    /// A human readable label such as `"top-left corner"` or `"center tile 3/7"`.
+    #[must_use] pub fn label(&self) -> DebugLabel { DebugLabel { cell: self.tile.cell, index: self.index, count: self.count } }
+}
+
+/// A human readable [DebugTile] label.  See [DebugTile::label].
+/// 
+/// [DebugTile]:        struct.DebugTile.html
+/// [DebugTile::label]: struct.DebugTile.html#method.label
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DebugLabel { cell: Cell, index: usize, count: usize }
+
+impl Display for DebugLabel {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let name = match self.cell {
+            Cell::TopLeft       => "top-left corner",
+            Cell::Top           => "top edge",
+            Cell::TopRight      => "top-right corner",
+            Cell::Left          => "left edge",
+            Cell::Center        => "center",
+            Cell::Right         => "right edge",
+            Cell::BottomLeft    => "bottom-left corner",
+            Cell::Bottom        => "bottom edge",
+            Cell::BottomRight   => "bottom-right corner",
+        };
+        if self.count == 1 { write!(f, "{}", name) } else { write!(f, "{} tile {}/{}", name, self.index + 1, self.count) }
+    }
+}
+
+/// An iterator over the [DebugTile]s of a [Layout].  See [Layout::debug_tiles].
+/// 
+/// [DebugTile]:            struct.DebugTile.html
+/// [Layout]:               struct.Layout.html
+/// [Layout::debug_tiles]:  struct.Layout.html#method.debug_tiles
+#[derive(Clone, Debug)]
+pub struct DebugTiles<S: RatioScalar> {
+    tiles:  Tiles<S>,
+    counts: [usize; 9],
+    seen:   [usize; 9],
+}
+
+impl<S: RatioScalar> Iterator for DebugTiles<S> {
+    type Item = DebugTile<S>;
+    fn next(&mut self) -> Option<DebugTile<S>> {
+        let tile = self.tiles.next_tile()?;
+        let cell = tile.cell as usize;
+        let index = self.seen[cell];
+        self.seen[cell] += 1;
+        let [r, g, b] = CELL_RGB[cell];
+        let dim = |c: u8| if index & 1 == 0 { c } else { c / 4 * 3 };
+        Some(DebugTile { tile, color: [dim(r), dim(g), dim(b), 0x80], index, count: self.counts[cell] })
+    }
+}
+
+impl<S: RatioScalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Like [tiles], but also yields a suggested color and label for each tile, for drawing diagnostic overlays.
+    /// 
+    /// ```rust
+    /// # use nines::*;
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 9, 3), inner: Rect::xywh(1, 1, 7, 1) },
+    ///     style: Style::new(Scale::Repeat(Partial::Center)),
+    /// }.validate().unwrap();
+    /// let labels = layout.debug_tiles().map(|t| t.label().to_string()).collect::<Vec<_>>();
+    /// assert_eq!(labels[0], "top-left corner");
+    /// assert_eq!(labels[1], "top edge tile 1/7");
+    /// assert_eq!(labels[12], "center tile 3/7");
+    /// ```
+    /// 
+    /// [tiles]:    #method.tiles
+    pub fn debug_tiles(&self) -> DebugTiles<S> {
+        let mut counts = [0; 9];
+        let mut tiles = self.tiles();
+        while let Some(tile) = tiles.next_tile() { counts[tile.cell as usize] += 1; }
+        DebugTiles { tiles: self.tiles(), counts, seen: [0; 9] }
+    }
+}
+
+
+
+#[cfg(feature = "alloc")] #[test] fn debug_svg_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(10, 0, 3, 3), inner: Rect::xywh(11, 1, 1, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 8, 5), inner: Rect::xywh(1, 1, 6, 3) },
+        style: Style { fill: false, ..Style::new(Scale::MirrorRepeat(Partial::Center)) },
+    }.validate().unwrap();
+
+    let svg = layout.to_debug_svg();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 8 5""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("<text").count(), layout.tiles().count());
+    assert!(!svg.contains(">Center<"));
+    assert!(svg.contains(r##"<rect x="2" y="0" width="1" height="1" fill="#3CB44B""##));  // 2nd top tile
+    assert!(svg.contains("src 11,0 1x1 ↔"));
+    assert!(!svg.contains("<g"));
+
+    let svg = layout.to_debug_svg_with_src();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 12 5""#));
+    assert!(svg.contains(r#"<g transform="translate(-1 0)">"#));
+    assert_eq!(svg.matches("<text").count(), layout.tiles().count() + 8);
+}
+
+#[cfg(feature = "alloc")] #[test] fn ascii_art_test() {
+    use alloc::string::ToString;
+
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(10.0, 0.0, 3.0, 3.0), inner: Rect::xywh(11.0, 1.0, 1.0, 1.0) },
+        dst: Dimensions { outer: Rect::xywh(-4.0, 0.0, 108.0, 50.0), inner: Rect::xywh(-2.5, 1.5, 105.0, 47.0) },
+        style: Style::default(),
+    }.validate().unwrap();
+
+    assert_eq!(layout.ascii_art().to_string(), "\
+dst:
+-4.0 -2.5         102.5 104.0
+┌────┬────────────┬─────┐ 0.0
+│    │            │     │
+├────┼────────────┼─────┤ 1.5
+│    │ 105.0×47.0 │     │
+├────┼────────────┼─────┤ 48.5
+│    │            │     │
+└────┴────────────┴─────┘ 50.0
+src:
+10.0 11.0      12.0 13.0
+┌────┬─────────┬────┐ 0.0
+│    │         │    │
+├────┼─────────┼────┤ 1.0
+│    │ 1.0×1.0 │    │
+├────┼─────────┼────┤ 2.0
+│    │         │    │
+└────┴─────────┴────┘ 3.0
+");
+}
+
+#[cfg(feature = "alloc")] #[test] fn debug_tiles_test() {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+        style: Style { order: EmitOrder::CenterFirst, ..Style::new(Scale::Repeat(Partial::Center)) },
+    }.validate().unwrap();
+
+    let tiles = layout.debug_tiles().collect::<Vec<_>>();
+    assert_eq!(tiles.iter().map(|t| t.tile).collect::<Vec<_>>(), layout.to_tile_vec());
+    assert_eq!(tiles[0].label().to_string(), "center tile 1/6");
+    assert_eq!(tiles[5].label().to_string(), "center tile 6/6");
+    assert_eq!(tiles[6].label().to_string(), "top-left corner");
+    assert_eq!(tiles[7].label().to_string(), "top edge tile 1/3");
+    assert_eq!(tiles[0].color, [0x91, 0x1E, 0xB4, 0x80]);
+    assert_eq!(tiles[1].color, [0x6C, 0x15, 0x87, 0x80]);
+    assert_ne!(tiles[6].color, tiles[7].color);
+}
+
+#[test] fn label_truncate_test() {
+    assert_eq!(Label::new(12_i32).as_str(), "12");
+    assert_eq!(Label::new(1e300_f64).as_str(), "1e300");
+    let mut label = Label::default();
+    assert!(write!(label, "{}", "é".repeat(20)).is_err());
+    assert_eq!(label.as_str(), "é".repeat(16)); // 32 bytes of 2 byte chars
+    assert!(write!(label, "x").is_err());
+    assert_eq!(label.chars(), 16);
+
+    let mut label = Label::default();
+    let _ = write!(label, "a{}", "é".repeat(20));
+    assert_eq!(label.as_str(), format!("a{}", "é".repeat(15))); // the split 16th `é` is dropped, not mangled
+}
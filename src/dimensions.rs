@@ -1,12 +1,14 @@
 use super::*;
-use std::convert::TryFrom;
-use std::fmt::Debug;
-use std::ops::Deref;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::ops::{Deref, Mul};
 
 /// Slice dimensions with non-negative & non-NAN dimensions (including borders.)
 /// 
 /// Do not base soundness assumptions on this definition of validity - `debug_assert_valid` can bypass checks.
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Dimensions<S>", into = "Dimensions<S>"))] // Deserialization goes through `validate()`
 pub struct ValidDimensions<S: Scalar>(Dimensions<S>);
 
 impl<S: Scalar> Deref for ValidDimensions<S> { type Target = Dimensions<S>; fn deref(&self) -> &Self::Target { &self.0 } }
@@ -23,6 +25,14 @@ impl<S: Scalar> From<&ValidDimensions<S>> for Dimensions<S> { fn from(value: &Va
 impl<S: Scalar> AsRef<Dimensions<S>> for ValidDimensions<S> { fn as_ref(&self) -> &Dimensions<S> { &self.0 } }
 // NOTE:  Do *NOT* implement AsMut!  That would allow validation to be bypassed, mooting the point of this type!
 
+/// Multiply every coordinate by `rhs`.  See [Dimensions::scaled] to validate the result.
+/// 
+/// [Dimensions::scaled]:   struct.Dimensions.html#method.scaled
+impl<S: RatioScalar> Mul<S> for Dimensions<S> {
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self { Dimensions { outer: self.outer * rhs, inner: self.inner * rhs } }
+}
+
 
 
 /// The dimensions of a nine-square layout.  See also [ValidDimensions].
@@ -46,19 +56,39 @@ impl<S: Scalar> AsRef<Dimensions<S>> for ValidDimensions<S> { fn as_ref(&self) -
 /// 
 /// [ValidDimensions]:      struct.ValidDimensions.html
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dimensions<S: Scalar> {
     pub outer: Rect<S>,
     pub inner: Rect<S>,
 }
 
 impl<S: Scalar> Dimensions<S> {
+    /// Create a 9-slice from its `outer` rect and the sizes of its `borders` - e.g. "this image, with 8px borders all
+    /// around" - instead of computing the inner rect by hand.
+    /// 
+    /// Can return Err if:
+    /// * `outer` is invalid
+    /// * Any border is negative (or NaN)
+    /// * The center would have negative bounds
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let button = Dimensions::from_outer_and_borders(Rect::xywh(0, 0, 32, 24), Borders::uniform(8)).unwrap();
+    /// assert_eq!(button.inner, Rect::xywh(8, 8, 16, 8));
+    /// assert!(Dimensions::from_outer_and_borders(Rect::xywh(0, 0, 32, 12), Borders::uniform(8)).is_err());
+    /// ```
+    pub fn from_outer_and_borders(outer: impl Into<Rect<S>>, borders: Borders<S>) -> Result<ValidDimensions<S>, Error> {
+        with_borders(outer.into().validate()?, borders.validate()?)
+    }
+
     /// Validate these dimensions are non-negative / non-NaN.  This means:
     /// 
     /// ```text
     /// outer.left ≤ inner.left ≤ inner.right ≤ outer.right
     /// outer.top ≤ inner.top ≤ inner.bottom ≤ outer.bottom
     /// ```
-    #[must_use] pub fn validate(&self) -> Result<ValidDimensions<S>, Error> {
+    pub fn validate(&self) -> Result<ValidDimensions<S>, Error> {
         if !(self.outer.left   <= self.inner.left  ) { return err("Expected outer.left ≤ inner.left"); }
         if !(self.inner.left   <= self.inner.right ) { return err("Expected inner.left ≤ inner.right"); }
         if !(self.inner.right  <= self.outer.right ) { return err("Expected inner.right ≤ outer.right"); }
@@ -68,6 +98,90 @@ impl<S: Scalar> Dimensions<S> {
         Ok(ValidDimensions(*self))
     }
 
+    /// Validate these dimensions, resolving undersized `outer` rects (where opposing borders overlap) per `policy`.
+    /// 
+    /// Still returns `Err` if `outer` is invalid, or if any border is negative (or NaN.)
+    pub fn validate_with(&self, policy: OverflowPolicy) -> Result<ValidDimensions<S>, Error> where S: RatioScalar {
+        if policy == OverflowPolicy::Error { return self.validate(); }
+        let outer = self.outer.validate()?;
+        // Compare before subtracting, so invalid unsigned borders are an Err instead of an underflow.
+        if !(self.outer.left   <= self.inner.left  ) { return err("Expected outer.left ≤ inner.left"); }
+        if !(self.inner.right  <= self.outer.right ) { return err("Expected inner.right ≤ outer.right"); }
+        if !(self.outer.top    <= self.inner.top   ) { return err("Expected outer.top ≤ inner.top"); }
+        if !(self.inner.bottom <= self.outer.bottom) { return err("Expected inner.bottom ≤ outer.bottom"); }
+        let borders = Borders {
+            left:   self.inner.left     - self.outer.left,
+            right:  self.outer.right    - self.inner.right,
+            top:    self.inner.top      - self.outer.top,
+            bottom: self.outer.bottom   - self.inner.bottom,
+        };
+        fit(outer, borders, policy)
+    }
+
+    /// Multiply all coordinates by a (e.g. DPI) scale factor, rounding integer coordinates per `rounding`, and validate the result.
+    /// 
+    /// Since every coordinate is rounded the same way, edges that were ordered stay ordered - but borders may change
+    /// size by a pixel relative to each other.  Returns Err if the result is invalid - e.g. if `self` was, or if
+    /// `factor` is negative or NaN.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let dims = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) };
+    /// let hidpi = dims.scale(1.25, Rounding::Nearest).unwrap();
+    /// assert_eq!(hidpi.outer, Rect::xywh(0, 0, 4, 4));
+    /// assert_eq!(hidpi.inner, Rect { left: 1, top: 1, right: 3, bottom: 3 });
+    /// ```
+    pub fn scale(&self, factor: f64, rounding: Rounding) -> Result<ValidDimensions<S>, Error> {
+        Dimensions {
+            outer: *self.outer.scale(factor, rounding)?,
+            inner: *self.inner.scale(factor, rounding)?,
+        }.validate()
+    }
+
+    /// Multiply horizontal coordinates by `sx` and vertical coordinates by `sy` - e.g. for a UI zoom level - and
+    /// validate the result.  Borders are scaled too.
+    /// 
+    /// Returns Err if the result is invalid - e.g. if `self` was, or if `sx` or `sy` is negative or NaN.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let dims = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) };
+    /// let zoomed = dims.scaled(2, 2).unwrap();
+    /// assert_eq!(zoomed, dims * 2);
+    /// assert_eq!(zoomed.borders(), Borders { left: 2, right: 2, top: 2, bottom: 2 });
+    /// ```
+    pub fn scaled(&self, sx: S, sy: S) -> Result<ValidDimensions<S>, Error> where S: RatioScalar {
+        Dimensions {
+            outer: *self.outer.scaled(sx, sy)?,
+            inner: *self.inner.scaled(sx, sy)?,
+        }.validate()
+    }
+
+    /// Convert every coordinate with `f`.  The result isn't validated - `f` might not preserve ordering.
+    #[must_use] pub fn map<T: Scalar>(&self, mut f: impl FnMut(S) -> T) -> Dimensions<T> {
+        Dimensions { outer: self.outer.map(&mut f), inner: self.inner.map(&mut f) }
+    }
+
+    /// Convert to another scalar type, rounding to the nearest `T`.  See [Rect::cast].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let texels = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) };
+    /// assert_eq!(texels.cast::<f32>(), Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) });
+    /// ```
+    /// 
+    /// [Rect::cast]:   struct.Rect.html#method.cast
+    #[must_use] pub fn cast<T: Scalar>(&self) -> Dimensions<T> { Dimensions { outer: self.outer.cast(), inner: self.inner.cast() } }
+
+    /// Convert to `f32`.
+    #[must_use] pub fn to_f32(&self) -> Dimensions<f32> { self.cast() }
+
+    /// Convert to `i32`, rounding per `rounding`.  Results saturate at the limits of `i32`.
+    #[must_use] pub fn to_i32(&self, rounding: Rounding) -> Dimensions<i32> { Dimensions { outer: self.outer.to_i32(rounding), inner: self.inner.to_i32(rounding) } }
+
     #[must_use] pub(crate) fn debug_assert_valid(&self) -> ValidDimensions<S> {
         if DEBUG {
             assert!(self.outer.left   <= self.inner.left,   "Expected outer.left ≤ inner.left");
@@ -82,9 +196,77 @@ impl<S: Scalar> Dimensions<S> {
 }
 
 impl<S: Scalar> ValidDimensions<S> {
+    /// Create a 9-slice with the same border size `border` on every side of `outer`.
+    /// See [Dimensions::from_outer_and_borders].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let panel = ValidDimensions::uniform_border(Rect::xywh(0, 0, 32, 32), 8).unwrap();
+    /// assert_eq!(panel.inner(), Rect::xywh(8, 8, 16, 16));
+    /// ```
+    /// 
+    /// [Dimensions::from_outer_and_borders]:   struct.Dimensions.html#method.from_outer_and_borders
+    pub fn uniform_border(outer: impl Into<Rect<S>>, border: S) -> Result<Self, Error> {
+        Dimensions::from_outer_and_borders(outer, Borders::uniform(border))
+    }
+
+    /// Create a 9-slice with `horizontal` sized left and right borders, and `vertical` sized top and bottom borders.
+    /// See [Dimensions::from_outer_and_borders].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let tab = ValidDimensions::symmetric_border(Rect::xywh(0, 0, 32, 16), 6, 2).unwrap();
+    /// assert_eq!(tab.inner(), Rect::xywh(6, 2, 20, 12));
+    /// ```
+    /// 
+    /// [Dimensions::from_outer_and_borders]:   struct.Dimensions.html#method.from_outer_and_borders
+    pub fn symmetric_border(outer: impl Into<Rect<S>>, horizontal: S, vertical: S) -> Result<Self, Error> {
+        Dimensions::from_outer_and_borders(outer, Borders::symmetric(horizontal, vertical))
+    }
+
     #[must_use] pub fn outer(&self) -> ValidRect<S> { self.outer.debug_assert_valid() }
     #[must_use] pub fn inner(&self) -> ValidRect<S> { self.inner.debug_assert_valid() }
 
+    /// Move both rects by `dx` horizontally and `dy` vertically, keeping the same borders - e.g. to position a skin at
+    /// its offset within a texture atlas.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let skin = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap();
+    /// let atlased = skin.translate(64, 32);
+    /// assert_eq!(atlased.outer(), Rect::xywh(64, 32, 3, 3));
+    /// assert_eq!(atlased.at_origin(), skin);
+    /// ```
+    #[must_use] pub fn translate(&self, dx: S, dy: S) -> Self {
+        Dimensions { outer: self.outer.translate(dx, dy), inner: self.inner.translate(dx, dy) }.debug_assert_valid()
+    }
+
+    /// Move both rects so the outer rect's top left corner is at `[0, 0]`, keeping the same borders.
+    /// Never underflows, even for unsigned scalars.
+    #[must_use] pub fn at_origin(&self) -> Self {
+        let (outer, inner) = (self.outer, self.inner);
+        Dimensions {
+            outer: outer.at_origin(),
+            inner: Rect { left: inner.left - outer.left, right: inner.right - outer.left, top: inner.top - outer.top, bottom: inner.bottom - outer.top },
+        }.debug_assert_valid()
+    }
+
+    /// Convert to another scalar type, rounding to the nearest `T`.  See [Rect::cast].
+    /// 
+    /// Every coordinate is rounded the same way, so edges that were ordered stay ordered, and the result is still valid.
+    /// 
+    /// [Rect::cast]:   struct.Rect.html#method.cast
+    #[must_use] pub fn cast<T: Scalar>(&self) -> ValidDimensions<T> { self.0.cast().debug_assert_valid() }
+
+    /// Convert to `f32`.
+    #[must_use] pub fn to_f32(&self) -> ValidDimensions<f32> { self.0.to_f32().debug_assert_valid() }
+
+    /// Convert to `i32`, rounding per `rounding`.
+    #[must_use] pub fn to_i32(&self, rounding: Rounding) -> ValidDimensions<i32> { self.0.to_i32(rounding).debug_assert_valid() }
+
     /// Get the sizes of the borders - that is, the spacing between the outer and inner rects.
     /// 
     /// ```text
@@ -100,8 +282,10 @@ impl<S: Scalar> ValidDimensions<S> {
     ///  │  │    │  │ ↕ bottom
     ///  └──┴────┴──┘┈┈
     /// ```
-    #[must_use] pub fn borders(&self) -> Rect<S> {
-        Rect {
+    /// 
+    /// Each border is a nearer edge subtracted from a farther one, so this never underflows - even for unsigned scalars.
+    #[must_use] pub fn borders(&self) -> Borders<S> {
+        Borders {
             left:   self.inner.left     - self.outer.left,
             right:  self.outer.right    - self.inner.right,
             top:    self.inner.top      - self.outer.top,
@@ -109,6 +293,71 @@ impl<S: Scalar> ValidDimensions<S> {
         }
     }
 
+    /// Like [borders](#method.borders), but returns Err instead of overflowing.
+    pub fn try_borders(&self) -> Result<Borders<S>, Error> {
+        match (
+            self.inner.left.checked_sub(self.outer.left),
+            self.outer.right.checked_sub(self.inner.right),
+            self.inner.top.checked_sub(self.outer.top),
+            self.outer.bottom.checked_sub(self.inner.bottom),
+        ) {
+            (Some(left), Some(right), Some(top), Some(bottom)) => Ok(Borders { left, right, top, bottom }),
+            _ => err("Expected borders not to overflow"),
+        }
+    }
+
+    /// The `[width, height]` these dimensions are drawn at without any scaling - e.g. a widget's preferred size.
+    #[must_use] pub fn natural_size(&self) -> [S; 2] { self.outer().size() }
+
+    /// The `[width, height]` these dimensions are drawn at when uniformly scaled by `multiplier` - e.g. pixel art at 2x.
+    #[must_use] pub fn natural_size_scaled(&self, multiplier: S) -> [S; 2] where S: RatioScalar {
+        let [w, h] = self.natural_size();
+        [w * multiplier, h * multiplier]
+    }
+
+    /// Where to place content (e.g. text or child widgets) within the frame:  the inner rect.
+    #[must_use] pub fn content_rect(&self) -> ValidRect<S> { self.inner() }
+
+    /// Where to place content within the frame:  the inner rect, further inset by `padding` (negative padding outsets.)
+    /// 
+    /// Can return Err if the padding would result in a negative (or NaN) size.
+    pub fn content_rect_padded(&self, padding: &Borders<S>) -> Result<ValidRect<S>, Error> {
+        self.inner.shrink(padding).validate()
+    }
+
+    /// Get the rectangle of a single [Cell].
+    /// 
+    /// [Cell]:     enum.Cell.html
+    #[must_use] pub fn cell(&self, cell: Cell) -> ValidRect<S> {
+        let xs = [self.outer.left, self.inner.left, self.inner.right, self.outer.right];
+        let ys = [self.outer.top, self.inner.top, self.inner.bottom, self.outer.bottom];
+        let [x, y] = cell.xy();
+        Rect::from([xs[x+0]..xs[x+1], ys[y+0]..ys[y+1]]).debug_assert_valid()
+    }
+
+    /// Which [Cell] contains the point `(x, y)`, or `None` if it's outside `outer`.
+    /// Like [Rect], the right/bottom edges are exclusive.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let window = Dimensions { outer: Rect::xywh(0, 0, 100, 100), inner: Rect::xywh(4, 20, 92, 76) }.validate().unwrap();
+    /// assert_eq!(window.cell_at(50, 10), Some(Cell::Top));        // title bar
+    /// assert_eq!(window.cell_at( 1, 99), Some(Cell::BottomLeft)); // resize corner
+    /// assert_eq!(window.cell_at(50, 50), Some(Cell::Center));     // client area
+    /// assert_eq!(window.cell_at(100, 50), None);
+    /// ```
+    /// 
+    /// [Cell]:     enum.Cell.html
+    /// [Rect]:     struct.Rect.html
+    #[must_use] pub fn cell_at(&self, x: S, y: S) -> Option<Cell> {
+        let (o, i) = (&self.outer, &self.inner);
+        if !(o.left <= x && x < o.right && o.top <= y && y < o.bottom) { return None; }
+        let column = if x < i.left { 0 } else if x < i.right { 1 } else { 2 };
+        let row    = if y < i.top  { 0 } else if y < i.bottom { 1 } else { 2 };
+        Some(Cell::ALL[row * 3 + column])
+    }
+
     /// Create a new 9-slice with specified outer dimensions, keeping the border sizes the same.
     /// 
     /// Can return Err if:
@@ -116,17 +365,68 @@ impl<S: Scalar> ValidDimensions<S> {
     /// * The center would have negative bounds
     /// 
     /// May panic on overflow/underflow.
-    #[must_use] pub fn with_outer(&self, outer: impl Into<ValidRect<S>>) -> Result<Self, Error> {
-        let borders = self.borders();
+    pub fn with_outer(&self, outer: impl Into<ValidRect<S>>) -> Result<Self, Error> {
+        with_borders(outer.into().validate()?, self.borders())
+    }
+
+    /// Like [with_outer](#method.with_outer), but returns Err instead of panicking on overflow/underflow - e.g. for
+    /// untrusted asset data.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let src = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap();
+    /// assert!(src.try_with_outer(Rect::xywh(0, 0, 10, 10).validate().unwrap()).is_ok());
+    /// 
+    /// let huge = Rect { left: i32::MIN, right: i32::MAX, top: 0, bottom: 10 }.validate().unwrap();
+    /// assert!(src.try_with_outer(huge).is_err());
+    /// ```
+    pub fn try_with_outer(&self, outer: impl Into<ValidRect<S>>) -> Result<Self, Error> {
+        let borders = self.try_borders()?;
         let outer = outer.into().validate()?;
-        if borders.left + borders.right > outer.width()  { return err("Resulting dimensions would have a negative center width"); }
-        if borders.top + borders.bottom > outer.height() { return err("Resulting dimensions would have a negative center height"); }
+        let [width, height] = outer.try_size()?;
+        let (horizontal, vertical) = match (borders.left.checked_add(borders.right), borders.top.checked_add(borders.bottom)) {
+            (Some(horizontal), Some(vertical)) => (horizontal, vertical),
+            _ => return err("Expected borders not to overflow"),
+        };
+        if horizontal > width  { return err("Resulting dimensions would have a negative center width"); }
+        if vertical   > height { return err("Resulting dimensions would have a negative center height"); }
         Ok(Dimensions {
-            inner: outer.shrink(&borders),
+            inner: outer.try_shrink(&borders)?,
             outer: *outer,
         }.debug_assert_valid())
     }
 
+    /// Create a new 9-slice with specified outer dimensions, keeping the border sizes the same - unless `outer` is too
+    /// small to fit them, in which case `policy` decides what happens.
+    /// 
+    /// Can return Err if:
+    /// * `rect` is invalid
+    /// * `policy` is [OverflowPolicy::Error] and the center would have negative bounds
+    /// 
+    /// [OverflowPolicy::Error]:    enum.OverflowPolicy.html#variant.Error
+    pub fn with_outer_policy(&self, outer: impl Into<ValidRect<S>>, policy: OverflowPolicy) -> Result<Self, Error> where S: RatioScalar {
+        fit(outer.into().validate()?, self.borders(), policy)
+    }
+
+    /// Create a new 9-slice with specified outer dimensions, keeping the border sizes the same - unless `outer` is too
+    /// small to fit them, in which case all borders are reduced by the same factor until they fit, per CSS
+    /// [border-image-width].  Unlike [with_outer], this never fails.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let src = Dimensions { outer: Rect::xywh(0, 0, 30, 30), inner: Rect::xywh(10, 5, 10, 20) }.validate().unwrap();
+    /// let dst = src.with_outer_shrinking(Rect::xywh(0, 0, 10, 100).validate().unwrap());
+    /// assert_eq!(dst.borders(), Borders { left: 5, right: 5, top: 2, bottom: 2 });
+    /// ```
+    /// 
+    /// [border-image-width]:   https://www.w3.org/TR/css-backgrounds-3/#border-image-width
+    /// [with_outer]:           #method.with_outer
+    #[must_use] pub fn with_outer_shrinking(&self, outer: impl Into<ValidRect<S>>) -> Self where S: RatioScalar {
+        with_borders_shrinking(outer.into(), self.borders())
+    }
+
     /// Create a new 9-slice with specified inner dimensions, keeping the border sizes the same.
     /// 
     /// May panic on overflow/underflow.
@@ -140,17 +440,102 @@ impl<S: Scalar> ValidDimensions<S> {
     }
 }
 
+/// What to do when a destination is too small to fit its borders.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let src = Dimensions { outer: Rect::xywh(0, 0, 30, 30), inner: Rect::xywh(10, 10, 10, 10) }.validate().unwrap();
+/// let tiny = Rect::xywh(0, 0, 10, 30).validate().unwrap();
+/// 
+/// assert!(src.with_outer_policy(tiny, OverflowPolicy::Error).is_err());
+/// assert_eq!(src.with_outer_policy(tiny, OverflowPolicy::ClampCenterToZero).unwrap().borders(), Borders { left: 5, right: 5, top: 10, bottom: 10 });
+/// assert_eq!(src.with_outer_policy(tiny, OverflowPolicy::ShrinkBordersProportionally).unwrap().borders(), Borders { left: 5, right: 5, top: 5, bottom: 5 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OverflowPolicy {
+    /// Return `Err`.  The behavior of [ValidDimensions::with_outer].
+    /// 
+    /// [ValidDimensions::with_outer]:  struct.ValidDimensions.html#method.with_outer
+    Error,
+
+    /// Collapse the center to zero size along the overflowing axis, splitting the overlap between opposing borders.
+    /// Borders along the other axis are unaffected.
+    ClampCenterToZero,
+
+    /// Reduce all borders by the same factor until they fit, per CSS.  The behavior of [ValidDimensions::with_outer_shrinking].
+    /// 
+    /// [ValidDimensions::with_outer_shrinking]:    struct.ValidDimensions.html#method.with_outer_shrinking
+    ShrinkBordersProportionally,
+}
+
+impl core::default::Default for OverflowPolicy { fn default() -> Self { OverflowPolicy::Error } }
+
+/// Fit non-negative `borders` within `outer`, or return Err if they don't fit.
+fn with_borders<S: Scalar>(outer: ValidRect<S>, borders: Borders<S>) -> Result<ValidDimensions<S>, Error> {
+    if borders.left + borders.right > outer.width()  { return err("Resulting dimensions would have a negative center width"); }
+    if borders.top + borders.bottom > outer.height() { return err("Resulting dimensions would have a negative center height"); }
+    Ok(Dimensions {
+        inner: outer.shrink(&borders),
+        outer: *outer,
+    }.debug_assert_valid())
+}
+
+/// Fit non-negative `borders` within `outer`, reducing all of them by the same factor if they don't fit.
+fn with_borders_shrinking<S: RatioScalar>(outer: ValidRect<S>, b: Borders<S>) -> ValidDimensions<S> {
+    let (width, height) = (outer.width(), outer.height());
+    let (horizontal, vertical) = (b.left + b.right, b.top + b.bottom);
+
+    // Reduce all borders by the smallest of width/horizontal and height/vertical, if either is < 1.
+    let h_overflow = width < horizontal;
+    let v_overflow = height < vertical;
+    let (num, den) = match (h_overflow, v_overflow) {
+        (false, false)  => return Dimensions { inner: outer.shrink(&b), outer: *outer }.debug_assert_valid(),
+        (true,  false)  => (width, horizontal),
+        (false, true)   => (height, vertical),
+        (true,  true)   => if width * vertical < height * horizontal { (width, horizontal) } else { (height, vertical) },
+    };
+    let scale = |border: S| border * num / den; // den > num ≥ 0
+    let borders = Borders { left: scale(b.left), right: scale(b.right), top: scale(b.top), bottom: scale(b.bottom) };
+    Dimensions { inner: outer.shrink(&borders), outer: *outer }.debug_assert_valid()
+}
+
+/// Fit non-negative `borders` within `outer` per `policy`.
+/// 
+/// Never subtracts a border from a coordinate it doesn't fit within, so unsigned scalars won't underflow.
+fn fit<S: RatioScalar>(outer: ValidRect<S>, borders: Borders<S>, policy: OverflowPolicy) -> Result<ValidDimensions<S>, Error> {
+    match policy {
+        OverflowPolicy::Error                       => with_borders(outer, borders),
+        OverflowPolicy::ShrinkBordersProportionally => Ok(with_borders_shrinking(outer, borders)),
+        OverflowPolicy::ClampCenterToZero           => {
+            let two = S::from_count(2);
+            let clamp = |start: S, end: S, border_start: S, border_end: S| {
+                let len = end - start;
+                if border_start + border_end <= len { return (start + border_start, end - border_end); }
+                let a = if border_start < len { start + border_start } else { end };
+                let b = if border_end   < len { end   - border_end   } else { start };
+                let mid = b + (a - b) / two;
+                (mid, mid)
+            };
+            let (left, right) = clamp(outer.left, outer.right,  borders.left, borders.right);
+            let (top, bottom) = clamp(outer.top,  outer.bottom, borders.top,  borders.bottom);
+            Ok(Dimensions { outer: *outer, inner: Rect { left, right, top, bottom } }.debug_assert_valid())
+        },
+    }
+}
+
 
 
 #[test] fn dims_int_test() {
-    use std::mem::swap;
+    use core::mem::swap;
 
     let mut slice = Dimensions {
         outer: [0..10, 0..100].into(),
         inner: [1.. 8, 10..80].into(),
     };
 
-    assert_eq!(slice.validate().unwrap().borders(), Rect { left: 1, right: 2, top: 10, bottom: 20 });
+    assert_eq!(slice.validate().unwrap().borders(), Borders { left: 1, right: 2, top: 10, bottom: 20 });
     assert_eq!(slice.validate().unwrap().outer(),   Rect::from([0..10, 0..100]));
     assert_eq!(slice.validate().unwrap().inner(),   Rect::from([1.. 8, 10..80]));
 
@@ -161,16 +546,125 @@ impl<S: Scalar> ValidDimensions<S> {
     assert!(slice.validate().is_ok());
 }
 
+#[cfg(feature = "unsigned-scalar")] #[test] fn dims_unsigned_test() {
+    let src = Dimensions { outer: Rect::xywh(0_u32, 0, 30, 30), inner: Rect::xywh(10, 10, 10, 10) }.validate().unwrap();
+    let tiny = Rect::xywh(0_u32, 0, 10, 6).validate().unwrap();
+    assert!(src.with_outer_policy(tiny, OverflowPolicy::Error).is_err());
+    assert_eq!(src.with_outer_policy(tiny, OverflowPolicy::ClampCenterToZero).unwrap().inner(), Rect::xywh(5, 3, 0, 0));
+    assert_eq!(src.with_outer_policy(tiny, OverflowPolicy::ShrinkBordersProportionally).unwrap().borders(), Borders { left: 3, right: 3, top: 3, bottom: 3 });
+
+    let overlapping = Dimensions { outer: Rect::xywh(0_u32, 0, 10, 10), inner: Rect { left: 8, right: 2, top: 0, bottom: 10 } };
+    assert_eq!(overlapping.validate_with(OverflowPolicy::ClampCenterToZero).unwrap().inner(), Rect { left: 5, right: 5, top: 0, bottom: 10 });
+    let inverted = Dimensions { outer: Rect::xywh(5_u32, 0, 10, 10), inner: Rect::xywh(0, 0, 10, 10) };
+    assert!(inverted.validate_with(OverflowPolicy::ClampCenterToZero).is_err());
+}
+
+#[test] fn dims_from_outer_and_borders_test() {
+    let outer = Rect::xywh(10.0, 20.0, 30.0, 40.0);
+    let dims = Dimensions::from_outer_and_borders(outer, Borders { left: 1.0, right: 2.0, top: 3.0, bottom: 4.0 }).unwrap();
+    assert_eq!(dims.outer, outer);
+    assert_eq!(dims.inner, Rect { left: 11.0, right: 38.0, top: 23.0, bottom: 56.0 });
+    assert_eq!(dims.borders(), Borders { left: 1.0, right: 2.0, top: 3.0, bottom: 4.0 });
+
+    assert!(Dimensions::from_outer_and_borders(Rect::xywh(0.0, 0.0, 30.0, 7.0), Borders::symmetric(15.0, 3.5)).is_ok());
+    assert!(Dimensions::from_outer_and_borders(Rect::xywh(0.0, 0.0, 30.0, 6.0), Borders::symmetric(15.0, 3.5)).is_err());
+    assert!(Dimensions::from_outer_and_borders(Rect::xywh(0.0, 0.0, -1.0, 10.0), Borders::uniform(0.0)).is_err());
+    assert!(Dimensions::from_outer_and_borders(outer, Borders { left: -1.0, right: 0.0, top: 0.0, bottom: 0.0 }).is_err());
+    assert!(Dimensions::from_outer_and_borders(outer, Borders::uniform(f64::NAN)).is_err());
+}
+
+#[test] fn dims_uniform_symmetric_border_test() {
+    let outer = Rect::xywh(0, 0, 20, 10);
+    assert_eq!(ValidDimensions::uniform_border(outer, 3).unwrap(), Dimensions::from_outer_and_borders(outer, Borders::uniform(3)).unwrap());
+    assert_eq!(ValidDimensions::uniform_border(outer, 5).unwrap().inner(), Rect::xywh(5, 5, 10, 0));
+    assert!(ValidDimensions::uniform_border(outer, 6).is_err());
+    assert!(ValidDimensions::uniform_border(outer, -1).is_err());
+
+    assert_eq!(ValidDimensions::symmetric_border(outer, 10, 0).unwrap().inner(), Rect::xywh(10, 0, 0, 10));
+    assert_eq!(ValidDimensions::symmetric_border(outer, 4, 2).unwrap().borders(), Borders { left: 4, right: 4, top: 2, bottom: 2 });
+    assert!(ValidDimensions::symmetric_border(outer, 2, 6).is_err());
+}
+
+#[test] fn dims_natural_size_test() {
+    let src = Dimensions { outer: Rect::xywh(5, 5, 30, 20), inner: Rect::xywh(10, 10, 10, 10) }.validate().unwrap();
+    assert_eq!(src.natural_size(), [30, 20]);
+    assert_eq!(src.natural_size_scaled(3), [90, 60]);
+}
+
+#[test] fn dims_content_rect_test() {
+    let dims = Dimensions { outer: Rect::xywh(0, 0, 30, 20), inner: Rect::xywh(5, 5, 20, 10) }.validate().unwrap();
+    assert_eq!(dims.content_rect(), Rect::xywh(5, 5, 20, 10));
+    assert_eq!(dims.content_rect_padded(&Borders { left: 1, right: 2, top: 3, bottom: 4 }).unwrap(), Rect::xywh(6, 8, 17, 3));
+    assert_eq!(dims.content_rect_padded(&Borders { left: -5, right: 0, top: 0, bottom: 0 }).unwrap(), Rect::xywh(0, 5, 25, 10));
+    assert!(dims.content_rect_padded(&Borders { left: 0, right: 0, top: 6, bottom: 6 }).is_err());
+}
+
+#[test] fn dims_cell_at_test() {
+    let dims = Dimensions { outer: Rect::xywh(10, 10, 30, 30), inner: Rect::xywh(20, 20, 10, 10) }.validate().unwrap();
+    for cell in Cell::ALL.iter().copied() {
+        let r = dims.cell(cell);
+        assert_eq!(dims.cell_at(r.left, r.top), Some(cell));
+        assert_eq!(dims.cell_at(r.right-1, r.bottom-1), Some(cell));
+    }
+    assert_eq!(dims.cell_at( 9, 20), None);
+    assert_eq!(dims.cell_at(40, 20), None);
+    assert_eq!(dims.cell_at(20,  9), None);
+    assert_eq!(dims.cell_at(20, 40), None);
+
+    let empty_center = Dimensions { outer: Rect::xywh(0.0, 0.0, 2.0, 2.0), inner: Rect::xywh(1.0, 1.0, 0.0, 0.0) }.validate().unwrap();
+    assert_eq!(empty_center.cell_at(1.0, 1.0), Some(Cell::BottomRight));
+    assert_eq!(empty_center.cell_at(f32::NAN, 1.0), None);
+}
+
+#[test] fn dims_with_outer_shrinking_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 30, 30), inner: Rect::xywh(10, 5, 10, 15) }.validate().unwrap(); // borders: 10, 10, 5, 10
+    let shrink = |w, h| src.with_outer_shrinking(Rect::xywh(100, 100, w, h).validate().unwrap());
+
+    assert_eq!(shrink(50, 50).borders(), src.borders());
+    assert_eq!(shrink(20, 15).borders(), src.borders());
+    assert_eq!(shrink(10, 15).borders(), Borders { left: 5, right: 5, top: 2, bottom: 5 });     // horizontal:  ½
+    assert_eq!(shrink(40,  3).borders(), Borders { left: 2, right: 2, top: 1, bottom: 2 });     // vertical:    ⅕
+    assert_eq!(shrink(10,  3).borders(), Borders { left: 2, right: 2, top: 1, bottom: 2 });     // both:        min(½, ⅕)
+    assert_eq!(shrink( 0,  0).borders(), Borders { left: 0, right: 0, top: 0, bottom: 0 });
+    assert_eq!(shrink(10,  3).outer, Rect::xywh(100, 100, 10, 3));
+
+    let src = Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 4.0), inner: Rect::xywh(1.0, 1.0, 2.0, 2.0) }.validate().unwrap();
+    assert_eq!(src.with_outer_shrinking(Rect::xywh(0.0, 0.0, 1.0, 8.0).validate().unwrap()).borders(), Borders { left: 0.5, right: 0.5, top: 0.5, bottom: 0.5 });
+    let none = Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 4.0), inner: Rect::xywh(0.0, 0.0, 4.0, 4.0) }.validate().unwrap();
+    assert_eq!(none.with_outer_shrinking(Rect::xywh(0.0, 0.0, 0.0, 0.0).validate().unwrap()).borders(), Borders::default());
+}
+
+#[test] fn dims_overflow_policy_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 30, 30), inner: Rect::xywh(10, 5, 10, 15) }.validate().unwrap(); // borders: 10, 10, 5, 10
+    let fit = |w, h, policy| src.with_outer_policy(Rect::xywh(100, 100, w, h).validate().unwrap(), policy).map(|d| d.borders());
+
+    for policy in [OverflowPolicy::Error, OverflowPolicy::ClampCenterToZero, OverflowPolicy::ShrinkBordersProportionally].iter().copied() {
+        assert_eq!(fit(20, 15, policy).unwrap(), src.borders());
+    }
+    assert!(fit(19, 15, OverflowPolicy::Error).is_err());
+    assert_eq!(fit(10, 15, OverflowPolicy::ClampCenterToZero).unwrap(), Borders { left: 5, right: 5, top: 5, bottom: 10 });
+    assert_eq!(fit(10, 3, OverflowPolicy::ClampCenterToZero).unwrap(), Borders { left: 5, right: 5, top: 1, bottom: 2 });
+    assert_eq!(fit(10, 3, OverflowPolicy::ShrinkBordersProportionally).unwrap(), Borders { left: 2, right: 2, top: 1, bottom: 2 });
+
+    let undersized = Dimensions { outer: Rect::xywh(0, 0, 10, 10), inner: Rect { left: 8, right: 4, top: 2, bottom: 8 } };
+    assert!(undersized.validate().is_err());
+    assert!(undersized.validate_with(OverflowPolicy::Error).is_err());
+    assert_eq!(undersized.validate_with(OverflowPolicy::ClampCenterToZero).unwrap().inner, Rect { left: 6, right: 6, top: 2, bottom: 8 });
+    assert_eq!(undersized.validate_with(OverflowPolicy::ShrinkBordersProportionally).unwrap().borders(), Borders { left: 5, right: 4, top: 1, bottom: 1 });
+    let negative_border = Dimensions { outer: Rect::xywh(0, 0, 10, 10), inner: Rect { left: -1, right: 4, top: 2, bottom: 8 } };
+    assert!(negative_border.validate_with(OverflowPolicy::ClampCenterToZero).is_err());
+}
+
 #[test] fn dims_f32_test() {
-    use std::mem::swap;
-    use std::f32::NAN;
+    use core::mem::swap;
+    const NAN : f32 = f32::NAN;
     
     let mut slice = Dimensions {
         outer: [0.0..10.0, 0.0..100.0].into(),
         inner: [1.0.. 8.0, 10.0..80.0].into(),
     };
 
-    assert_eq!(slice.validate().unwrap().borders(), Rect { left: 1.0, right: 2.0, top: 10.0, bottom: 20.0 });
+    assert_eq!(slice.validate().unwrap().borders(), Borders { left: 1.0, right: 2.0, top: 10.0, bottom: 20.0 });
     assert_eq!(slice.validate().unwrap().outer(),   Rect::from([0.0..10.0, 0.0..100.0]));
     assert_eq!(slice.validate().unwrap().inner(),   Rect::from([1.0.. 8.0, 10.0..80.0]));
 
@@ -190,3 +684,17 @@ impl<S: Scalar> ValidDimensions<S> {
     slice.inner.bottom = NAN; assert!(slice.validate().is_err()); slice.inner.bottom =  80.0; assert!(slice.validate().is_ok());
     slice.outer.bottom = NAN; assert!(slice.validate().is_err()); slice.outer.bottom = 100.0; assert!(slice.validate().is_ok());
 }
+
+#[cfg(feature = "serde")] #[test] fn valid_dimensions_serde_test() {
+    let valid = Dimensions { outer: Rect { left: 0, right: 10, top: 0, bottom: 10 }, inner: Rect { left: 2, right: 8, top: 3, bottom: 7 } }.validate().unwrap();
+    let json = serde_json::to_string(&valid).unwrap();
+    assert_eq!(json, serde_json::to_string(&*valid).unwrap());
+    assert_eq!(serde_json::from_str::<ValidDimensions<i32>>(&json).unwrap(), valid);
+
+    let inverted = r#"{ "outer": { "left": 0, "right": 10, "top": 0, "bottom": 10 }, "inner": { "left": 8, "right": 2, "top": 3, "bottom": 7 } }"#;
+    assert!(serde_json::from_str::<Dimensions<i32>>(inverted).is_ok());
+    assert!(serde_json::from_str::<ValidDimensions<i32>>(inverted).unwrap_err().to_string().contains("inner.left ≤ inner.right"));
+
+    assert!(serde_json::from_str::<ValidRect<f32>>(r#"{ "left": 1.0, "right": 2.0, "top": 3.0, "bottom": 4.0 }"#).is_ok());
+    assert!(serde_json::from_str::<ValidRect<f32>>(r#"{ "left": 2.0, "right": 1.0, "top": 3.0, "bottom": 4.0 }"#).is_err());
+}
@@ -2,10 +2,10 @@
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Error(ErrorKind);
 
-impl std::error::Error for Error {}
+#[cfg(feature = "std")] impl std::error::Error for Error {}
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self.0 {
             ErrorKind::Generic(msg) => write!(fmt, "{}", msg),
         }
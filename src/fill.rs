@@ -0,0 +1,106 @@
+use super::*;
+
+/// Which way a partially filled [Layout] fills up.  See [Layout::each_dst_src_filled].
+/// 
+/// [Layout]:                       struct.Layout.html
+/// [Layout::each_dst_src_filled]:  struct.Layout.html#method.each_dst_src_filled
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FillDirection {
+    /// Fill from the left edge towards the right edge.
+    LeftToRight,
+
+    /// Fill from the right edge towards the left edge.
+    RightToLeft,
+
+    /// Fill from the top edge towards the bottom edge.
+    TopToBottom,
+
+    /// Fill from the bottom edge towards the top edge.
+    BottomToTop,
+}
+
+impl core::default::Default for FillDirection { fn default() -> Self { FillDirection::LeftToRight } }
+
+impl FillDirection {
+    /// The portion of `outer` filled to `value / max`.  `value` is clamped to `0 ..= max`.
+    #[must_use] pub fn filled<S: RatioScalar>(self, outer: &ValidRect<S>, value: S, max: S) -> ValidRect<S> {
+        let extent = |len: S| fraction_of(len, value, max);
+        let mut r = **outer;
+        match self {
+            FillDirection::LeftToRight => r.right   = r.left   + extent(outer.width()),
+            FillDirection::RightToLeft => r.left    = r.right  - extent(outer.width()),
+            FillDirection::TopToBottom => r.bottom  = r.top    + extent(outer.height()),
+            FillDirection::BottomToTop => r.top     = r.bottom - extent(outer.height()),
+        }
+        r.debug_assert_valid()
+    }
+}
+
+/// `len * value / max`, with `value` clamped to `0 ..= max`.  Returns 0 if `max` isn't positive, or either is NaN.
+pub(crate) fn fraction_of<S: RatioScalar>(len: S, value: S, max: S) -> S {
+    let zero = S::default();
+    if !(zero < max) || !(zero < value) { zero }
+    else if max <= value { len }
+    else { len * value / max }
+}
+
+impl<S: RatioScalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Enumerate the destination and source rectangles for a layout filled to `value / max` in `direction` - e.g. for
+    /// 9-sliced progress or health bars, without needing an external scissor rect.
+    /// 
+    /// Tiles are clipped as per [each_dst_src_clipped], with source rectangles trimmed proportionally.
+    /// Pass `max = 1.0` to use a plain `0.0 ..= 1.0` fraction with floating point scalars.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 4, 4), inner: Rect::xywh(1, 1, 2, 2) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 10, 4), inner: Rect::xywh(1, 1, 8, 2) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let (hp, max_hp) = (50, 100);
+    /// let mut rects = Vec::new();
+    /// layout.each_dst_src_filled(hp, max_hp, FillDirection::LeftToRight, |dst, src| rects.push((Rect::from(dst), Rect::from(src))));
+    /// assert_eq!(rects.len(), 3 * 2); // left column + partial center column
+    /// assert_eq!(rects[1], (Rect::xywh(1, 0, 4, 1), Rect::xywh(1, 0, 1, 1)));
+    /// ```
+    /// 
+    /// [each_dst_src_clipped]: #method.each_dst_src_clipped
+    pub fn each_dst_src_filled(&self, value: S, max: S, direction: FillDirection, each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
+        let clip = direction.filled(&self.dst.outer(), value, max);
+        self.each_dst_src_clipped(&clip, each_dst_src);
+    }
+}
+
+
+
+#[test] fn fill_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 4.0), inner: Rect::xywh(1.0, 1.0, 2.0, 2.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 10.0), inner: Rect::xywh(1.0, 1.0, 2.0, 8.0) },
+        style: Style::default(),
+    }.validate().unwrap();
+
+    let collect = |value: f32, direction: FillDirection| {
+        let mut rects = Vec::new();
+        layout.each_dst_src_filled(value, 1.0, direction, |dst, src| rects.push((Rect::from(dst), Rect::from(src))));
+        rects
+    };
+
+    assert!(collect(0.0,        FillDirection::BottomToTop).is_empty());
+    assert!(collect(-1.0,       FillDirection::BottomToTop).is_empty());
+    assert!(collect(f32::NAN,   FillDirection::BottomToTop).is_empty());
+
+    let mut full = Vec::new();
+    layout.each_dst_src(|dst, src| full.push((Rect::from(dst), Rect::from(src))));
+    assert_eq!(collect(1.0, FillDirection::BottomToTop), full);
+    assert_eq!(collect(2.0, FillDirection::TopToBottom), full);
+
+    let half = collect(0.5, FillDirection::BottomToTop);
+    assert_eq!(half.len(), 3 * 2);
+    assert_eq!(half[0], (Rect { left: 0.0, right: 1.0, top: 5.0, bottom: 9.0 }, Rect { left: 0.0, right: 1.0, top: 2.0, bottom: 3.0 }));
+    assert_eq!(half[3], (Rect { left: 0.0, right: 1.0, top: 9.0, bottom: 10.0 }, Rect { left: 0.0, right: 1.0, top: 3.0, bottom: 4.0 }));
+}
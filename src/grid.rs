@@ -0,0 +1,320 @@
+use super::*;
+use crate::npatch::segment_spans;
+
+/// The dimensions of an N×M grid slice:  sorted x and y cuts (including the outer edges), and which columns and rows
+/// stretch.  A generalization of [Dimensions], for skins with more than one stretchable band per axis - e.g. tabbed
+/// panels, or title bars with button wells.
+/// 
+/// ```text
+/// xs[0]     xs[1]         xs[2]  xs[3]      xs[4]
+///  ┌─────────┬─────────────┬──────┬──────────┐┈┈ ys[0]
+///  │  fixed  │   stretch   │fixed │ stretch  │
+///  ├─────────┼─────────────┼──────┼──────────┤┈┈ ys[1]
+///  │         │             │      │          │
+///  └─────────┴─────────────┴──────┴──────────┘┈┈ ys[2]
+/// ```
+/// 
+/// [Dimensions]:   struct.Dimensions.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridDimensions<'a, S: RatioScalar> {
+    xs:                 &'a [S],
+    ys:                 &'a [S],
+    stretch_columns:    &'a [bool],
+    stretch_rows:       &'a [bool],
+}
+
+impl<'a, S: RatioScalar> GridDimensions<'a, S> {
+    /// Create new grid dimensions.  Returns `Err` if `xs` or `ys` aren't sorted (or contain NaN), or if there isn't
+    /// exactly one stretch flag per column (`xs.len() - 1`) and row (`ys.len() - 1`).
+    pub fn new(xs: &'a [S], ys: &'a [S], stretch_columns: &'a [bool], stretch_rows: &'a [bool]) -> Result<Self, Error> {
+        if xs.len() != stretch_columns.len() + 1 { return err("Expected xs.len() == stretch_columns.len() + 1"); }
+        if ys.len() != stretch_rows.len()    + 1 { return err("Expected ys.len() == stretch_rows.len() + 1"); }
+        if !xs.iter().all(|x| S::default() <= *x || *x < S::default()) || !xs.windows(2).all(|w| w[0] <= w[1]) { return err("Expected sorted, non-NaN xs"); }
+        if !ys.iter().all(|y| S::default() <= *y || *y < S::default()) || !ys.windows(2).all(|w| w[0] <= w[1]) { return err("Expected sorted, non-NaN ys"); }
+        Ok(Self { xs, ys, stretch_columns, stretch_rows })
+    }
+
+    #[must_use] pub fn xs(&self) -> &'a [S] { self.xs }
+    #[must_use] pub fn ys(&self) -> &'a [S] { self.ys }
+    #[must_use] pub fn stretch_columns(&self) -> &'a [bool] { self.stretch_columns }
+    #[must_use] pub fn stretch_rows(&self) -> &'a [bool] { self.stretch_rows }
+    #[must_use] pub fn columns(&self) -> usize { self.stretch_columns.len() }
+    #[must_use] pub fn rows(&self) -> usize { self.stretch_rows.len() }
+
+    /// The rectangle enclosing the entire grid.
+    #[must_use] pub fn outer(&self) -> ValidRect<S> {
+        let (xs, ys) = (self.xs, self.ys);
+        Rect { left: xs[0], right: xs[xs.len()-1], top: ys[0], bottom: ys[ys.len()-1] }.debug_assert_valid()
+    }
+
+    /// The rectangle of a single grid cell.  Panics if `column` or `row` is out of bounds.
+    #[must_use] pub fn cell(&self, column: usize, row: usize) -> ValidRect<S> {
+        Rect { left: self.xs[column], right: self.xs[column+1], top: self.ys[row], bottom: self.ys[row+1] }.debug_assert_valid()
+    }
+
+    fn columns_segments(&self) -> impl Iterator<Item = Segment<S>> + Clone + 'a { segments(self.xs, self.stretch_columns) }
+    fn rows_segments   (&self) -> impl Iterator<Item = Segment<S>> + Clone + 'a { segments(self.ys, self.stretch_rows) }
+}
+
+fn segments<'a, S: RatioScalar>(cuts: &'a [S], stretch: &'a [bool]) -> impl Iterator<Item = Segment<S>> + Clone + 'a {
+    cuts.windows(2).zip(stretch.iter()).map(|(w, stretch)| if *stretch { Segment::Stretch(w[1] - w[0]) } else { Segment::Fixed(w[1] - w[0]) })
+}
+
+/// A single destination/source rectangle pair emitted by a [GridLayout].
+/// 
+/// [GridLayout]:   struct.GridLayout.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridTile<S: RatioScalar> {
+    /// Which grid column this tile belongs to.
+    pub column: usize,
+
+    /// Which grid row this tile belongs to.
+    pub row:    usize,
+
+    /// Where to draw.
+    pub dst:    ValidRect<S>,
+
+    /// What to draw.
+    pub src:    ValidRect<S>,
+
+    /// If `src` should be flipped along an axis when drawn into `dst`, e.g. by [Scale::MirrorRepeat].
+    /// 
+    /// [Scale::MirrorRepeat]:  enum.Scale.html#variant.MirrorRepeat
+    pub mirror: Axises<bool>,
+}
+
+/// Describe an N×M grid layout to render with.
+/// 
+/// Fixed columns and rows are drawn at their source size.  Any extra destination length is shared between stretch
+/// columns (or rows) in proportion to their source lengths, and filled per `scale`.  If the destination is too small to
+/// fit all fixed bands (or there are no stretch bands), the fixed bands are scaled proportionally instead.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// // A title bar:  fixed caps, a stretched title area, a fixed button well, and a stretched spacer.
+/// let xs = [0, 4, 8, 14, 16, 20];
+/// let ys = [0, 4, 12];
+/// let grid = GridLayout {
+///     dst:    Rect::xywh(0, 0, 38, 12).validate().unwrap(),
+///     src:    GridDimensions::new(&xs, &ys, &[false, true, false, true, false], &[false, true]).unwrap(),
+///     scale:  Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch },
+/// };
+/// 
+/// let mut tiles = Vec::new();
+/// grid.each_tile(|tile| tiles.push(*tile));
+/// assert_eq!(tiles.len(), 5 * 2);
+/// assert_eq!(tiles[1].dst, Rect::xywh( 4, 0, 16, 4)); // stretch:  4 + 24 * 4/6
+/// assert_eq!(tiles[2].dst, Rect::xywh(20, 0,  6, 4)); // fixed button well
+/// assert_eq!(tiles[3].dst, Rect::xywh(26, 0,  8, 4)); // stretch:  2 + 24 * 2/6
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridLayout<'a, S: RatioScalar> {
+    pub dst:    ValidRect<S>,
+    pub src:    GridDimensions<'a, S>,
+
+    /// How stretch columns (`horizontal`) and rows (`vertical`) are filled.
+    pub scale:  Axises<Scale>,
+}
+
+impl<S: RatioScalar> GridLayout<'_, S> {
+    /// Enumerate the [GridTile]s for a layout, in row-major order of grid cells.
+    /// 
+    /// [GridTile]: struct.GridTile.html
+    pub fn each_tile(&self, each_tile: impl FnMut(&GridTile<S>)) {
+        let (src, scale) = (&self.src, self.scale);
+        self.each_tile_with(|column, row| Axises {
+            horizontal: if src.stretch_columns[column] { scale.horizontal } else { Scale::Stretch },
+            vertical:   if src.stretch_rows[row]       { scale.vertical   } else { Scale::Stretch },
+        }, each_tile);
+    }
+
+    /// Enumerate the [GridTile]s for a layout, in row-major order of grid cells, scaling each cell per
+    /// `cell_scale(column, row)` instead of `self.scale` - e.g. to repeat one band of a complex skin while stretching another.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let (xs, ys) = ([0, 2, 4, 6, 8], [0, 2]);
+    /// let grid = GridLayout {
+    ///     dst:    Rect::xywh(0, 0, 16, 2).validate().unwrap(),
+    ///     src:    GridDimensions::new(&xs, &ys, &[false, true, true, false], &[false]).unwrap(),
+    ///     scale:  Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch },
+    /// };
+    /// 
+    /// let mut tiles = Vec::new();
+    /// grid.each_tile_with(|column, _row| Axises {
+    ///     horizontal: if column == 1 { Scale::Repeat(Partial::Split) } else { Scale::Stretch },
+    ///     vertical:   Scale::Stretch,
+    /// }, |tile| tiles.push((tile.column, tile.dst.left .. tile.dst.right)));
+    /// assert_eq!(tiles, vec![(0, 0..2), (1, 2..4), (1, 4..6), (1, 6..8), (2, 8..14), (3, 14..16)]);
+    /// ```
+    /// 
+    /// [GridTile]: struct.GridTile.html
+    pub fn each_tile_with(&self, mut cell_scale: impl FnMut(usize, usize) -> Axises<Scale>, mut each_tile: impl FnMut(&GridTile<S>)) {
+        let (src, dst) = (&self.src, &self.dst);
+        for (row, (dst_y, src_y)) in segment_spans(src.rows_segments(), src.ys[0], dst.top .. dst.bottom).enumerate() {
+            for (column, (dst_x, src_x)) in segment_spans(src.columns_segments(), src.xs[0], dst.left .. dst.right).enumerate() {
+                let scale = cell_scale(column, row);
+                let xs = scale.horizontal.spans(dst_x, src_x);
+                for y in scale.vertical.spans(dst_y.clone(), src_y.clone()) {
+                    for x in xs.clone() {
+                        each_tile(&GridTile {
+                            column, row,
+                            dst:    Rect::<S>::from([x.dst, y.dst.clone()]).debug_assert_valid(),
+                            src:    Rect::<S>::from([x.src, y.src.clone()]).debug_assert_valid(),
+                            mirror: Axises { horizontal: x.mirror, vertical: y.mirror },
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Enumerate the destination and source rectangles for a layout.
+    /// 
+    /// Mirroring (e.g. from [Scale::MirrorRepeat]) is not reported - use [each_tile] if you need it.
+    /// 
+    /// [Scale::MirrorRepeat]:  enum.Scale.html#variant.MirrorRepeat
+    /// [each_tile]:            #method.each_tile
+    pub fn each_dst_src(&self, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
+        self.each_tile(|tile| each_dst_src(&tile.dst, &tile.src));
+    }
+}
+
+
+
+/// A fixed-shape grid slice with `C` columns and `R` rows, laid out entirely on the stack - e.g. 5×5 (25-slice) window
+/// chrome in hot paths.  Segments are distributed as per [NPatch].
+/// 
+/// ```rust
+/// use nines::*;
+/// use nines::Segment::*;
+/// 
+/// let bands = [Fixed(2), Stretch(1), Fixed(4), Stretch(1), Fixed(2)];
+/// let chrome = GridSlice::new(Rect::xywh(0, 0, 10, 10).validate().unwrap(), bands, bands).unwrap();
+/// 
+/// let rects : [[(ValidRect<i32>, ValidRect<i32>); 5]; 5] = chrome.layout(&Rect::xywh(0, 0, 20, 30).validate().unwrap());
+/// assert_eq!(rects[0][1].0, Rect::xywh(2, 0, 6, 2));  // top edge, left of the center notch
+/// assert_eq!(rects[0][1].1, Rect::xywh(2, 0, 1, 2));
+/// assert_eq!(rects[4][4].0, Rect::xywh(18, 28, 2, 2)); // bottom right corner
+/// ```
+/// 
+/// [NPatch]:   struct.NPatch.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridSlice<S: RatioScalar, const C: usize, const R: usize> {
+    src:        ValidRect<S>,
+    columns:    [Segment<S>; C],
+    rows:       [Segment<S>; R],
+}
+
+impl<S: RatioScalar, const C: usize, const R: usize> GridSlice<S, C, R> {
+    /// Create a new grid slice.  Returns `Err` if any segment has a negative (or NaN) length, or if the segments of an
+    /// axis don't sum to the width/height of `src`.
+    pub fn new(src: ValidRect<S>, columns: [Segment<S>; C], rows: [Segment<S>; R]) -> Result<Self, Error> {
+        NPatch::new(src, &columns[..], &rows[..])?;
+        Ok(Self { src, columns, rows })
+    }
+
+    #[must_use] pub fn src(&self) -> ValidRect<S> { self.src }
+    #[must_use] pub fn columns(&self) -> &[Segment<S>; C] { &self.columns }
+    #[must_use] pub fn rows(&self) -> &[Segment<S>; R] { &self.rows }
+
+    /// Get the destination and source rectangles of every grid cell, indexed `[row][column]`.
+    pub fn layout(&self, dst: &ValidRect<S>) -> [[(ValidRect<S>, ValidRect<S>); C]; R] {
+        let mut a = [[(ValidRect::default(), ValidRect::default()); C]; R];
+        let rows = segment_spans(self.rows.iter().copied(), self.src.top, dst.top .. dst.bottom);
+        for (row, (dst_y, src_y)) in a.iter_mut().zip(rows) {
+            let columns = segment_spans(self.columns.iter().copied(), self.src.left, dst.left .. dst.right);
+            for (dst_src, (dst_x, src_x)) in row.iter_mut().zip(columns) {
+                *dst_src = (
+                    Rect::<S>::from([dst_x, dst_y.clone()]).debug_assert_valid(),
+                    Rect::<S>::from([src_x, src_y.clone()]).debug_assert_valid(),
+                );
+            }
+        }
+        a
+    }
+}
+
+
+
+#[test] fn grid_test() {
+    let xs = [10, 12, 14, 15, 18];
+    let ys = [0, 1, 3];
+    let src = GridDimensions::new(&xs, &ys, &[false, true, false, true], &[false, true]).unwrap();
+    assert_eq!((src.columns(), src.rows()), (4, 2));
+    assert_eq!(src.outer(), Rect { left: 10, right: 18, top: 0, bottom: 3 });
+    assert_eq!(src.cell(3, 1), Rect { left: 15, right: 18, top: 1, bottom: 3 });
+
+    let grid = GridLayout {
+        dst:    Rect::xywh(0, 0, 14, 5).validate().unwrap(),
+        src,
+        scale:  Axises { horizontal: Scale::Repeat(Partial::Split), vertical: Scale::Stretch },
+    };
+    let mut v = Vec::new();
+    grid.each_tile(|tile| if tile.row == 0 { v.push((tile.column, tile.dst.left .. tile.dst.right, tile.src.left .. tile.src.right)) });
+    // 6 extra split 2:3 between the stretch columns, which are then repeated
+    assert_eq!(v, vec![
+        (0, 0..2, 10..12),
+        (1, 2..4, 12..14), (1, 4..6, 12..14),
+        (2, 6..7, 14..15),
+        (3, 7..10, 15..18), (3, 10..13, 15..18), (3, 13..14, 15..16),
+    ]);
+
+    let mut rows = Vec::new();
+    grid.each_dst_src(|dst, src| if dst.left == 0 { rows.push((dst.top .. dst.bottom, src.top .. src.bottom)) });
+    assert_eq!(rows, vec![(0..1, 0..1), (1..5, 1..3)]);
+
+    assert!(GridDimensions::new(&[0, 2, 1], &ys, &[true, true], &[false, true]).is_err());
+    assert!(GridDimensions::new(&xs, &ys, &[true], &[false, true]).is_err());
+    assert!(GridDimensions::new(&xs, &ys, &[false, true, false, true], &[false]).is_err());
+    assert!(GridDimensions::new(&[f32::NAN], &[0.0], &[], &[]).is_err());
+    assert!(GridDimensions::new(&[0.0], &[0.0], &[], &[]).is_ok());
+}
+
+#[test] fn grid_slice_test() {
+    use Segment::*;
+    let grid = GridSlice::new(Rect::xywh(10, 20, 4, 3).validate().unwrap(), [Fixed(1), Stretch(2), Fixed(1)], [Fixed(1), Stretch(2)]).unwrap();
+    let rects = grid.layout(&Rect::xywh(0, 0, 8, 5).validate().unwrap());
+    let dst = |row: usize, column: usize| { let d = rects[row][column].0; (d.left .. d.right, d.top .. d.bottom) };
+    let src = |row: usize, column: usize| { let s = rects[row][column].1; (s.left .. s.right, s.top .. s.bottom) };
+    assert_eq!(dst(0, 0), (0..1, 0..1));
+    assert_eq!(dst(1, 1), (1..7, 1..5));
+    assert_eq!(dst(1, 2), (7..8, 1..5));
+    assert_eq!(src(1, 1), (11..13, 21..23));
+    assert_eq!(src(1, 2), (13..14, 21..23));
+
+    let mut npatch = Vec::new();
+    NPatch::new(grid.src(), &grid.columns()[..], &grid.rows()[..]).unwrap().each_dst_src(&Rect::xywh(0, 0, 8, 5).validate().unwrap(), |dst, src| npatch.push((*dst, *src)));
+    assert_eq!(npatch, rects.iter().flatten().copied().collect::<Vec<_>>());
+
+    assert!(GridSlice::new(Rect::xywh(0, 0, 4, 3).validate().unwrap(), [Fixed(1), Stretch(2)], [Fixed(3)]).is_err());
+}
+
+#[test] fn grid_cell_scale_test() {
+    let (xs, ys) = ([0, 2, 4], [0, 2, 4]);
+    let grid = GridLayout {
+        dst:    Rect::xywh(0, 0, 6, 6).validate().unwrap(),
+        src:    GridDimensions::new(&xs, &ys, &[true, true], &[true, true]).unwrap(),
+        scale:  Axises { horizontal: Scale::MirrorRepeat(Partial::Split), vertical: Scale::Stretch },
+    };
+
+    let mut per_band = Vec::new();
+    let mut per_cell = Vec::new();
+    grid.each_tile(|tile| per_band.push(*tile));
+    grid.each_tile_with(|_, _| grid.scale, |tile| per_cell.push(*tile));
+    assert_eq!(per_band, per_cell);
+    assert_eq!(per_band.len(), 2 * 2 * 2);
+
+    // Checkerboard of repeated and stretched cells
+    let mut counts = [[0; 2]; 2];
+    grid.each_tile_with(|column, row| if (column + row) % 2 == 0 {
+        Axises { horizontal: Scale::Repeat(Partial::Split), vertical: Scale::Repeat(Partial::Split) }
+    } else {
+        Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch }
+    }, |tile| counts[tile.row][tile.column] += 1);
+    assert_eq!(counts, [[4, 1], [1, 4]]);
+}
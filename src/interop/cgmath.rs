@@ -0,0 +1,56 @@
+//! [cgmath](https://docs.rs/cgmath/) conversions:
+//! 
+//! * [Rect] ↔ `Range<cgmath::Point2>` (top left `..` bottom right)
+//! * [Rect] ↔ `(cgmath::Point2, cgmath::Vector2)` (top left, size)
+//! * [Axises] ↔ `cgmath::Vector2` (`horizontal` is `x`, `vertical` is `y`)
+
+use crate::*;
+use ::cgmath_crate::{Point2, Vector2};
+use core::ops::Range;
+
+impl<S: Scalar> From<Range<Point2<S>>> for Rect<S> {
+    fn from(value: Range<Point2<S>>) -> Self {
+        Self { left: value.start.x, right: value.end.x, top: value.start.y, bottom: value.end.y }
+    }
+}
+
+impl<S: Scalar> From<Rect<S>> for Range<Point2<S>> {
+    fn from(value: Rect<S>) -> Self { Point2::new(value.left, value.top) .. Point2::new(value.right, value.bottom) }
+}
+
+impl<S: Scalar> From<ValidRect<S>> for Range<Point2<S>> {
+    fn from(value: ValidRect<S>) -> Self { Rect::from(value).into() }
+}
+
+impl<S: Scalar> From<(Point2<S>, Vector2<S>)> for Rect<S> {
+    fn from((origin, size): (Point2<S>, Vector2<S>)) -> Self { Self::xywh(origin.x, origin.y, size.x, size.y) }
+}
+
+impl<S: Scalar> From<Rect<S>> for (Point2<S>, Vector2<S>) {
+    fn from(value: Rect<S>) -> Self {
+        (Point2::new(value.left, value.top), Vector2::new(value.right - value.left, value.bottom - value.top))
+    }
+}
+
+impl<S: Scalar> From<ValidRect<S>> for (Point2<S>, Vector2<S>) {
+    fn from(value: ValidRect<S>) -> Self { Rect::from(value).into() }
+}
+
+impl<S: Scalar> From<Vector2<S>> for Axises<S> { fn from(value: Vector2<S>) -> Self { Self { horizontal: value.x, vertical: value.y } } }
+impl<S: Scalar> From<Axises<S>> for Vector2<S> { fn from(value: Axises<S>) -> Self { Vector2::new(value.horizontal, value.vertical) } }
+
+
+
+#[test] fn cgmath_test() {
+    let rect = Rect::xywh(1.0, 2.0, 3.0, 4.0);
+    let range : Range<Point2<f32>> = rect.into();
+    assert_eq!(range, Point2::new(1.0, 2.0) .. Point2::new(4.0, 6.0));
+    assert_eq!(Rect::from(range), rect);
+
+    let (origin, size) : (Point2<f32>, Vector2<f32>) = rect.validate().unwrap().into();
+    assert_eq!((origin, size), (Point2::new(1.0, 2.0), Vector2::new(3.0, 4.0)));
+    assert_eq!(Rect::from((origin, size)), rect);
+
+    let axises = Axises { horizontal: 1, vertical: 2 };
+    assert_eq!(Axises::from(Vector2::from(axises)), axises);
+}
@@ -0,0 +1,68 @@
+//! [embedded-graphics](https://docs.rs/embedded-graphics/) rendering:
+//! 
+//! * [Layout::draw_embedded_graphics] to draw a 9-sliced image onto any `DrawTarget`
+
+use crate::*;
+use ::embedded_graphics::{Pixel, draw_target::DrawTarget, image::GetPixel, prelude::Point};
+
+impl Layout<ValidDimensions<i32>, ValidDimensions<i32>> {
+    /// Draw `image` (e.g. an `ImageRaw`), 9-sliced per this layout, onto `target` with nearest-neighbor sampling.
+    /// 
+    /// `src` is in `image` pixels, `dst` in `target` pixels.  Source pixels outside `image` are skipped.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay, pixelcolor::BinaryColor};
+    /// 
+    /// // A 3x3 frame:  lit border, unlit center
+    /// let image = ImageRaw::<BinaryColor>::new(&[0b111_00000, 0b101_00000, 0b111_00000], 3);
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let mut display = MockDisplay::new();
+    /// layout.draw_embedded_graphics(&mut display, &image).unwrap();
+    /// display.assert_pattern(&[
+    ///     "#####",
+    ///     "#...#",
+    ///     "#...#",
+    ///     "#####",
+    /// ]);
+    /// ```
+    pub fn draw_embedded_graphics<D: DrawTarget, I: GetPixel<Color = D::Color>>(&self, target: &mut D, image: &I) -> Result<(), D::Error> {
+        let mut tiles = self.tiles();
+        while let Some(tile) = tiles.next_tile() {
+            if tile.src.width() <= 0 || tile.src.height() <= 0 { continue; }
+            let pixels = (tile.dst.top .. tile.dst.bottom).flat_map(|y| (tile.dst.left .. tile.dst.right).map(move |x| (x, y)));
+            target.draw_iter(pixels.filter_map(|(x, y)| {
+                let [sx, sy] = tile.nearest_src(x, y);
+                image.pixel(Point::new(sx, sy)).map(|color| Pixel(Point::new(x, y), color))
+            }))?;
+        }
+        Ok(())
+    }
+}
+
+
+
+#[test] fn embedded_graphics_test() {
+    use ::embedded_graphics::{image::ImageRaw, mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    // 4x3, with a half-lit 2x1 center
+    let image = ImageRaw::<BinaryColor>::new(&[0b1111_0000, 0b1101_0000, 0b1111_0000], 4);
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 4, 3), inner: Rect::xywh(1, 1, 2, 1) },
+        dst: Dimensions { outer: Rect::xywh(1, 0, 8, 3), inner: Rect::xywh(2, 1, 6, 1) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Center)),
+    }.validate().unwrap();
+
+    let mut display = MockDisplay::new();
+    layout.draw_embedded_graphics(&mut display, &image).unwrap();
+    display.assert_pattern(&[
+        " ########",
+        " ##..##.#",
+        " ########",
+    ]);
+}
@@ -0,0 +1,67 @@
+//! [euclid](https://docs.rs/euclid/) conversions, preserving euclid's unit parameter:
+//! 
+//! * [Rect] ↔ `euclid::Rect` (origin + size)
+//! * [Rect] ↔ `euclid::Box2D` (min + max)
+//! * [Dimensions] ↔ `(euclid::Rect, euclid::Rect)` (outer, inner)
+
+use crate::*;
+use ::euclid::{Box2D, Point2D, Size2D};
+
+impl<S: Scalar, U> From<::euclid::Rect<S, U>> for Rect<S> {
+    fn from(value: ::euclid::Rect<S, U>) -> Self { Self::xywh(value.origin.x, value.origin.y, value.size.width, value.size.height) }
+}
+
+impl<S: Scalar, U> From<Rect<S>> for ::euclid::Rect<S, U> {
+    fn from(value: Rect<S>) -> Self {
+        Self::new(Point2D::new(value.left, value.top), Size2D::new(value.right - value.left, value.bottom - value.top))
+    }
+}
+
+impl<S: Scalar, U> From<ValidRect<S>> for ::euclid::Rect<S, U> {
+    fn from(value: ValidRect<S>) -> Self { Rect::from(value).into() }
+}
+
+impl<S: Scalar, U> From<Box2D<S, U>> for Rect<S> {
+    fn from(value: Box2D<S, U>) -> Self { Self { left: value.min.x, top: value.min.y, right: value.max.x, bottom: value.max.y } }
+}
+
+impl<S: Scalar, U> From<Rect<S>> for Box2D<S, U> {
+    fn from(value: Rect<S>) -> Self { Self::new(Point2D::new(value.left, value.top), Point2D::new(value.right, value.bottom)) }
+}
+
+impl<S: Scalar, U> From<ValidRect<S>> for Box2D<S, U> {
+    fn from(value: ValidRect<S>) -> Self { Rect::from(value).into() }
+}
+
+impl<S: Scalar, U> From<(::euclid::Rect<S, U>, ::euclid::Rect<S, U>)> for Dimensions<S> {
+    fn from((outer, inner): (::euclid::Rect<S, U>, ::euclid::Rect<S, U>)) -> Self { Self { outer: outer.into(), inner: inner.into() } }
+}
+
+impl<S: Scalar, U> From<Dimensions<S>> for (::euclid::Rect<S, U>, ::euclid::Rect<S, U>) {
+    fn from(value: Dimensions<S>) -> Self { (value.outer.into(), value.inner.into()) }
+}
+
+impl<S: Scalar, U> From<ValidDimensions<S>> for (::euclid::Rect<S, U>, ::euclid::Rect<S, U>) {
+    fn from(value: ValidDimensions<S>) -> Self { (value.outer.into(), value.inner.into()) }
+}
+
+
+
+#[test] fn euclid_test() {
+    struct Px;
+    type ERect = ::euclid::Rect<i32, Px>;
+
+    let rect = Rect::xywh(1, 2, 3, 4);
+    let e : ERect = rect.into();
+    assert_eq!(e, ERect::new(Point2D::new(1, 2), Size2D::new(3, 4)));
+    assert_eq!(Rect::from(e), rect);
+
+    let b : Box2D<i32, Px> = rect.validate().unwrap().into();
+    assert_eq!(b, Box2D::new(Point2D::new(1, 2), Point2D::new(4, 6)));
+    assert_eq!(Rect::from(b), rect);
+
+    let dims = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) };
+    let (outer, inner) : (ERect, ERect) = dims.validate().unwrap().into();
+    assert_eq!(inner, ERect::new(Point2D::new(1, 1), Size2D::new(1, 1)));
+    assert_eq!(Dimensions::from((outer, inner)), dims);
+}
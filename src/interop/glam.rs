@@ -0,0 +1,76 @@
+//! [glam](https://docs.rs/glam/) conversions:
+//! 
+//! * [Rect] ↔ `(glam::Vec2, glam::Vec2)` / `(glam::DVec2, glam::DVec2)` (top left, bottom right)
+//! * [Axises] ↔ `glam::Vec2` / `glam::DVec2` (`horizontal` is `x`, `vertical` is `y`)
+//! * [Layout::each_quad_vec2] for quad corner positions
+
+use crate::*;
+use ::glam::{DVec2, Vec2};
+
+macro_rules! conversions {
+    ( $($s:ty => $vec2:ty),* $(,)? ) => {$(
+        impl From<($vec2, $vec2)> for Rect<$s> {
+            fn from((min, max): ($vec2, $vec2)) -> Self { Self { left: min.x, top: min.y, right: max.x, bottom: max.y } }
+        }
+
+        impl From<Rect<$s>> for ($vec2, $vec2) {
+            fn from(value: Rect<$s>) -> Self { (<$vec2>::new(value.left, value.top), <$vec2>::new(value.right, value.bottom)) }
+        }
+
+        impl From<ValidRect<$s>> for ($vec2, $vec2) {
+            fn from(value: ValidRect<$s>) -> Self { Rect::from(value).into() }
+        }
+
+        impl From<$vec2> for Axises<$s> { fn from(value: $vec2) -> Self { Self { horizontal: value.x, vertical: value.y } } }
+        impl From<Axises<$s>> for $vec2 { fn from(value: Axises<$s>) -> Self { <$vec2>::new(value.horizontal, value.vertical) } }
+    )*};
+}
+
+conversions!(f32 => Vec2, f64 => DVec2);
+
+impl Layout<ValidDimensions<f32>, ValidDimensions<f32>> {
+    /// Enumerate the corners of every tile's destination and source quads - top left, top right, bottom left, bottom
+    /// right - with mirroring applied to the source corners.  See [mesh::quad_vertices].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// use glam::Vec2;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+    ///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 5.0, 4.0), inner: Rect::xywh(1.0, 1.0, 3.0, 2.0) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let mut quads = Vec::new();
+    /// layout.each_quad_vec2(|dst, _src| quads.push(dst));
+    /// assert_eq!(quads[4], [Vec2::new(1.0, 1.0), Vec2::new(4.0, 1.0), Vec2::new(1.0, 3.0), Vec2::new(4.0, 3.0)]);
+    /// ```
+    /// 
+    /// [mesh::quad_vertices]:  mesh/fn.quad_vertices.html
+    pub fn each_quad_vec2(&self, mut each_quad: impl FnMut([Vec2; 4], [Vec2; 4])) {
+        self.each_tile(|tile| {
+            let v = mesh::quad_vertices(tile);
+            each_quad(
+                [Vec2::from(v[0].dst), Vec2::from(v[1].dst), Vec2::from(v[2].dst), Vec2::from(v[3].dst)],
+                [Vec2::from(v[0].src), Vec2::from(v[1].src), Vec2::from(v[2].src), Vec2::from(v[3].src)],
+            );
+        });
+    }
+}
+
+
+
+#[test] fn glam_test() {
+    let rect = Rect::xywh(1.0, 2.0, 3.0, 4.0);
+    let (min, max) : (Vec2, Vec2) = rect.into();
+    assert_eq!((min, max), (Vec2::new(1.0, 2.0), Vec2::new(4.0, 6.0)));
+    assert_eq!(Rect::from((min, max)), rect);
+
+    let (min, max) : (DVec2, DVec2) = Rect::xywh(1.0, 2.0, 3.0, 4.0).validate().unwrap().into();
+    assert_eq!((min, max), (DVec2::new(1.0, 2.0), DVec2::new(4.0, 6.0)));
+
+    let axises = Axises { horizontal: 1.0f32, vertical: 2.0 };
+    assert_eq!(Vec2::from(axises), Vec2::new(1.0, 2.0));
+    assert_eq!(Axises::from(Vec2::from(axises)), axises);
+}
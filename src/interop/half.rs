@@ -0,0 +1,43 @@
+//! [half](https://docs.rs/half/) support:
+//! 
+//! * [Scalar] and [RatioScalar] for `half::f16` and `half::bf16`, via `f64` for conversions.
+//! 
+//! NaN compares false against everything just like `f32`, so `validate` still rejects NaN coordinates.
+
+use crate::*;
+use ::half::{bf16, f16};
+
+macro_rules! scalars {
+    ( $($ty:ty),* $(,)? ) => {$(
+        impl Scalar for $ty {
+            fn to_f64(self) -> f64 { <$ty>::to_f64(self) }
+            fn from_f64(value: f64, _rounding: Rounding) -> Self { <$ty>::from_f64(value) }
+            fn checked_add(self, rhs: Self) -> Option<Self> { Some(self + rhs) }
+            fn checked_sub(self, rhs: Self) -> Option<Self> { Some(self - rhs) }
+            fn saturating_sub(self, rhs: Self) -> Self { self - rhs }
+        }
+
+        impl RatioScalar for $ty {
+            fn checked_mul(self, rhs: Self) -> Option<Self> { Some(self * rhs) }
+        }
+    )*};
+}
+
+scalars!(f16, bf16);
+
+
+
+#[test] fn half_test() {
+    let h = f16::from_f32;
+    let src = Dimensions { outer: Rect::xywh(h(0.0), h(0.0), h(3.0), h(3.0)), inner: Rect::xywh(h(1.0), h(1.0), h(1.0), h(1.0)) }.validate().unwrap();
+    let layout = Layout { dst: src.with_outer(Rect::xywh(h(0.0), h(0.0), h(10.5), h(4.0)).validate().unwrap()).unwrap(), src, style: Style::new(Scale::Round) };
+    assert_eq!(layout.tiles().len(), 11 + 2*11 + 11);
+    assert_eq!(layout.tiles().len(), layout.tiles().count());
+    assert!(layout.check_overflow().is_ok());
+    assert_eq!(h(2.5).mul_f64(2.0, Rounding::Floor), h(5.0));
+    assert_eq!(f16::from_count(3).to_count(), 3);
+
+    assert!(Rect::xywh(h(0.0), f16::NAN, h(1.0), h(1.0)).validate().is_err());
+    assert!(Rect::xywh(bf16::from_f32(0.0), bf16::NAN, bf16::from_f32(1.0), bf16::from_f32(1.0)).validate().is_err());
+    assert!(Dimensions { outer: Rect::xywh(h(0.0), h(0.0), h(3.0), h(3.0)), inner: Rect::xywh(f16::NAN, h(1.0), h(1.0), h(1.0)) }.validate().is_err());
+}
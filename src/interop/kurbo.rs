@@ -0,0 +1,51 @@
+//! [kurbo](https://docs.rs/kurbo/) conversions:
+//! 
+//! * [Rect]`<f64>` ↔ `kurbo::Rect`
+//! * [Layout::each_dst_src_kurbo] for `kurbo::Rect` tiles
+
+use crate::*;
+
+impl From<::kurbo::Rect> for Rect<f64> {
+    fn from(value: ::kurbo::Rect) -> Self { Self { left: value.x0, top: value.y0, right: value.x1, bottom: value.y1 } }
+}
+
+impl From<Rect<f64>> for ::kurbo::Rect {
+    fn from(value: Rect<f64>) -> Self { Self::new(value.left, value.top, value.right, value.bottom) }
+}
+
+impl From<ValidRect<f64>> for ::kurbo::Rect {
+    fn from(value: ValidRect<f64>) -> Self { Rect::from(value).into() }
+}
+
+impl Layout<ValidDimensions<f64>, ValidDimensions<f64>> {
+    /// Enumerate the destination and source rectangles for a layout as `kurbo::Rect`s.  See [each_dst_src].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+    ///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 5.0, 4.0), inner: Rect::xywh(1.0, 1.0, 3.0, 2.0) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let mut rects = Vec::new();
+    /// layout.each_dst_src_kurbo(|dst, src| rects.push((dst, src)));
+    /// assert_eq!(rects[4], (kurbo::Rect::new(1.0, 1.0, 4.0, 3.0), kurbo::Rect::new(1.0, 1.0, 2.0, 2.0)));
+    /// ```
+    /// 
+    /// [each_dst_src]: #method.each_dst_src
+    pub fn each_dst_src_kurbo(&self, mut each_dst_src: impl FnMut(::kurbo::Rect, ::kurbo::Rect)) {
+        self.each_dst_src(|dst, src| each_dst_src((*dst).into(), (*src).into()));
+    }
+}
+
+
+
+#[test] fn kurbo_test() {
+    let rect = Rect::xywh(1.0, 2.0, 3.0, 4.0);
+    let k : ::kurbo::Rect = rect.into();
+    assert_eq!(k, ::kurbo::Rect::new(1.0, 2.0, 4.0, 6.0));
+    assert_eq!(Rect::from(k), rect);
+    assert_eq!(::kurbo::Rect::from(rect.validate().unwrap()), k);
+}
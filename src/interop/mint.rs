@@ -0,0 +1,64 @@
+//! [mint](https://docs.rs/mint/) conversions:
+//! 
+//! * [Rect] ↔ `Range<mint::Point2>` (top left `..` bottom right)
+//! * [Rect] ↔ `(mint::Point2, mint::Vector2)` (top left, size)
+//! * [Axises] ↔ `mint::Vector2` / `mint::Point2` (`horizontal` is `x`, `vertical` is `y`)
+
+use crate::*;
+use ::mint::{Point2, Vector2};
+use core::ops::Range;
+
+impl<S: Scalar> From<Range<Point2<S>>> for Rect<S> {
+    fn from(value: Range<Point2<S>>) -> Self {
+        Self { left: value.start.x, right: value.end.x, top: value.start.y, bottom: value.end.y }
+    }
+}
+
+impl<S: Scalar> From<Rect<S>> for Range<Point2<S>> {
+    fn from(value: Rect<S>) -> Self {
+        Point2 { x: value.left, y: value.top } .. Point2 { x: value.right, y: value.bottom }
+    }
+}
+
+impl<S: Scalar> From<ValidRect<S>> for Range<Point2<S>> {
+    fn from(value: ValidRect<S>) -> Self { Rect::from(value).into() }
+}
+
+impl<S: Scalar> From<(Point2<S>, Vector2<S>)> for Rect<S> {
+    fn from((origin, size): (Point2<S>, Vector2<S>)) -> Self {
+        Self::xywh(origin.x, origin.y, size.x, size.y)
+    }
+}
+
+impl<S: Scalar> From<Rect<S>> for (Point2<S>, Vector2<S>) {
+    fn from(value: Rect<S>) -> Self {
+        (Point2 { x: value.left, y: value.top }, Vector2 { x: value.right - value.left, y: value.bottom - value.top })
+    }
+}
+
+impl<S: Scalar> From<ValidRect<S>> for (Point2<S>, Vector2<S>) {
+    fn from(value: ValidRect<S>) -> Self { Rect::from(value).into() }
+}
+
+impl<S: Scalar> From<Vector2<S>> for Axises<S> { fn from(value: Vector2<S>) -> Self { Self { horizontal: value.x, vertical: value.y } } }
+impl<S: Scalar> From<Point2<S>>  for Axises<S> { fn from(value: Point2<S> ) -> Self { Self { horizontal: value.x, vertical: value.y } } }
+impl<S: Scalar> From<Axises<S>> for Vector2<S> { fn from(value: Axises<S>) -> Self { Self { x: value.horizontal, y: value.vertical } } }
+impl<S: Scalar> From<Axises<S>> for Point2<S>  { fn from(value: Axises<S>) -> Self { Self { x: value.horizontal, y: value.vertical } } }
+
+
+
+#[test] fn mint_test() {
+    let rect = Rect::xywh(1, 2, 3, 4);
+    let range : Range<Point2<i32>> = rect.into();
+    assert_eq!(range, Point2 { x: 1, y: 2 } .. Point2 { x: 4, y: 6 });
+    assert_eq!(Rect::from(range), rect);
+
+    let (origin, size) : (Point2<i32>, Vector2<i32>) = rect.validate().unwrap().into();
+    assert_eq!((origin, size), (Point2 { x: 1, y: 2 }, Vector2 { x: 3, y: 4 }));
+    assert_eq!(Rect::from((origin, size)), rect);
+
+    let axises = Axises { horizontal: 1.0, vertical: 2.0 };
+    assert_eq!(Vector2::from(axises), Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(Axises::from(Vector2::from(axises)), axises);
+    assert_eq!(Axises::from(Point2::from(axises)), axises);
+}
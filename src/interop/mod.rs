@@ -0,0 +1,13 @@
+//! Conversions to and from other crates' types, each behind a feature of the same name.
+
+#[cfg(feature = "cgmath")] mod cgmath;
+#[cfg(feature = "embedded-graphics")] mod embedded_graphics;
+#[cfg(feature = "euclid")] mod euclid;
+#[cfg(feature = "glam")] mod glam;
+#[cfg(feature = "half")] mod half;
+#[cfg(feature = "kurbo")] mod kurbo;
+#[cfg(feature = "mint")] mod mint;
+#[cfg(feature = "nalgebra")] mod nalgebra;
+#[cfg(feature = "raqote")] mod raqote;
+#[cfg(feature = "sdl2")] mod sdl2;
+#[cfg(feature = "tiny-skia")] mod tiny_skia;
@@ -0,0 +1,61 @@
+//! [raqote](https://docs.rs/raqote/) rendering:
+//! 
+//! * [Layout::draw_raqote] to draw a 9-sliced `Image` into a `DrawTarget`
+
+use crate::*;
+use ::raqote_crate::{DrawOptions, DrawTarget, ExtendMode, FilterMode, Image, Source, Transform};
+
+impl Layout<ValidDimensions<f32>, ValidDimensions<f32>> {
+    /// Draw `src`, 9-sliced per this layout, into `target`.  Each tile is filled with an image source mapping its
+    /// source rectangle onto its destination rectangle (flipped for mirrored tiles), sampled with `filter`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// use raqote_crate::{DrawOptions, DrawTarget, FilterMode, Image};
+    /// 
+    /// let pixels = [0xFF000000u32; 3 * 3];
+    /// let src = Image { width: 3, height: 3, data: &pixels[..] };
+    /// let mut dst = DrawTarget::new(50, 40);
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0.0, 0.0,  3.0,  3.0), inner: Rect::xywh(1.0, 1.0,  1.0,  1.0) },
+    ///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 50.0, 40.0), inner: Rect::xywh(5.0, 5.0, 40.0, 30.0) },
+    ///     style: Style::new(Scale::Round),
+    /// }.validate().unwrap();
+    /// layout.draw_raqote(&mut dst, &src, FilterMode::Nearest, &DrawOptions::new());
+    /// ```
+    pub fn draw_raqote<B: AsRef<[u32]> + AsMut<[u32]>>(&self, target: &mut DrawTarget<B>, src: &Image, filter: FilterMode, options: &DrawOptions) {
+        self.each_tile(|tile| {
+            let (d, s) = (&tile.dst, &tile.src);
+            if !(0.0 < d.width()) || !(0.0 < d.height()) || !(0.0 < s.width()) || !(0.0 < s.height()) { return; }
+
+            // raqote image transforms map destination space to image space
+            let (kx, ky) = (s.width() / d.width(), s.height() / d.height());
+            let (m11, m31) = if tile.mirror.horizontal { (-kx, s.right  + d.left * kx) } else { (kx, s.left - d.left * kx) };
+            let (m22, m32) = if tile.mirror.vertical   { (-ky, s.bottom + d.top  * ky) } else { (ky, s.top  - d.top  * ky) };
+            let source = Source::Image(*src, ExtendMode::Pad, filter, Transform::new(m11, 0.0, 0.0, m22, m31, m32));
+            target.fill_rect(d.left, d.top, d.width(), d.height(), &source, options);
+        });
+    }
+}
+
+
+
+#[test] fn raqote_test() {
+    // 3x3 source where every pixel has a unique red value
+    let pixels = (0 .. 9).map(|i| 0xFF000000 | (10 * i) << 16).collect::<Vec<u32>>();
+    let src = Image { width: 3, height: 3, data: &pixels[..] };
+
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 6.0, 5.0), inner: Rect::xywh(1.0, 1.0, 4.0, 3.0) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Center)),
+    }.validate().unwrap();
+    let mut dst = DrawTarget::new(6, 5);
+    layout.draw_raqote(&mut dst, &src, FilterMode::Nearest, &DrawOptions::new());
+
+    let red = |x: usize, y: usize| (dst.get_data()[y * 6 + x] >> 16 & 0xFF) / 10;
+    assert_eq!([red(0, 0), red(1, 0), red(5, 0), red(0, 4), red(5, 4)], [0, 1, 2, 6, 8]);
+    assert_eq!([red(1, 1), red(2, 2), red(4, 3)], [4, 4, 4]);
+    assert_eq!([red(0, 2), red(5, 3)], [3, 5]);
+    assert!(dst.get_data().iter().all(|p| p >> 24 == 0xFF));
+}
@@ -0,0 +1,58 @@
+//! [sdl2](https://docs.rs/sdl2/) conversions:
+//! 
+//! * [Rect]`<i32>` → `sdl2::rect::Rect` (fallible, as SDL sizes are unsigned)
+//! * [ValidRect]`<i32>` → `sdl2::rect::Rect`
+//! * `sdl2::rect::Rect` → [Rect]`<i32>`
+//! * [Layout::copy_sdl2] to draw a layout via `Canvas::copy`
+//! 
+//! Beware:  `sdl2::rect::Rect` clamps zero widths and heights up to 1.
+
+use crate::*;
+use ::sdl2_crate::render::{Canvas, RenderTarget, Texture};
+use core::convert::TryFrom;
+
+impl From<::sdl2_crate::rect::Rect> for Rect<i32> {
+    fn from(value: ::sdl2_crate::rect::Rect) -> Self {
+        Self::xywh(value.x(), value.y(), value.width() as i32, value.height() as i32)
+    }
+}
+
+impl From<ValidRect<i32>> for ::sdl2_crate::rect::Rect {
+    fn from(value: ValidRect<i32>) -> Self { Self::new(value.left, value.top, value.width() as u32, value.height() as u32) }
+}
+
+impl TryFrom<Rect<i32>> for ::sdl2_crate::rect::Rect {
+    type Error = Error;
+    fn try_from(value: Rect<i32>) -> Result<Self, Error> { Ok(value.validate()?.into()) }
+}
+
+impl Layout<ValidDimensions<i32>, ValidDimensions<i32>> {
+    /// Draw this layout with `texture` via `Canvas::copy` - or `Canvas::copy_ex` for mirrored tiles.
+    /// 
+    /// Zero-area tiles are skipped, since `sdl2::rect::Rect` would round them up to a visible 1 pixel.
+    /// 
+    /// Returns the first `Err` from SDL, without drawing any remaining tiles.
+    pub fn copy_sdl2<T: RenderTarget>(&self, canvas: &mut Canvas<T>, texture: &Texture) -> Result<(), String> {
+        let mut tiles = self.tiles();
+        while let Some(tile) = tiles.next_tile() {
+            if tile.dst.width() <= 0 || tile.dst.height() <= 0 || tile.src.width() <= 0 || tile.src.height() <= 0 { continue; }
+            let (dst, src) = (::sdl2_crate::rect::Rect::from(tile.dst), ::sdl2_crate::rect::Rect::from(tile.src));
+            if tile.mirror.horizontal || tile.mirror.vertical {
+                canvas.copy_ex(texture, src, dst, 0.0, None, tile.mirror.horizontal, tile.mirror.vertical)?;
+            } else {
+                canvas.copy(texture, src, dst)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+#[test] fn sdl2_test() {
+    let rect = Rect::xywh(1, 2, 3, 4);
+    let sdl = ::sdl2_crate::rect::Rect::try_from(rect).unwrap();
+    assert_eq!(sdl, ::sdl2_crate::rect::Rect::new(1, 2, 3, 4));
+    assert_eq!(Rect::from(sdl), rect);
+    assert!(::sdl2_crate::rect::Rect::try_from(Rect { left: 2, right: 1, top: 0, bottom: 0 }).is_err());
+}
@@ -0,0 +1,68 @@
+//! [tiny-skia](https://docs.rs/tiny-skia/) rendering:
+//! 
+//! * [Layout::draw_tiny_skia] to draw a 9-sliced `Pixmap` into another `Pixmap`
+
+use crate::*;
+use ::tiny_skia_crate::{FilterQuality, Paint, Pattern, PixmapMut, PixmapRef, SpreadMode, Transform};
+
+impl Layout<ValidDimensions<f32>, ValidDimensions<f32>> {
+    /// Draw `src`, 9-sliced per this layout, into `target`.  Each tile is filled with a pattern shader mapping its
+    /// source rectangle onto its destination rectangle (flipped for mirrored tiles), sampled with `quality`.
+    /// 
+    /// Tiles are drawn without anti-aliasing, so neighboring tiles meet without seams.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// use tiny_skia_crate::{FilterQuality, Pixmap};
+    /// 
+    /// let src = Pixmap::new(3, 3).unwrap();
+    /// let mut dst = Pixmap::new(50, 40).unwrap();
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0.0, 0.0,  3.0,  3.0), inner: Rect::xywh(1.0, 1.0,  1.0,  1.0) },
+    ///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 50.0, 40.0), inner: Rect::xywh(5.0, 5.0, 40.0, 30.0) },
+    ///     style: Style::new(Scale::Round),
+    /// }.validate().unwrap();
+    /// layout.draw_tiny_skia(&mut dst.as_mut(), src.as_ref(), FilterQuality::Nearest);
+    /// ```
+    pub fn draw_tiny_skia(&self, target: &mut PixmapMut, src: PixmapRef, quality: FilterQuality) {
+        self.each_tile(|tile| {
+            let (d, s) = (&tile.dst, &tile.src);
+            let rect = match ::tiny_skia_crate::Rect::from_ltrb(d.left, d.top, d.right, d.bottom) { Some(r) => r, None => return };
+            if !(0.0 < s.width()) || !(0.0 < s.height()) { return; }
+
+            let (sx, sy) = (d.width() / s.width(), d.height() / s.height());
+            let (sx, tx) = if tile.mirror.horizontal { (-sx, d.left + s.right  * sx) } else { (sx, d.left - s.left * sx) };
+            let (sy, ty) = if tile.mirror.vertical   { (-sy, d.top  + s.bottom * sy) } else { (sy, d.top  - s.top  * sy) };
+            let paint = Paint {
+                shader:     Pattern::new(src, SpreadMode::Pad, quality, 1.0, Transform::from_row(sx, 0.0, 0.0, sy, tx, ty)),
+                anti_alias: false,
+                ..Paint::default()
+            };
+            target.fill_rect(rect, &paint, Transform::identity(), None);
+        });
+    }
+}
+
+
+
+#[test] fn tiny_skia_test() {
+    use ::tiny_skia_crate::{Pixmap, PremultipliedColorU8};
+
+    // 3x3 source where every pixel has a unique red value
+    let mut src = Pixmap::new(3, 3).unwrap();
+    for (i, p) in src.pixels_mut().iter_mut().enumerate() { *p = PremultipliedColorU8::from_rgba(10 * i as u8, 0, 0, 255).unwrap(); }
+
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 6.0, 5.0), inner: Rect::xywh(1.0, 1.0, 4.0, 3.0) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Center)),
+    }.validate().unwrap();
+    let mut dst = Pixmap::new(6, 5).unwrap();
+    layout.draw_tiny_skia(&mut dst.as_mut(), src.as_ref(), FilterQuality::Nearest);
+
+    let red = |x: u32, y: u32| dst.pixel(x, y).unwrap().red() / 10;
+    assert_eq!([red(0, 0), red(1, 0), red(5, 0), red(0, 4), red(5, 4)], [0, 1, 2, 6, 8]);
+    assert_eq!([red(1, 1), red(2, 2), red(4, 3)], [4, 4, 4]);
+    assert_eq!([red(0, 2), red(5, 3)], [3, 5]);
+    assert!(dst.pixels().iter().all(|p| p.alpha() == 255));
+}
@@ -0,0 +1,126 @@
+use super::*;
+
+/// A single layer of a [Layers] stack.
+/// 
+/// [Layers]:   struct.Layers.html
+#[derive(Clone, Copy, Debug)]
+pub struct Layer<S: RatioScalar> {
+    /// The skin to draw for this layer.  Its borders are kept the same size in the destination.
+    pub src:    ValidDimensions<S>,
+
+    /// How to scale this layer's skin.
+    pub style:  Style,
+
+    /// How far this layer's outer rect extends past the shared destination outer rect, per side.
+    /// Positive values grow the layer (e.g. a drop shadow), negative values shrink it (e.g. an inner glow).
+    pub offset: Borders<S>,
+}
+
+impl<S: RatioScalar> Layer<S> {
+    /// Get the destination dimensions of this layer for a shared destination outer rect.
+    /// 
+    /// Can return Err if the offset outer rect is invalid, or if the center would have negative bounds.
+    pub fn dst(&self, dst_outer: ValidRect<S>) -> Result<ValidDimensions<S>, Error> {
+        self.src.with_outer(dst_outer.grow(&self.offset).validate()?)
+    }
+
+    /// Get the full [Layout] of this layer for a shared destination outer rect.
+    /// 
+    /// [Layout]:   struct.Layout.html
+    pub fn layout(&self, dst_outer: ValidRect<S>) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Error> {
+        Ok(Layout { dst: self.dst(dst_outer)?, src: self.src, style: self.style })
+    }
+}
+
+/// A back-to-front stack of [Layer]s (e.g. drop shadow, frame, and inner glow) laid out against one destination outer rect.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let shadow  = Dimensions { outer: Rect::xywh( 0, 0, 8, 8), inner: Rect::xywh( 4, 4, 0, 0) }.validate().unwrap();
+/// let frame   = Dimensions { outer: Rect::xywh( 8, 0, 3, 3), inner: Rect::xywh( 9, 1, 1, 1) }.validate().unwrap();
+/// let stack = [
+///     Layer { src: shadow, style: Style::default(), offset: Borders::uniform(2) },
+///     Layer { src: frame,  style: Style::default(), offset: Borders::default() },
+/// ];
+/// 
+/// let mut tiles = Vec::new();
+/// Layers::new(&stack).each_dst_src(Rect::xywh(10, 10, 20, 20).validate().unwrap(), |layer, dst, _src| {
+///     tiles.push((layer, Rect::from(dst)));
+/// }).unwrap();
+/// 
+/// assert_eq!(tiles[0], (0, Rect::xywh( 8,  8, 4, 4)));
+/// assert_eq!(tiles[9], (1, Rect::xywh(10, 10, 1, 1)));
+/// ```
+/// 
+/// [Layer]:    struct.Layer.html
+#[derive(Clone, Copy, Debug)]
+pub struct Layers<'a, S: RatioScalar> {
+    layers: &'a [Layer<S>],
+}
+
+impl<'a, S: RatioScalar> Layers<'a, S> {
+    /// Create a new stack of `layers`, drawn in order (back to front.)
+    #[must_use] pub fn new(layers: &'a [Layer<S>]) -> Self { Self { layers } }
+
+    #[must_use] pub fn layers(&self) -> &'a [Layer<S>] { self.layers }
+
+    /// Check that every layer can be laid out against `dst_outer`.
+    pub fn validate(&self, dst_outer: ValidRect<S>) -> Result<(), Error> {
+        for layer in self.layers.iter() { layer.dst(dst_outer)?; }
+        Ok(())
+    }
+
+    /// Enumerate the [Tile]s of every layer, along with the index of the layer they belong to.
+    /// 
+    /// Can return Err (without enumerating anything) if any layer can't be laid out against `dst_outer`.
+    /// 
+    /// [Tile]:     struct.Tile.html
+    pub fn each_tile(&self, dst_outer: ValidRect<S>, mut each_tile: impl FnMut(usize, &Tile<S>)) -> Result<(), Error> {
+        self.validate(dst_outer)?;
+        for (i, layer) in self.layers.iter().enumerate() {
+            let mut tiles = layer.layout(dst_outer)?.tiles();
+            while let Some(tile) = tiles.next_tile() {
+                each_tile(i, &tile);
+            }
+        }
+        Ok(())
+    }
+
+    /// Enumerate the destination and source rectangles of every layer, along with the index of the layer they belong to.
+    /// 
+    /// Can return Err (without enumerating anything) if any layer can't be laid out against `dst_outer`.
+    pub fn each_dst_src(&self, dst_outer: ValidRect<S>, mut each_dst_src: impl FnMut(usize, &ValidRect<S>, &ValidRect<S>)) -> Result<(), Error> {
+        self.each_tile(dst_outer, |i, tile| each_dst_src(i, &tile.dst, &tile.src))
+    }
+}
+
+
+
+#[test] fn layers_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap();
+    let stack = [
+        Layer { src, style: Style::default(), offset: Borders::uniform(1) },
+        Layer { src, style: Style::default(), offset: Borders::default() },
+        Layer { src, style: Style::default(), offset: Borders::uniform(-2) },
+    ];
+    let layers = Layers::new(&stack);
+    let outer = Rect::xywh(0, 0, 10, 10).validate().unwrap();
+
+    let mut actual = Vec::new();
+    layers.each_dst_src(outer, |i, dst, src| actual.push((i, *dst, *src))).unwrap();
+
+    let mut expected = Vec::new();
+    for (i, layer) in stack.iter().enumerate() {
+        layer.layout(outer).unwrap().each_dst_src(|dst, src| expected.push((i, *dst, *src)));
+    }
+    assert_eq!(actual, expected);
+    assert_eq!(actual.len(), 3 * 9);
+    assert_eq!(actual[ 0].1, Rect::xywh(-1, -1, 1, 1));
+    assert_eq!(actual[18].1, Rect::xywh( 2,  2, 1, 1));
+
+    // The innermost layer no longer fits: nothing is emitted
+    assert!(layers.each_dst_src(Rect::xywh(0, 0, 5, 5).validate().unwrap(), |_, _, _| panic!()).is_err());
+}
@@ -1,5 +1,8 @@
 use super::*;
-use std::convert::TryFrom;
+#[cfg(feature = "alloc")] use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::mem::MaybeUninit;
+use core::ops::{ControlFlow, Range};
 
 /// Describe a layout to render with.  The general use pattern is:
 /// 
@@ -63,6 +66,46 @@ impl<S: Scalar> Layout<Dimensions<S>, Dimensions<S>> {
     }
 }
 
+impl<S: RatioScalar> Layout<Dimensions<S>, Dimensions<S>> {
+    /// Validate that dst and src contain valid (non-negative sized, non-NAN) dimensions, resolving an undersized dst per
+    /// `policy`.  See [Dimensions::validate_with].
+    /// 
+    /// [Dimensions::validate_with]:    struct.Dimensions.html#method.validate_with
+    pub fn validate_with(&self, policy: OverflowPolicy) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Error> {
+        Ok(Layout {
+            dst:    self.dst.validate_with(policy)?,
+            src:    self.src.validate()?,
+            style:  self.style,
+        })
+    }
+
+    /// Validate per [validate], and also check that laying out the result can't overflow - so malformed (e.g.
+    /// untrusted asset) data is reported as an Err instead of panicking.  See [check_overflow].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 100, 100), inner: Rect::xywh(1, 1, 98, 98) },
+    ///     style: Style::new(Scale::Round),
+    /// };
+    /// assert!(layout.try_validate().is_ok());
+    /// 
+    /// let huge = Layout { dst: Dimensions { outer: Rect::xywh(0, 0, 100, 100_000), inner: Rect::xywh(1, 1, 98, 99_998) }, ..layout };
+    /// assert!(huge.validate().is_ok());
+    /// assert!(huge.try_validate().is_err()); // Round would compute 99_998 * 99_998
+    /// ```
+    /// 
+    /// [validate]:         #method.validate
+    /// [check_overflow]:   #method.check_overflow
+    pub fn try_validate(&self) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Error> {
+        let layout = self.validate()?;
+        layout.check_overflow()?;
+        Ok(layout)
+    }
+}
+
 impl<S: Scalar> TryFrom<Layout<Dimensions<S>, Dimensions<S>>> for Layout<ValidDimensions<S>, ValidDimensions<S>> {
     type Error = Error;
     fn try_from(value: Layout<Dimensions<S>, Dimensions<S>>) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Self::Error> {
@@ -71,9 +114,371 @@ impl<S: Scalar> TryFrom<Layout<Dimensions<S>, Dimensions<S>>> for Layout<ValidDi
 }
 
 impl<S: Scalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Get the destination and source rectangles of all nine cells in row-major order, stretched.
+    /// 
+    /// This is intended for the common all-[Stretch] case, without needing a closure or allocation.  Every cell is
+    /// stretched and reported (even if empty), so `style` must emit all nine cells as unmirrored, unsnapped [Stretch]es
+    /// for this to match [each_dst_src] - debug builds assert as much.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let [_, (top_dst, top_src), ..] = layout.to_array();
+    /// assert_eq!(top_dst, Rect::xywh(1, 0, 3, 1));
+    /// assert_eq!(top_src, Rect::xywh(1, 0, 1, 1));
+    /// ```
+    /// 
+    /// [Stretch]:      enum.Scale.html#variant.Stretch
+    /// [each_dst_src]: #method.each_dst_src
+    pub fn to_array(&self) -> [(ValidRect<S>, ValidRect<S>); 9] {
+        debug_assert!(self.style.stretches(&Cell::ALL), "Layout::to_array expects a style that stretches every cell");
+        let mut a = [(ValidRect::default(), ValidRect::default()); 9];
+        for (cell, dst_src) in Cell::ALL.iter().copied().zip(a.iter_mut()) {
+            *dst_src = (self.dst.cell(cell), self.src.cell(cell));
+        }
+        a
+    }
+}
+
+impl<S: RatioScalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Check that enumerating this layout's tiles (via [tiles], [each_dst_src], etc.) can't overflow `S`.
+    /// 
+    /// Returns Err if any rect's width or height, any intermediate result of scaling a cell, or the total tile count would overflow.
+    /// This is conservative:  it may reject a few layouts that would only overflow with a different [Remainder].
+    /// 
+    /// [tiles]:        #method.tiles
+    /// [each_dst_src]: #method.each_dst_src
+    /// [Remainder]:    enum.Remainder.html
+    pub fn check_overflow(&self) -> Result<(), Error> {
+        self.dst.outer().try_size()?;
+        self.src.outer().try_size()?;
+        let outer = self.dst.outer;
+        if self.style.mirror_horizontal && outer.left.checked_add(outer.right).is_none() { return err("Expected outer.left + outer.right not to overflow"); }
+        let mut count = 0_usize;
+        for cell in Cell::ALL.iter().copied().filter(|cell| self.style.emits(*cell)) {
+            let art = self.style.src_cell(cell);
+            let (dst, src, scale) = (self.dst.cell(art), self.src.cell(art), self.style.cell_scale(art));
+            scale.horizontal.check_overflow(dst.left, dst.width(),  src.width(),  self.style.saturating)?;
+            scale.vertical  .check_overflow(dst.top,  dst.height(), src.height(), self.style.saturating)?;
+            let tiles = scale.horizontal.tile_count(dst.width(), src.width()).checked_mul(scale.vertical.tile_count(dst.height(), src.height()));
+            count = match tiles.and_then(|tiles| count.checked_add(tiles)) { Some(count) => count, None => return err("Expected tile count not to overflow") };
+        }
+        Ok(())
+    }
+
     /// Enumerate the destination and source rectangles for a layout.
+    /// 
+    /// Mirroring (e.g. from [Scale::MirrorRepeat]) is not reported - use [each_tile] if you need it.
+    /// 
+    /// [Scale::MirrorRepeat]:  enum.Scale.html#variant.MirrorRepeat
+    /// [each_tile]:            #method.each_tile
     pub fn each_dst_src(&self, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
-        do_layout_9(self.dst, self.src, self.style, &mut each_dst_src);
+        do_layout_9(self.dst, self.src, self.style, &mut |tile| each_dst_src(&tile.dst, &tile.src));
+    }
+
+    /// Enumerate the destination and source rectangles for a layout, along with which [Cell] they belong to.
+    /// 
+    /// Mirroring (e.g. from [Scale::MirrorRepeat]) is not reported - use [each_tile] if you need it.
+    /// 
+    /// [Cell]:                 enum.Cell.html
+    /// [Scale::MirrorRepeat]:  enum.Scale.html#variant.MirrorRepeat
+    /// [each_tile]:            #method.each_tile
+    pub fn each_dst_src_cell(&self, mut each_dst_src_cell: impl FnMut(Cell, &ValidRect<S>, &ValidRect<S>)) {
+        do_layout_9(self.dst, self.src, self.style, &mut |tile| each_dst_src_cell(tile.cell, &tile.dst, &tile.src));
+    }
+
+    /// Enumerate the destination and source rectangles for a layout, until `each_dst_src` returns [ControlFlow::Break].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// use std::ops::ControlFlow;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let hit = layout.try_each_dst_src(|dst, src| {
+    ///     if dst.width() > 1 { ControlFlow::Break(*src) } else { ControlFlow::Continue(()) }
+    /// });
+    /// assert_eq!(hit, ControlFlow::Break(Rect::xywh(1, 0, 1, 1).validate().unwrap()));
+    /// ```
+    /// 
+    /// [ControlFlow::Break]:   https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#variant.Break
+    pub fn try_each_dst_src<B>(&self, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>) -> ControlFlow<B>) -> ControlFlow<B> {
+        let mut tiles = self.tiles();
+        while let Some(tile) = tiles.next_tile() {
+            if let ControlFlow::Break(b) = each_dst_src(&tile.dst, &tile.src) { return ControlFlow::Break(b); }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Enumerate the destination and source rectangles for many `(dst, src)` layouts sharing a single `style`, in a single pass.
+    /// 
+    /// `each_dst_src` is also passed the index into `dst_srcs` the rectangles belong to.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let src = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap();
+    /// let panels = (1 ..= 10).map(|i| (src.with_outer(Rect::xywh(0, 0, 3*i, 3*i).validate().unwrap()).unwrap(), src)).collect::<Vec<_>>();
+    /// 
+    /// let mut quads = Vec::new();
+    /// Layout::each_dst_src_batch(&panels[..], Style::default(), |_panel, dst, src| quads.push((*dst, *src)));
+    /// assert_eq!(quads.len(), 9 * 10);
+    /// ```
+    pub fn each_dst_src_batch(dst_srcs: &[(ValidDimensions<S>, ValidDimensions<S>)], style: Style, mut each_dst_src: impl FnMut(usize, &ValidRect<S>, &ValidRect<S>)) {
+        for (i, (dst, src)) in dst_srcs.iter().copied().enumerate() {
+            let mut tiles = Tiles::new(dst, src, style);
+            while let Some(tile) = tiles.next_tile() {
+                each_dst_src(i, &tile.dst, &tile.src);
+            }
+        }
+    }
+
+    /// Enumerate the [Tile]s for a layout.
+    /// 
+    /// [Tile]:     struct.Tile.html
+    pub fn each_tile(&self, mut each_tile: impl FnMut(&Tile<S>)) {
+        do_layout_9(self.dst, self.src, self.style, &mut each_tile);
+    }
+
+    /// Enumerate the destination and source rectangles for a layout, clipped to `clip` - e.g. for scrollable containers.
+    /// 
+    /// Source rectangles are trimmed in proportion to their destination rectangles (flipped for mirrored tiles).
+    /// Tiles that don't overlap `clip` with a nonzero area are skipped entirely.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 4, 4), inner: Rect::xywh(1, 1, 2, 2) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 10, 10), inner: Rect::xywh(1, 1, 8, 8) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let mut rects = Vec::new();
+    /// layout.each_dst_src_clipped(&Rect::xywh(5, 5, 10, 10).validate().unwrap(), |dst, src| rects.push((Rect::from(dst), Rect::from(src))));
+    /// assert_eq!(rects.len(), 4);
+    /// assert_eq!(rects[0], (Rect::xywh(5, 5, 4, 4), Rect::xywh(2, 2, 1, 1)));
+    /// ```
+    pub fn each_dst_src_clipped(&self, clip: &ValidRect<S>, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
+        do_layout_9(self.dst, self.src, self.style, &mut |tile| {
+            let (dst, src) = (&tile.dst, &tile.src);
+            let x = clip_axis(dst.left..dst.right, src.left..src.right, tile.mirror.horizontal, clip.left..clip.right);
+            let y = clip_axis(dst.top..dst.bottom, src.top..src.bottom, tile.mirror.vertical,   clip.top..clip.bottom);
+            if let (Some((dst_x, src_x)), Some((dst_y, src_y))) = (x, y) {
+                let dst = Rect::from((dst_x, dst_y)).debug_assert_valid();
+                let src = Rect::from((src_x, src_y)).debug_assert_valid();
+                each_dst_src(&dst, &src);
+            }
+        });
+    }
+
+    /// Enumerate the destination and source rectangles for a layout, with source rectangles inset by `inset` texels -
+    /// e.g. `0.5` to keep bilinear filtering from bleeding neighboring atlas pixels (or the other side of a repeated
+    /// tile) into edges.
+    /// 
+    /// Source edges shared with an adjacent tile - where both cells' sources also meet in the source image, as when
+    /// a stretched border meets a stretched center - are not inset, since filtering across them is seamless.
+    /// Source rectangles narrower than `inset * 2` collapse to their midpoint.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+    ///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 9.0, 9.0), inner: Rect::xywh(3.0, 3.0, 3.0, 3.0) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let mut srcs = Vec::new();
+    /// layout.each_dst_src_inset(0.5, |_dst, src| srcs.push(Rect::from(src)));
+    /// assert_eq!(srcs[0], Rect { left: 0.5, top: 0.5, right: 1.0, bottom: 1.0 }); // top left:  only outer edges inset
+    /// assert_eq!(srcs[4], Rect { left: 1.0, top: 1.0, right: 2.0, bottom: 2.0 }); // center:    no edges inset
+    /// ```
+    pub fn each_dst_src_inset(&self, inset: S, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
+        let (d, s) = (self.dst, self.src);
+        let mut dst_xs = [d.outer.left, d.inner.left, d.inner.right, d.outer.right];
+        if self.style.mirror_horizontal { dst_xs = [d.outer.left, d.outer.right - d.inner.right + d.outer.left, d.outer.right - d.inner.left + d.outer.left, d.outer.right]; }
+        let dst_ys = [d.outer.top,  d.inner.top,  d.inner.bottom, d.outer.bottom];
+        let dst_xs = dst_xs.map(|x| self.style.snap.snap(x, d.outer.left));
+        let dst_ys = dst_ys.map(|y| self.style.snap.snap(y, d.outer.top));
+        let src_xs = [s.outer.left, s.inner.left, s.inner.right, s.outer.right];
+        let src_ys = [s.outer.top,  s.inner.top,  s.inner.bottom, s.outer.bottom];
+        do_layout_9(self.dst, self.src, self.style, &mut |tile| {
+            let (dst, src) = (&tile.dst, &tile.src);
+            let x = inset_axis(dst.left..dst.right, src.left..src.right, tile.mirror.horizontal, &dst_xs, &src_xs, self.style.mirror_horizontal, inset);
+            let y = inset_axis(dst.top..dst.bottom, src.top..src.bottom, tile.mirror.vertical,   &dst_ys, &src_ys, false, inset);
+            let src = Rect::from((x, y)).debug_assert_valid();
+            each_dst_src(dst, &src);
+        });
+    }
+
+    /// Iterate over the destination and source rectangles for a layout.  Equivalent to [each_dst_src].
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// 
+    /// let wide = layout.tiles().filter(|(dst, _src)| dst.width() > 1).count();
+    /// assert_eq!(wide, 3);
+    /// ```
+    /// 
+    /// [each_dst_src]:     #method.each_dst_src
+    pub fn tiles(&self) -> Tiles<S> {
+        Tiles::new(self.dst, self.src, self.style)
+    }
+
+    /// Collect the destination and source rectangles for a layout.  Equivalent to `self.tiles().collect()`.
+    #[cfg(feature = "alloc")] pub fn to_vec(&self) -> Vec<(ValidRect<S>, ValidRect<S>)> {
+        self.tiles().collect()
+    }
+
+    /// Collect the [Tile]s for a layout.
+    /// 
+    /// [Tile]:     struct.Tile.html
+    #[cfg(feature = "alloc")] pub fn to_tile_vec(&self) -> Vec<Tile<S>> {
+        let mut tiles = self.tiles();
+        let mut v = Vec::with_capacity(tiles.len());
+        while let Some(tile) = tiles.next_tile() { v.push(tile); }
+        v
+    }
+
+    /// How many tiles this layout will emit, e.g. to preallocate vertex buffers.
+    pub fn tile_count(&self) -> usize {
+        self.tiles().len()
+    }
+
+    /// The `[width, height]` of `dst.outer` at which no scaling occurs:  `dst`'s borders, plus `src`'s center scaled by
+    /// the same multiplier `src`'s borders are scaled by to reach `dst`'s.  Axises without src borders use a multiplier
+    /// of 1.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// // Pixel art drawn at 3x
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 12, 12), inner: Rect::xywh(4, 4, 4, 4) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 100, 100), inner: Rect::xywh(12, 12, 76, 76) },
+    ///     style: Style::default(),
+    /// }.validate().unwrap();
+    /// assert_eq!(layout.natural_dst_size(), [36, 36]);
+    /// ```
+    #[must_use] pub fn natural_dst_size(&self) -> [S; 2] {
+        let zero = S::default();
+        let (dst, src) = (self.dst.borders(), self.src.borders());
+        let [src_center_w, src_center_h] = self.src.inner().size();
+        let axis = |dst_borders: S, src_borders: S, src_center: S| {
+            if zero < src_borders { dst_borders + src_center * dst_borders / src_borders } else { dst_borders + src_center }
+        };
+        [
+            axis(dst.left + dst.right, src.left + src.right, src_center_w),
+            axis(dst.top + dst.bottom, src.top + src.bottom, src_center_h),
+        ]
+    }
+
+    /// Where to place content (e.g. text or child widgets) within the destination frame.  See [ValidDimensions::content_rect].
+    /// 
+    /// [ValidDimensions::content_rect]:    struct.ValidDimensions.html#method.content_rect
+    #[must_use] pub fn dst_content_rect(&self) -> ValidRect<S> { self.dst.content_rect() }
+
+    /// Where to place content within the destination frame, inset by `padding`.  See [ValidDimensions::content_rect_padded].
+    /// 
+    /// [ValidDimensions::content_rect_padded]: struct.ValidDimensions.html#method.content_rect_padded
+    pub fn dst_content_rect_padded(&self, padding: &Borders<S>) -> Result<ValidRect<S>, Error> { self.dst.content_rect_padded(padding) }
+
+    /// Map a destination-space `[x, y]` back to the source-space coordinate drawn there, e.g. for per-pixel alpha hit
+    /// testing against the source image.  Repeated tiles wrap back into their source cell, and mirrored tiles are
+    /// reflected - a mirrored tile maps its start edge to the end of its source range.
+    /// 
+    /// Returns `None` if `point` is outside `dst.outer`, within a cell this style doesn't emit, or within a gap between
+    /// tiles (e.g. [Scale::Space] or [Scale::Fixed] with a larger destination).  Bounds are half-open.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 6, 6), inner: Rect::xywh(2, 2, 2, 2) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 10, 10), inner: Rect::xywh(2, 2, 6, 6) },
+    ///     style: Style::new(Scale::Repeat(Partial::Center)),
+    /// }.validate().unwrap();
+    /// assert_eq!(layout.map_dst_to_src([7, 5]), Some([3, 3]));
+    /// assert_eq!(layout.map_dst_to_src([9, 1]), Some([5, 1]));
+    /// assert_eq!(layout.map_dst_to_src([10, 1]), None);
+    /// ```
+    /// 
+    /// [Scale::Space]:     enum.Scale.html#variant.Space
+    /// [Scale::Fixed]:     enum.Scale.html#variant.Fixed
+    #[must_use] pub fn map_dst_to_src(&self, point: [S; 2]) -> Option<[S; 2]> {
+        let [x, y] = point;
+        let flip = if self.style.mirror_horizontal { Some(self.dst.outer.left + self.dst.outer.right) } else { None };
+        let art = match flip {
+            None => self.dst.cell_at(x, y)?,
+            Some(sum) => Cell::ALL.iter().copied().find(|art| {
+                let r = self.dst.cell(*art);
+                sum - r.right <= x && x < sum - r.left && r.top <= y && y < r.bottom
+            })?,
+        };
+        if !self.style.emits(self.style.src_cell(art)) { return None; }
+
+        let dst = self.dst.cell(art);
+        let src = self.src.cell(art);
+        let scale = self.style.cell_scale(art);
+        let axis = |spans: Spans<S>, v: S, flip: Option<S>| spans.map(|span| match flip {
+            Some(sum) => (sum - span.dst.end .. sum - span.dst.start, span.src, !span.mirror),
+            None => (span.dst, span.src, span.mirror),
+        }).find(|(dst, _, _)| dst.start <= v && v < dst.end).map(|(dst, src, mirror)| {
+            let offset = (v - dst.start) * (src.end - src.start) / (dst.end - dst.start);
+            crate::tile::src_at(src.start, src.end, offset, mirror)
+        });
+        Some([
+            axis(scale.horizontal.spans_with(dst.left..dst.right, src.left..src.right, self.style.remainder).saturating(self.style.saturating), x, flip)?,
+            axis(scale.vertical  .spans_with(dst.top..dst.bottom, src.top..src.bottom, self.style.remainder).saturating(self.style.saturating), y, None)?,
+        ])
+    }
+
+    /// Write the destination and source rectangles for a layout into caller-provided storage, without allocating.
+    /// 
+    /// Returns the number of pairs written - `out[..n]` will be initialized.
+    /// Returns Err (without writing anything) if `out` has room for fewer than [tile_count] pairs.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// use std::mem::MaybeUninit;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+    ///     dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+    ///     style: Style::new(Scale::Repeat(Partial::Center)),
+    /// }.validate().unwrap();
+    /// 
+    /// let mut storage = [MaybeUninit::uninit(); 32];
+    /// let n = layout.write_dst_src(&mut storage[..]).unwrap();
+    /// assert_eq!(n, 4 + 2*3 + 2*2 + 3*2);
+    /// ```
+    /// 
+    /// [tile_count]:   #method.tile_count
+    pub fn write_dst_src(&self, out: &mut [MaybeUninit<(Rect<S>, Rect<S>)>]) -> Result<usize, Error> {
+        let tiles = self.tiles();
+        let n = tiles.len();
+        if out.len() < n { return err("Not enough room in out for all tiles"); }
+        for (slot, (dst, src)) in out.iter_mut().zip(tiles) {
+            *slot = MaybeUninit::new((*dst, *src));
+        }
+        Ok(n)
     }
 
     #[cfg(test)] fn collect_dst_src_vec(&self) -> Vec<(ValidRect<S>, ValidRect<S>)> {
@@ -81,42 +486,97 @@ impl<S: Scalar> Layout<ValidDimensions<S>, ValidDimensions<S>> {
         self.each_dst_src(|dst, src| v.push((*dst, *src)));
         v
     }
+
+    #[cfg(test)] fn collect_tile_vec(&self) -> Vec<Tile<S>> {
+        let mut v = Vec::new();
+        self.each_tile(|tile| v.push(*tile));
+        assert_eq!(v, self.to_tile_vec());
+        v
+    }
 }
 
-fn do_layout_9<S: Scalar>(dst: ValidDimensions<S>, src: ValidDimensions<S>, style: Style, each_dst_src: &mut impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
-    let dstx = [dst.outer.left, dst.inner.left, dst.inner.right, dst.outer.right];
-    let dsty = [dst.outer.top, dst.inner.top, dst.inner.bottom, dst.outer.bottom];
-    let srcx = [src.outer.left, src.inner.left, src.inner.right, src.outer.right];
-    let srcy = [src.outer.top, src.inner.top, src.inner.bottom, src.outer.bottom];
+#[cfg(feature = "rayon")]
+impl<S: RatioScalar + Send + Sync> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+    /// Lay out many `(dst, src)` layouts sharing a single `style` in parallel, via [rayon].
+    /// 
+    /// Each thread collects into its own buffer, which are then merged.
+    /// The result is in the same order as [each_dst_src_batch] would emit, with the index into `dst_srcs` each pair of rectangles belongs to.
+    /// 
+    /// [rayon]:                https://docs.rs/rayon/
+    /// [each_dst_src_batch]:   #method.each_dst_src_batch
+    pub fn par_dst_src_batch(dst_srcs: &[(ValidDimensions<S>, ValidDimensions<S>)], style: Style) -> Vec<(usize, ValidRect<S>, ValidRect<S>)> {
+        use rayon_crate::prelude::*;
+        dst_srcs.par_iter().enumerate()
+            .fold(Vec::new, |mut v, (i, (dst, src))| {
+                v.extend(Tiles::new(*dst, *src, style).map(|(dst, src)| (i, dst, src)));
+                v
+            })
+            .reduce(Vec::new, |mut a, mut b| { a.append(&mut b); a })
+    }
+}
+
+impl<D: RatioScalar, S: RatioScalar> Layout<ValidDimensions<D>, ValidDimensions<S>> {
+    /// Enumerate the destination and source rectangles for a layout whose destination and source use different scalar
+    /// types - e.g. `f32` screen coordinates and `i32` texel coordinates.
+    /// 
+    /// The layout math is done in the destination's scalar type:  source coordinates are converted to `D` and back
+    /// (via [ValidDimensions::cast] and [ValidRect::cast]), rounding to the nearest `S`.  Whole tiles round-trip exactly as
+    /// long as `D` can represent every source coordinate (e.g. `f32` texel coordinates up to 2²⁴.)
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let layout = Layout {
+    ///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap(),
+    ///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 7.5, 3.0), inner: Rect::xywh(1.0, 1.0, 5.5, 1.0) }.validate().unwrap(),
+    ///     style: Style::default(),
+    /// };
+    /// 
+    /// let mut rects = Vec::new();
+    /// layout.each_dst_src_mixed(|dst, src| rects.push((Rect::<f32>::from(dst), Rect::<i32>::from(src))));
+    /// assert_eq!(rects[1], (Rect::xywh(1.0, 0.0, 5.5, 1.0), Rect::xywh(1, 0, 1, 1)));
+    /// ```
+    /// 
+    /// [ValidDimensions::cast]:    struct.ValidDimensions.html#method.cast
+    /// [ValidRect::cast]:          struct.ValidRect.html#method.cast
+    pub fn each_dst_src_mixed(&self, mut each_dst_src: impl FnMut(&ValidRect<D>, &ValidRect<S>)) {
+        do_layout_9(self.dst, self.src.cast(), self.style, &mut |tile| each_dst_src(&tile.dst, &tile.src.cast()));
+    }
+}
 
-    for (x, y, horizontal,              vertical                ) in [
-        (0, 0, Scale::Stretch,          Scale::Stretch          ), // Corner:   Top Left
-        (1, 0, Scale::Stretch,          style.border.top        ), // Edge:     Top
-        (2, 0, Scale::Stretch,          Scale::Stretch          ), // Corner:   Top Right
-        (0, 1, style.border.left,       Scale::Stretch          ), // Edge:     Left
-        (1, 1, style.center.horizontal, style.center.vertical   ), // Center
-        (2, 1, style.border.right,      Scale::Stretch          ), // Edge:     Right
-        (0, 2, Scale::Stretch,          Scale::Stretch          ), // Corner:   Bottom Left
-        (1, 2, Scale::Stretch,          style.border.bottom     ), // Edge:     Bottom
-        (2, 2, Scale::Stretch,          Scale::Stretch          ), // Corner:   Bottom Right
-    ].iter().copied() {
-        let (dx0, dx1, dy0, dy1) = (dstx[x+0], dstx[x+1], dsty[y+0], dsty[y+1]);
-        let (sx0, sx1, sy0, sy1) = (srcx[x+0], srcx[x+1], srcy[y+0], srcy[y+1]);
-        do_layout_1(
-            Rect::<S>::from([dx0..dx1, dy0..dy1]).debug_assert_valid(),
-            Rect::<S>::from([sx0..sx1, sy0..sy1]).debug_assert_valid(),
-            horizontal,
-            vertical,
-            each_dst_src,
-        );
+fn do_layout_9<S: RatioScalar>(dst: ValidDimensions<S>, src: ValidDimensions<S>, style: Style, each_tile: &mut impl FnMut(&Tile<S>)) {
+    let mut tiles = Tiles::new(dst, src, style);
+    while let Some(tile) = tiles.next_tile() {
+        each_tile(&tile);
     }
 }
 
-fn do_layout_1<S: Scalar>(dst: ValidRect<S>, src: ValidRect<S>, _horizontal: Scale, _vertical: Scale, each_dst_src: &mut impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
-    // XXX: This is wrong, need to not ignore horizontal / vertical
-    assert_eq!(_horizontal, Scale::Stretch, "Non-default horizontal scale not yet implemented");
-    assert_eq!(_vertical,   Scale::Stretch, "Non-default vertical scale not yet implemented");
-    each_dst_src(&dst, &src);
+/// Intersect `dst` with `clip`, trimming `src` proportionally.  Returns `None` if the intersection is empty.
+fn clip_axis<S: RatioScalar>(dst: Range<S>, src: Range<S>, mirror: bool, clip: Range<S>) -> Option<(Range<S>, Range<S>)> {
+    let start = if dst.start < clip.start { clip.start } else { dst.start };
+    let end   = if clip.end < dst.end     { clip.end   } else { dst.end   };
+    if !(start < end) { return None; }
+
+    let (dst_len, src_len) = (dst.end - dst.start, src.end - src.start);
+    let (head, tail) = ((start - dst.start) * src_len / dst_len, (end - dst.start) * src_len / dst_len);
+    let src = if mirror { src.end - tail .. src.end - head } else { src.start + head .. src.start + tail };
+    Some((start .. end, src))
+}
+
+/// Inset `src` by `inset`, except for edges lying on a cell boundary where the source image is contiguous.
+/// `dst_bounds` and `src_bounds` are `[outer.start, inner.start, inner.end, outer.end]`.  If `flip`, the whole layout
+/// is mirrored along this axis, so destination bound `b` corresponds to source bound `3 - b`.
+fn inset_axis<S: RatioScalar>(dst: Range<S>, src: Range<S>, mirror: bool, dst_bounds: &[S; 4], src_bounds: &[S; 4], flip: bool, inset: S) -> Range<S> {
+    let shared = |dst_edge: S, src_edge: S| (1 ..= 2).any(|b| {
+        let s = if flip { 3 - b } else { b };
+        dst_edge == dst_bounds[b] && dst_bounds[b-1] < dst_bounds[b] && dst_bounds[b] < dst_bounds[b+1] &&
+        src_edge == src_bounds[s] && src_bounds[s-1] < src_bounds[s] && src_bounds[s] < src_bounds[s+1]
+    });
+    let (dst_start, dst_end) = if mirror { (dst.end, dst.start) } else { (dst.start, dst.end) };
+    let zero = S::default();
+    let start = src.start + if shared(dst_start, src.start) { zero } else { inset };
+    let end   = src.end   - if shared(dst_end,   src.end  ) { zero } else { inset };
+    if start <= end { start .. end } else { let mid = src.start + (src.end - src.start) / S::from_count(2); mid .. mid }
 }
 
 /// Expect a basic stretched Z pattern.
@@ -163,6 +623,11 @@ fn do_layout_1<S: Scalar>(dst: ValidRect<S>, src: ValidRect<S>, _horizontal: Sca
         style: Style::default(), // stretch
     };
     let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects, layout.validate().unwrap().to_array());
+    assert!(layout.style.stretches(&Cell::ALL));
+    assert!(!Style::new(Scale::Round).stretches(&Cell::ALL));
+    assert!(!Style { fill: false, ..Style::default() }.stretches(&Cell::ALL));
+    assert!(!Style { mirror_horizontal: true, ..Style::default() }.stretches(&Cell::ALL));
 
     assert_eq!(*rects[0].0, Rect::xywh(0, 0, 1, 1));
     assert_eq!(*rects[1].0, Rect::xywh(1, 0, 3, 1));
@@ -184,3 +649,508 @@ fn do_layout_1<S: Scalar>(dst: ValidRect<S>, src: ValidRect<S>, _horizontal: Sca
     assert_eq!(*rects[7].1, Rect::xywh(1, 2, 1, 1));
     assert_eq!(*rects[8].1, Rect::xywh(2, 2, 1, 1));
 }
+
+/// Expect dashes along the top/bottom edges, with the leftovers as gaps.
+/// 
+/// ### dst
+/// 
+/// ```text
+///   1         11         1
+/// ┊←─→┊←───────────────→┊←─→┊
+/// ┌───┬─┬────┬─┬────┬─┬───┐┈┈
+/// │ 0 │ │ 1  │ │ 2  │ │ 3 │ ↕ 1
+/// ├───┼─┴────┴─┴────┴─┼───┤┈┈
+/// ```
+#[test] fn layout_space_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 6, 3),
+            inner: Rect::xywh(1, 1, 4, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 13, 3),
+            inner: Rect::xywh(1, 1, 11, 1),
+        },
+        style: Style {
+            border: Rect { top: Scale::Space(Justify::Evenly), bottom: Scale::Space(Justify::Evenly), left: Scale::Stretch, right: Scale::Stretch },
+            ..Style::default()
+        },
+    };
+    let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects.len(), 9 + 1 + 1);
+
+    // 2 tiles of 4 in 11 → 3 leftover spread across 3 gaps
+    assert_eq!(*rects[1].0, Rect::xywh(2, 0, 4, 1));
+    assert_eq!(*rects[2].0, Rect::xywh(7, 0, 4, 1));
+    assert_eq!(*rects[1].1, Rect::xywh(1, 0, 4, 1));
+    assert_eq!(*rects[2].1, Rect::xywh(1, 0, 4, 1));
+    assert_eq!(*rects[3].0, Rect::xywh(12, 0, 1, 1));
+
+    assert_eq!(*rects[8].0, Rect::xywh(2, 2, 4, 1));
+    assert_eq!(*rects[9].0, Rect::xywh(7, 2, 4, 1));
+    assert_eq!(*rects[9].1, Rect::xywh(1, 2, 4, 1));
+}
+
+/// Expect the left/right edges to alternate between regular and mirrored tiles.
+#[test] fn layout_mirror_repeat_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 4),
+            inner: Rect::xywh(1, 1, 1, 2),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 7),
+            inner: Rect::xywh(1, 1, 1, 5),
+        },
+        style: Style {
+            border: Rect { top: Scale::Stretch, bottom: Scale::Stretch, left: Scale::MirrorRepeat(Partial::Center), right: Scale::Stretch },
+            ..Style::default()
+        },
+    };
+    let tiles = layout.validate().unwrap().collect_tile_vec();
+    assert_eq!(tiles.len(), 9 + 2);
+    assert_eq!(tiles.iter().filter(|t| t.cell == Cell::Left).count(), 3);
+
+    let left = &tiles[3..6];
+    assert_eq!(*left[0].dst, Rect::xywh(0, 1, 1, 2));
+    assert_eq!(*left[1].dst, Rect::xywh(0, 3, 1, 1));
+    assert_eq!(*left[2].dst, Rect::xywh(0, 4, 1, 2));
+    assert_eq!(*left[0].src, Rect::xywh(0, 1, 1, 2));
+    assert_eq!(*left[1].src, Rect::xywh(0, 2, 1, 1));
+    assert_eq!(*left[2].src, Rect::xywh(0, 1, 1, 2));
+    assert_eq!(left.iter().map(|t| t.mirror.vertical).collect::<Vec<_>>(), vec![false, true, false]);
+    assert!(tiles.iter().all(|t| !t.mirror.horizontal));
+}
+
+/// Expect fixed-size corners, aligned to the outside of the larger dst corners.
+#[test] fn layout_corners_test() {
+    let fixed = |horizontal, vertical| Axises { horizontal: Scale::Fixed(horizontal), vertical: Scale::Fixed(vertical) };
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 6, 6),
+            inner: Rect::xywh(2, 2, 2, 2),
+        },
+        style: Style {
+            corners: Corners {
+                top_left:       fixed(Align::Start, Align::Start),
+                top_right:      fixed(Align::End,   Align::Start),
+                bottom_left:    fixed(Align::Start, Align::End  ),
+                bottom_right:   fixed(Align::End,   Align::End  ),
+            },
+            ..Style::default()
+        },
+    };
+    let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects.len(), 9);
+    assert_eq!(*rects[0].0, Rect::xywh(0, 0, 1, 1));
+    assert_eq!(*rects[1].0, Rect::xywh(2, 0, 2, 2));
+    assert_eq!(*rects[2].0, Rect::xywh(5, 0, 1, 1));
+    assert_eq!(*rects[6].0, Rect::xywh(0, 5, 1, 1));
+    assert_eq!(*rects[8].0, Rect::xywh(5, 5, 1, 1));
+    assert_eq!(*rects[8].1, Rect::xywh(2, 2, 1, 1));
+}
+
+/// Expect hairline borders to stay 1 unit thick, despite thicker dst borders.
+#[test] fn layout_border_cross_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 10, 10),
+            inner: Rect::xywh(3, 3, 4, 4),
+        },
+        style: Style {
+            border_cross: Rect {
+                left:   Scale::Fixed(Align::End),
+                right:  Scale::Fixed(Align::Start),
+                top:    Scale::Fixed(Align::End),
+                bottom: Scale::Fixed(Align::Start),
+            },
+            ..Style::default()
+        },
+    };
+    let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects.len(), 9);
+    assert_eq!(*rects[1].0, Rect::xywh(3, 2, 4, 1));
+    assert_eq!(*rects[3].0, Rect::xywh(2, 3, 1, 4));
+    assert_eq!(*rects[5].0, Rect::xywh(7, 3, 1, 4));
+    assert_eq!(*rects[7].0, Rect::xywh(3, 7, 4, 1));
+    assert_eq!(*rects[7].1, Rect::xywh(1, 2, 1, 1));
+}
+
+/// Expect the center to be skipped without `fill`.
+#[test] fn layout_no_fill_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 5, 4),
+            inner: Rect::xywh(1, 1, 3, 2),
+        },
+        style: Style { fill: false, ..Style::default() },
+    };
+    let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects.len(), 8);
+    assert_eq!(*rects[3].0, Rect::xywh(0, 1, 1, 2));
+    assert_eq!(*rects[4].0, Rect::xywh(4, 1, 1, 2));
+}
+
+/// Expect only the top row for a "header bar" skin.
+#[test] fn layout_cells_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 5, 4),
+            inner: Rect::xywh(1, 1, 3, 2),
+        },
+        style: Style { cells: CellMask::TOP_ROW, ..Style::default() },
+    };
+    let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects.len(), 3);
+    assert_eq!(*rects[0].0, Rect::xywh(0, 0, 1, 1));
+    assert_eq!(*rects[1].0, Rect::xywh(1, 0, 3, 1));
+    assert_eq!(*rects[2].0, Rect::xywh(4, 0, 1, 1));
+}
+
+/// Expect the center to be emitted last.
+#[test] fn layout_order_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 5, 4),
+            inner: Rect::xywh(1, 1, 3, 2),
+        },
+        style: Style { order: EmitOrder::CenterLast, ..Style::default() },
+    };
+
+    let mut cells = Vec::new();
+    layout.validate().unwrap().each_dst_src_cell(|cell, _, _| cells.push(cell));
+    assert_eq!(cells, EmitOrder::CenterLast.cells());
+
+    let rects = layout.validate().unwrap().collect_dst_src_vec();
+    assert_eq!(rects.len(), 9);
+    assert_eq!(*rects[3].0, Rect::xywh(0, 1, 1, 2));
+    assert_eq!(*rects[4].0, Rect::xywh(4, 1, 1, 2));
+    assert_eq!(*rects[8].0, Rect::xywh(1, 1, 3, 2));
+}
+
+/// Expect tiles() to match each_dst_src, including for tiled layouts.
+#[test] fn layout_tiles_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 8, 7),
+            inner: Rect::xywh(1, 1, 6, 5),
+        },
+        style: Style { fill: false, ..Style::new(Scale::Round) },
+    }.validate().unwrap();
+
+    let tiles = layout.tiles().collect::<Vec<_>>();
+    assert_eq!(tiles, layout.collect_dst_src_vec());
+    assert_eq!(tiles, layout.to_vec());
+    assert_eq!(tiles.len(), 4 + 2*6 + 2*5);
+    assert_eq!(tiles.len(), layout.tile_count());
+
+    let mut iter = layout.tiles();
+    for remaining in (0 ..= tiles.len()).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+}
+
+/// Expect `write_dst_src` to fill exactly [tile_count] slots, and to reject storage that's even one slot short.
+#[test] fn layout_write_dst_src_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+        style: Style::new(Scale::Repeat(Partial::Center)),
+    }.validate().unwrap();
+    let n = layout.tile_count();
+    assert_eq!(n, 4 + 2*3 + 2*2 + 3*2);
+
+    let mut storage = [MaybeUninit::uninit(); 32];
+    assert_eq!(layout.write_dst_src(&mut storage[.. n]).unwrap(), n);
+    assert_eq!(layout.write_dst_src(&mut storage[..]).unwrap(), n);
+    assert!(layout.write_dst_src(&mut storage[.. n-1]).is_err());
+    assert!(layout.write_dst_src(&mut []).is_err());
+}
+
+/// Expect `skip_empty` to omit tiles in zero-height borders, and [Tiles] to report the reduced count.
+#[test] fn layout_skip_empty_test() {
+    let layout = |skip_empty| Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 3, 2), inner: Rect::xywh(1, 0, 1, 2) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 10, 4), inner: Rect::xywh(1, 0, 8, 4) },
+        style: Style { skip_empty, ..Style::new(Scale::Round) },
+    }.validate().unwrap();
+
+    assert_eq!(layout(false).tile_count(), 4 + 2*8 + 2*2 + 8*2);
+    assert_eq!(layout(true).tile_count(), 2*2 + 8*2);
+
+    let tiles = layout(true).collect_tile_vec();
+    assert_eq!(tiles.len(), 2*2 + 8*2);
+    assert!(tiles.iter().all(|t| t.dst.width() > 0 && t.dst.height() > 0));
+    assert_eq!(tiles.iter().map(|t| t.cell).filter(|c| *c == Cell::Center).count(), 8*2);
+
+    let mut iter = layout(true).tiles();
+    for remaining in (0 ..= tiles.len()).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+}
+
+#[test] fn layout_inset_test() {
+    let layout = |style| Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 3.0), inner: Rect::xywh(1.0, 1.0, 2.0, 1.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 6.0, 3.0), inner: Rect::xywh(1.0, 1.0, 4.0, 1.0) },
+        style,
+    }.validate().unwrap();
+    let inset = |style| {
+        let mut v = Vec::new();
+        layout(style).each_dst_src_inset(0.25, |_, src| v.push((src.left, src.right)));
+        v
+    };
+
+    // Stretch: only the outer edges
+    assert_eq!(inset(Style::default())[3..6], [(0.25, 1.0), (1.0, 3.0), (3.0, 3.75)]);
+    // Repeat: the seam between the two center tiles, but not the outer edges of the first and last
+    assert_eq!(inset(Style::new(Scale::Repeat(Partial::Center)))[4..8], [(0.25, 1.0), (1.0, 2.75), (1.25, 3.0), (3.0, 3.75)]);
+    // Mirrored:  the second tile's right edge samples the start of the center, which doesn't continue into the right border
+    assert_eq!(inset(Style::new(Scale::MirrorRepeat(Partial::Center)))[5..7], [(1.0, 2.75), (1.25, 2.75)]);
+    // Too narrow to inset:  collapse
+    let mut v = Vec::new();
+    layout(Style::default()).each_dst_src_inset(2.0, |_, src| v.push((src.left, src.right)));
+    assert_eq!(v[3..6], [(0.5, 0.5), (1.0, 3.0), (3.5, 3.5)]);
+}
+
+#[test] fn layout_dst_size_content_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 10, 6), inner: Rect::xywh(2, 0, 6, 6) };
+    let layout = |dst| Layout { src, dst, style: Style::default() }.validate().unwrap();
+    assert_eq!(layout(src).natural_dst_size(), [10, 6]);
+    assert_eq!(layout(src).dst_content_rect(), Rect::xywh(2, 0, 6, 6));
+    assert_eq!(layout(src).dst_content_rect_padded(&Borders { left: 1, right: 1, top: 1, bottom: 1 }).unwrap(), Rect::xywh(3, 1, 4, 4));
+    assert_eq!(layout(Dimensions { outer: Rect::xywh(0, 0, 50, 50), inner: Rect::xywh(4, 0, 42, 50) }).natural_dst_size(), [20, 6]);  // 2x horizontal, no vertical borders
+    assert_eq!(layout(Dimensions { outer: Rect::xywh(0, 0, 50, 50), inner: Rect::xywh(1, 1, 48, 48) }).natural_dst_size(), [5, 8]);   // ½x horizontal, no src vertical borders
+}
+
+#[test] fn layout_map_dst_to_src_test() {
+    let layout = |style| Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 6.0, 6.0), inner: Rect::xywh(2.0, 2.0, 2.0, 2.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 12.0, 10.0), inner: Rect::xywh(2.0, 2.0, 8.0, 6.0) },
+        style,
+    }.validate().unwrap();
+
+    let stretch = layout(Style::default());
+    assert_eq!(stretch.map_dst_to_src([1.0, 1.0]), Some([1.0, 1.0]));
+    assert_eq!(stretch.map_dst_to_src([6.0, 5.0]), Some([3.0, 3.0]));
+    assert_eq!(stretch.map_dst_to_src([11.0, 9.5]), Some([5.0, 5.5]));
+    assert_eq!(stretch.map_dst_to_src([12.0, 5.0]), None);
+    assert_eq!(stretch.map_dst_to_src([-0.5, 5.0]), None);
+    assert_eq!(layout(Style { fill: false, ..Style::default() }).map_dst_to_src([6.0, 5.0]), None);
+
+    let mirror = layout(Style::new(Scale::MirrorRepeat(Partial::Start)));
+    assert_eq!(mirror.map_dst_to_src([2.5, 2.5]), Some([2.5, 2.5]));
+    assert_eq!(mirror.map_dst_to_src([4.5, 4.5]), Some([3.5, 3.5]));    // mirrored
+    assert_eq!(mirror.map_dst_to_src([6.5, 6.5]), Some([2.5, 2.5]));    // wrapped
+
+    // Integer pixels of mirrored tiles stay within the source, and agree with Tile::nearest_src
+    let mirror = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 6, 6), inner: Rect::xywh(2, 2, 2, 2) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 12, 10), inner: Rect::xywh(2, 2, 8, 6) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Start)),
+    }.validate().unwrap();
+    assert_eq!(mirror.map_dst_to_src([4, 4]), Some([3, 3]));
+    assert_eq!(mirror.map_dst_to_src([5, 5]), Some([2, 2]));
+    mirror.each_tile(|tile| {
+        for y in tile.dst.top .. tile.dst.bottom {
+            for x in tile.dst.left .. tile.dst.right {
+                let src = mirror.map_dst_to_src([x, y]).unwrap();
+                assert!(tile.src.contains_point(src), "{:?} maps to {:?}, outside {:?}", [x, y], src, tile.src);
+                assert_eq!(src, tile.nearest_src(x, y));
+            }
+        }
+    });
+
+    let fixed = layout(Style::new(Scale::Fixed(Align::Center)));
+    assert_eq!(fixed.map_dst_to_src([6.5, 5.0]), Some([3.5, 3.0]));
+    assert_eq!(fixed.map_dst_to_src([2.5, 5.0]), None);
+}
+
+#[test] fn layout_clipped_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 4.0), inner: Rect::xywh(1.0, 1.0, 2.0, 2.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 6.0, 6.0), inner: Rect::xywh(1.0, 1.0, 4.0, 4.0) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Start)),
+    }.validate().unwrap();
+    let clipped = |clip: Rect<f32>| {
+        let mut v = Vec::new();
+        layout.each_dst_src_clipped(&clip.validate().unwrap(), |dst, src| v.push((Rect::from(dst), Rect::from(src))));
+        v
+    };
+
+    // Unclipped
+    assert_eq!(clipped(Rect::xywh(-1.0, -1.0, 8.0, 8.0)), layout.collect_dst_src_vec().into_iter().map(|(d, s)| (Rect::from(d), Rect::from(s))).collect::<Vec<_>>());
+    // Fully clipped, or clipped to zero area
+    assert_eq!(clipped(Rect::xywh(6.0, 0.0, 2.0, 2.0)), vec![]);
+    assert_eq!(clipped(Rect::xywh(2.0, 2.0, 0.0, 1.0)), vec![]);
+    // Within the mirrored center tile (dst 3..5 ← src 1..3 flipped)
+    assert_eq!(clipped(Rect::xywh(3.5, 3.5, 1.0, 1.0)), vec![(Rect::xywh(3.5, 3.5, 1.0, 1.0), Rect::xywh(1.5, 1.5, 1.0, 1.0))]);
+    assert_eq!(clipped(Rect::xywh(4.5, 4.5, 0.5, 0.5)), vec![(Rect::xywh(4.5, 4.5, 0.5, 0.5), Rect::xywh(1.0, 1.0, 0.5, 0.5))]);
+    // Straddling the corner and top edge
+    assert_eq!(clipped(Rect::xywh(0.5, 0.0, 1.0, 0.5)), vec![
+        (Rect::xywh(0.5, 0.0, 0.5, 0.5), Rect::xywh(0.5, 0.0, 0.5, 0.5)),
+        (Rect::xywh(1.0, 0.0, 0.5, 0.5), Rect::xywh(1.0, 0.0, 0.5, 0.5)),
+    ]);
+}
+
+/// Expect `mirror_horizontal` to flip an asymmetric skin across dst.outer, swapping the left and right cells.
+#[test] fn layout_mirror_horizontal_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 4, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap();
+    let layout = Layout {
+        dst:    src.with_outer(Rect::xywh(10, 0, 10, 3).validate().unwrap()).unwrap(),
+        src,
+        style:  Style { mirror_horizontal: true, ..Style::default() },
+    };
+
+    let mut tiles = Vec::new();
+    layout.each_tile(|tile| tiles.push((tile.cell, Rect::from(tile.dst), Rect::from(tile.src), tile.mirror.horizontal)));
+    assert_eq!(&tiles[..3], &[
+        (Cell::TopLeft,     Rect::xywh(10, 0, 2, 1), Rect::xywh(2, 0, 2, 1), true),
+        (Cell::Top,         Rect::xywh(12, 0, 7, 1), Rect::xywh(1, 0, 1, 1), true),
+        (Cell::TopRight,    Rect::xywh(19, 0, 1, 1), Rect::xywh(0, 0, 1, 1), true),
+    ]);
+
+    assert_eq!(layout.map_dst_to_src([10, 1]), Some([3, 1]));
+    assert_eq!(layout.map_dst_to_src([19, 1]), Some([0, 1]));
+    assert_eq!(layout.map_dst_to_src([20, 1]), None);
+    for y in 0 .. 3 {
+        for x in 10 .. 20 {
+            let [sx, sy] = layout.map_dst_to_src([x, y]).unwrap();
+            assert!(src.outer().contains_point([sx, sy]), "{:?} maps to {:?}, outside {:?}", [x, y], [sx, sy], src.outer);
+        }
+    }
+
+    // Repeated tiles are reflected too, so partial tiles end up on the left
+    let layout = Layout { style: Style { mirror_horizontal: true, ..Style::new(Scale::Repeat(Partial::Split)) }, ..layout };
+    let mut top = Vec::new();
+    layout.each_tile(|tile| if tile.cell == Cell::Top { top.push(Rect::from(tile.dst)) });
+    assert_eq!(top.len(), 7);
+    assert_eq!(top[0], Rect::xywh(18, 0, 1, 1));
+
+    // Shared source edges are still detected when inset
+    let mut srcs = Vec::new();
+    layout.each_dst_src_inset(1, |_dst, src| srcs.push(Rect::from(src)));
+    assert_eq!(srcs[0], Rect { left: 2, right: 3, top: 1, bottom: 1 });
+}
+
+/// Expect snapped tiles to land on whole pixels, without opening any seams between neighbors.
+#[test] fn layout_snap_test() {
+    let layout = |snap| Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+        dst: Dimensions { outer: Rect { left: 0.5, right: 11.0, top: 0.5, bottom: 3.5 }, inner: Rect { left: 1.5, right: 10.0, top: 1.5, bottom: 2.5 } },
+        style: Style { snap, skip_empty: true, ..Style::new(Scale::Repeat(Partial::Split)) },
+    }.validate().unwrap();
+
+    for snap in [Snap::Edges, Snap::Origin].iter().copied() {
+        let layout = layout(snap);
+        let mut top = Vec::new();
+        layout.each_dst_src(|dst, _src| {
+            if snap == Snap::Edges { for v in [dst.left, dst.right, dst.top, dst.bottom].iter().copied() { assert_eq!(v, crate::scalar::round_scalar(v, Rounding::Nearest)); } }
+            if dst.top == 1.0 { top.push(dst.left .. dst.right); }
+        });
+        assert_eq!(layout.tiles().len(), layout.tiles().count());
+        assert_eq!(top.first().unwrap().start, 1.0);
+        for pair in top.windows(2) { assert_eq!(pair[0].end, pair[1].start); }
+    }
+
+    let mut edges = Vec::new();
+    layout(Snap::Edges).each_dst_src(|dst, _src| if dst.top == 1.0 { edges.push(dst.right) });
+    assert_eq!(edges, vec![2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0]); // the 0.5px partial tile snapped away entirely
+}
+
+#[test] fn layout_check_overflow_test() {
+    let src = Dimensions { outer: Rect::xywh(0_i8, 0, 4, 4), inner: Rect::xywh(1, 1, 2, 2) };
+    let dst = Dimensions { outer: Rect::xywh(0_i8, 0, 100, 100), inner: Rect::xywh(1, 1, 98, 98) };
+    let layout = |style: Style| Layout { src, dst, style };
+
+    for scale in [Scale::Stretch, Scale::Repeat(Partial::Split), Scale::MirrorRepeat(Partial::Center), Scale::Space(Justify::Around), Scale::Fixed(Align::Center), Scale::Crop(Align::End)].iter().copied() {
+        let layout = layout(Style::new(scale)).try_validate().unwrap();
+        assert_eq!(layout.tiles().len(), layout.tiles().count());
+    }
+    assert!(layout(Style::new(Scale::Round)).try_validate().is_err()); // 98 + 98 + 2 overflows
+
+    let shifted = Layout { dst: Dimensions { outer: Rect::xywh(27, 0, 100, 100), inner: Rect::xywh(28, 1, 98, 98) }, ..layout(Style::default()) };
+    assert!(shifted.try_validate().is_ok());
+    assert!(Layout { style: Style { mirror_horizontal: true, ..Style::default() }, ..shifted }.try_validate().is_err()); // 27 + 127 overflows
+
+    let wide = Layout { dst: Dimensions { outer: Rect { left: -100, right: 100, top: 0, bottom: 100 }, inner: Rect { left: -99, right: 99, top: 1, bottom: 99 } }, ..shifted };
+    assert!(wide.validate().is_ok());
+    assert!(wide.try_validate().is_err()); // width 200 overflows
+}
+
+#[test] fn layout_check_overflow_float_test() {
+    let src = Dimensions { outer: Rect::xywh(0.0, 0.0, 1e-30, 1e-30), inner: Rect::xywh(0.0, 0.0, 1e-30, 1e-30) };
+    let dst = Dimensions { outer: Rect::xywh(0.0, 0.0, 1e30, 1e30), inner: Rect::xywh(0.0, 0.0, 1e30, 1e30) };
+    let layout = |scale: Scale| Layout { src, dst, style: Style::new(scale) };
+
+    for scale in [Scale::Repeat(Partial::Split), Scale::MirrorRepeat(Partial::Center), Scale::Space(Justify::Evenly), Scale::Round].iter().copied() {
+        assert!(layout(scale).validate().is_ok());
+        assert!(layout(scale).try_validate().is_err()); // 1e60 tiles doesn't fit in a usize
+    }
+    assert!(layout(Scale::Stretch).try_validate().is_ok());
+}
+
+#[test] fn layout_mixed_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 4, 4), inner: Rect::xywh(1, 1, 2, 2) }.validate().unwrap();
+    let dst = Dimensions { outer: Rect::xywh(0.5, 0.0, 11.0, 4.0), inner: Rect::xywh(1.5, 1.0, 9.0, 2.0) }.validate().unwrap();
+
+    for scale in [Scale::Stretch, Scale::Round, Scale::Repeat(Partial::Start), Scale::Space(Justify::Evenly)].iter().copied() {
+        let style = Style::new(scale);
+        let mut mixed = Vec::new();
+        Layout { dst, src, style }.each_dst_src_mixed(|dst, src| mixed.push((Rect::from(dst), Rect::from(src.to_f32()))));
+        let mut upcast = Vec::new();
+        Layout { dst, src: src.to_f32(), style }.each_dst_src(|dst, src| upcast.push((Rect::from(dst), Rect::from(src))));
+        assert_eq!(mixed, upcast, "{:?}", scale);
+    }
+}
+
+#[cfg(feature = "unsigned-scalar")] #[test] fn layout_saturating_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0_u32, 0, 8, 8), inner: Rect::xywh(1, 1, 6, 6) },
+        dst: Dimensions { outer: Rect::xywh(0_u32, 0, 4, 4), inner: Rect::xywh(1, 1, 2, 2) },
+        style: Style { saturating: true, ..Style::new(Scale::Crop(Align::End)) },
+    };
+    assert!(Layout { style: Style { saturating: false, ..layout.style }, ..layout }.try_validate().is_err());
+
+    let layout = layout.try_validate().unwrap();
+    let mut center = Vec::new();
+    layout.each_dst_src_cell(|cell, dst, src| if cell == Cell::Center { center.push((Rect::from(dst), Rect::from(src))) });
+    assert_eq!(center, vec![(Rect::xywh(1, 1, 2, 2), Rect::xywh(1, 1, 2, 2))]);
+    assert_eq!(layout.map_dst_to_src([2, 2]), Some([2, 2]));
+}
+
+#[cfg(feature = "rayon")] #[test] fn layout_par_batch_test() {
+    let src = Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) }.validate().unwrap();
+    let dst_srcs = (2 .. 100).map(|i| (src.with_outer(Rect::xywh(i, 0, i, i).validate().unwrap()).unwrap(), src)).collect::<Vec<_>>();
+    let style = Style::new(Scale::Round);
+
+    let mut expected = Vec::new();
+    Layout::each_dst_src_batch(&dst_srcs[..], style, |i, dst, src| expected.push((i, *dst, *src)));
+    assert_eq!(expected, Layout::par_dst_src_batch(&dst_srcs[..], style));
+}
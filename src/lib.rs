@@ -25,44 +25,152 @@
 //! 
 //! [Scalar]:   trait.Scalar.html
 //! [uNN]:      https://doc.rust-lang.org/std/primitive.u32.html
+//! [rayon]:    https://docs.rs/rayon/
+//! [bytemuck]: https://docs.rs/bytemuck/
+//! [mint]:     https://docs.rs/mint/
+//! [glam]:     https://docs.rs/glam/
+//! [half]:     https://docs.rs/half/
+//! [euclid]:   https://docs.rs/euclid/
+//! [cgmath]:   https://docs.rs/cgmath/
+//! [nalgebra]: https://docs.rs/nalgebra/
+//! [kurbo]:    https://docs.rs/kurbo/
+//! [sdl2]:     https://docs.rs/sdl2/
+//! [wgpu]:     https://docs.rs/wgpu/
+//! [embedded-graphics]:    https://docs.rs/embedded-graphics/
+//! [tiny-skia]:            https://docs.rs/tiny-skia/
+//! [raqote]:   https://docs.rs/raqote/
+//! [mesh]:     mesh/index.html
+//! [blit]:     blit/index.html
+//! [image]:    https://docs.rs/image/
+//! [ninepatch]:    ninepatch/index.html
+//! [aseprite]:     aseprite/index.html
+//! [atlas::NineSliceAtlas]:    atlas/struct.NineSliceAtlas.html
+//! [serde]:    https://docs.rs/serde/
+//! [Rect]:     struct.Rect.html
+//! [Dimensions]:   struct.Dimensions.html
+//! [Axises]:   struct.Axises.html
+//! [Scale]:    enum.Scale.html
+//! [Style]:    struct.Style.html
+//! [asset::NinePatchAsset]:    asset/struct.NinePatchAsset.html
+//! [std::error::Error]:    https://doc.rust-lang.org/std/error/trait.Error.html
+//! [Vec]:      https://doc.rust-lang.org/alloc/vec/struct.Vec.html
 //! 
 //! ### Crate Features
 //! 
+//! The minimum supported Rust version (1.55) covers the features without optional dependencies:  `std`, `alloc`,
+//! `debug`, `unsigned-scalar`, `blit`, `simd`, and `bytemuck`.  The others pull in crates needing much newer compilers.
+//! 
 //! | feature           | Overview |
 //! | ----------------- | -------- |
 //! | debug             | Enable extra asserts for debugging nines itself.
+//! | std               | (Default: On).  Implement [std::error::Error].  Disable for `#![no_std]` support.  Implies `alloc`.
+//! | alloc             | (Default: On).  Enable [Vec]-returning conveniences on allocator-only `#![no_std]` targets.
+//! | texturepacker     | Load [TexturePacker](https://www.codeandweb.com/texturepacker) JSON into an [atlas::NineSliceAtlas].  Implies `json`.
+//! | wgpu              | Batch layouts into [wgpu] vertex/index buffers via `nines::wgpu`.  Implies `std`.  Needs Rust 1.84+ (for wgpu 25), well past the MSRV.
 //! | unsigned-scalar   | Allow [Scalar] to use underflow-prone [uNN] types.
+//! | blit              | Software 9-slice blitting between raw pixel buffers via [blit].
+//! | simd              | SSE2/NEON fast paths for [blit]ting unscaled rows and single-pixel stretches on x86_64/aarch64.  Other stretches stay scalar.  Implies `blit`.
+//! | bytemuck          | Implement [bytemuck]'s `Pod`/`Zeroable` for [mesh] output, for direct upload to GPU buffers.  Uses `unsafe impl`s, as `#[derive(Pod)]` can't handle generic structs.
+//! | cgmath            | Convert [Rect] and [Axises] to and from [cgmath] points and vectors.  Implies `std`.
+//! | embedded-graphics | Draw 9-sliced images onto any [embedded-graphics] `DrawTarget`, e.g. for monochrome or e-ink displays.
+//! | euclid            | Convert [Rect] and [Dimensions] to and from [euclid] rects, preserving units.
+//! | glam              | Convert [Rect] and [Axises] to and from [glam] vectors, and emit tile corners as `glam::Vec2`s.
+//! | half              | Implement [Scalar] for [half]'s 16-bit `f16` and `bf16` floats, e.g. for compact GPU-adjacent UI coordinates.
+//! | kurbo             | Convert [Rect]`<f64>` to and from [kurbo] rects, and emit tiles as `kurbo::Rect`s.
+//! | mint              | Convert [Rect] and [Axises] to and from [mint] points and vectors.
+//! | nalgebra          | Convert [Rect] and [Axises] to and from [nalgebra] points and vectors.
+//! | rayon             | Enable parallel batch layout via [rayon].  Implies `std`.
+//! | image             | Import Android nine-patch (`.9.png`) markers via [ninepatch] and [image].  Implies `std`.
+//! | sdl2              | Convert [Rect]`<i32>` to and from [sdl2] rects, and draw layouts to an `sdl2::render::Canvas`.  Implies `std`.
+//! | tiny-skia         | Draw 9-sliced [tiny-skia] `Pixmap`s into other `Pixmap`s.  Implies `std`.
+//! | raqote            | Draw 9-sliced [raqote] `Image`s into a `DrawTarget`.  Implies `std`.
+//! | serde             | Implement [serde]'s `Serialize`/`Deserialize` for [Rect], [Dimensions], [Axises], [Scale], [Style], etc.
+//! | aseprite          | Import [Aseprite](https://www.aseprite.org/) 9-slices via [aseprite].  Implies `json`.
+//! | json              | Load/save [asset::NinePatchAsset]s as JSON.  Implies `serde` and `alloc`.
+//! | ron               | Load/save [asset::NinePatchAsset]s as RON.  Implies `serde` and `std`.
+//! | toml              | Load/save [asset::NinePatchAsset]s as TOML.  Implies `serde` and `std`.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#![cfg_attr(not(any(feature = "simd", feature = "bytemuck")), forbid(unsafe_code))]
+
+#![allow(clippy::neg_cmp_op_on_partial_ord)]    // `!(a <= b)` is intentional:  it also rejects NaN.
+#![allow(clippy::identity_op)]                  // `x+0` is used for alignment with `x+1`.
 
 
 
+#[cfg(feature = "alloc")] extern crate alloc;
+
 const DEBUG : bool = cfg!(feature = "debug");
 
+#[cfg(feature = "aseprite")] pub mod aseprite;
+#[cfg(all(feature = "serde", feature = "alloc"))] pub mod asset;
+#[cfg(feature = "alloc")] pub mod atlas;
+#[cfg(feature = "blit")] pub mod blit;
+mod axises;
+mod borders;
+mod cell;
+mod diagnostics;
 mod dimensions;
 mod error;
+mod fill;
+mod grid;
+mod interop;
+mod layers;
 mod layout;
+pub mod mesh;
+#[cfg(feature = "image")] pub mod ninepatch;
+mod npatch;
+mod plan;
 mod rect;
 mod scalar;
 mod scale;
+mod slice;
 mod style;
+mod three_slice;
+mod tile;
+mod track;
+pub mod unity;
+#[cfg(feature = "wgpu")] pub mod wgpu;
 
-pub use dimensions::{Dimensions, ValidDimensions};
+pub use axises::Axises;
+pub use borders::Borders;
+pub use cell::{Cell, CellMask, EmitOrder};
+pub use diagnostics::{AsciiArt, DebugLabel, DebugTile, DebugTiles};
+pub use dimensions::{Dimensions, OverflowPolicy, ValidDimensions};
 pub use error::Error;
+pub use fill::FillDirection;
+pub use grid::{GridDimensions, GridLayout, GridSlice, GridTile};
+pub use layers::{Layer, Layers};
 pub use layout::Layout;
+pub use npatch::{NPatch, Segment};
+pub use plan::LayoutPlan;
 pub use rect::{Rect, ValidRect};
-pub use scalar::Scalar;
-pub use scale::Scale;
-pub use style::Style;
+pub use scalar::{RatioScalar, Rounding, Scalar};
+pub use scale::{Align, Justify, Partial, Remainder, Scale, Span, Spans};
+pub use slice::{BorderImageOutset, BorderImageWidth, SliceSpec};
+pub use style::{Snap, Style};
+pub use three_slice::{HSlice, HSliceLayout, ThreeSlice, ThreeSliceLayout, ValidHSlice, VSlice, VSliceLayout, ValidVSlice};
+pub use tile::{Tile, Tiles};
+pub use track::{Orientation, Track, TrackPart};
 
 pub(crate) use error::err;
 
 
 
-/// A { horizontal, vertical } pair.
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Axises<V: std::fmt::Debug> {
-    /// A horizontal (x-axis) value
-    pub horizontal: V,
+/// A { top_left, top_right, bottom_left, bottom_right } quad.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Corners<V: core::fmt::Debug> {
+    /// The top left corner's value
+    pub top_left:       V,
+
+    /// The top right corner's value
+    pub top_right:      V,
+
+    /// The bottom left corner's value
+    pub bottom_left:    V,
 
-    /// A vertical (y-axis) value
-    pub vertical:   V,
+    /// The bottom right corner's value
+    pub bottom_right:   V,
 }
@@ -0,0 +1,364 @@
+//! Triangle mesh generation, for GPU rendering of [Layout]s.
+//! 
+//! Every [Tile] becomes a quad of four [Vertex]es - top left, top right, bottom left, bottom right - drawn as two
+//! triangles via [QUAD_INDICES].  Vertex construction is left to a closure, so you can normalize UVs, add colors, or
+//! convert into your renderer's own vertex type without an intermediate buffer.
+//! 
+//! [Layout]:       ../struct.Layout.html
+//! [Tile]:         ../struct.Tile.html
+//! [Vertex]:       struct.Vertex.html
+//! [QUAD_INDICES]: constant.QUAD_INDICES.html
+
+use super::*;
+#[cfg(feature = "alloc")] use alloc::vec::Vec;
+
+/// A single corner of a [Tile]'s quad.
+/// 
+/// `#[repr(C)]`, and [bytemuck::Pod] with the `bytemuck` feature, for direct upload to GPU buffers.
+/// 
+/// [Tile]:     ../struct.Tile.html
+/// [bytemuck::Pod]:    https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Vertex<S: RatioScalar> {
+    /// The `[x, y]` position of this vertex, in destination space.
+    pub dst:    [S; 2],
+
+    /// The `[u, v]` of this vertex, in source space (e.g. texels) - divide by your texture size for normalized UVs.
+    pub src:    [S; 2],
+}
+
+/// Indices into [quad_vertices] for the two triangles of a quad.
+/// 
+/// [quad_vertices]:    fn.quad_vertices.html
+pub const QUAD_INDICES : [u16; 6] = [0, 1, 2, 2, 1, 3];
+
+// SAFETY:  `#[repr(C)]` structs of same-typed `Pod` arrays, so there's no padding, and any bit pattern is valid.
+// Implemented by hand because `#[derive(Pod)]` rejects generic structs.
+#[cfg(feature = "bytemuck")] unsafe impl<S: RatioScalar + bytemuck::Zeroable> bytemuck::Zeroable for Vertex<S> {}
+#[cfg(feature = "bytemuck")] unsafe impl<S: RatioScalar + bytemuck::Pod>      bytemuck::Pod      for Vertex<S> {}
+#[cfg(feature = "bytemuck")] unsafe impl<S: RatioScalar + bytemuck::Zeroable> bytemuck::Zeroable for Instance<S> {}
+#[cfg(feature = "bytemuck")] unsafe impl<S: RatioScalar + bytemuck::Pod>      bytemuck::Pod      for Instance<S> {}
+
+/// The four corners of `tile` - top left, top right, bottom left, bottom right - with [Tile::mirror] applied to `src`.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let tile = Tile {
+///     cell:   Cell::Center,
+///     dst:    Rect::xywh(10, 10, 4, 4).validate().unwrap(),
+///     src:    Rect::xywh(1, 1, 2, 2).validate().unwrap(),
+///     mirror: Axises { horizontal: true, vertical: false },
+/// };
+/// let [tl, tr, _, br] = mesh::quad_vertices(&tile);
+/// assert_eq!(tl, mesh::Vertex { dst: [10, 10], src: [3, 1] });
+/// assert_eq!(tr, mesh::Vertex { dst: [14, 10], src: [1, 1] });
+/// assert_eq!(br, mesh::Vertex { dst: [14, 14], src: [1, 3] });
+/// ```
+/// 
+/// [Tile::mirror]: ../struct.Tile.html#structfield.mirror
+#[must_use] pub fn quad_vertices<S: RatioScalar>(tile: &Tile<S>) -> [Vertex<S>; 4] {
+    let (dst, src) = (&tile.dst, &tile.src);
+    let (u0, u1) = if tile.mirror.horizontal { (src.right, src.left) } else { (src.left, src.right) };
+    let (v0, v1) = if tile.mirror.vertical   { (src.bottom, src.top) } else { (src.top, src.bottom) };
+    [
+        Vertex { dst: [dst.left,  dst.top   ], src: [u0, v0] },
+        Vertex { dst: [dst.right, dst.top   ], src: [u1, v0] },
+        Vertex { dst: [dst.left,  dst.bottom], src: [u0, v1] },
+        Vertex { dst: [dst.right, dst.bottom], src: [u1, v1] },
+    ]
+}
+
+/// Per-tile data for instanced rendering of a unit quad (`0 ..= 1` on both axises).  See [instance].
+/// 
+/// Each transform is `[offset_x, offset_y, scale_x, scale_y]`:  a unit quad corner `[x, y]` maps to
+/// `[offset_x + x * scale_x, offset_y + y * scale_y]`.
+/// 
+/// `#[repr(C)]`, and [bytemuck::Pod] with the `bytemuck` feature, for direct upload to GPU buffers.
+/// 
+/// [instance]: fn.instance.html
+/// [bytemuck::Pod]:    https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Instance<S: RatioScalar> {
+    /// Maps the unit quad to destination space.
+    pub dst:    [S; 4],
+
+    /// Maps the unit quad to source space (e.g. texels).  Mirrored axises have a negative scale.
+    pub src:    [S; 4],
+}
+
+/// The [Instance] data for `tile`, with [Tile::mirror] applied to `src`.
+/// 
+/// Mirroring negates the source scale, so mirrored tiles will underflow with unsigned [Scalar]s.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let tile = Tile {
+///     cell:   Cell::Center,
+///     dst:    Rect::xywh(10, 20, 4, 6).validate().unwrap(),
+///     src:    Rect::xywh(1, 1, 2, 2).validate().unwrap(),
+///     mirror: Axises { horizontal: true, vertical: false },
+/// };
+/// let instance = mesh::instance(&tile);
+/// assert_eq!(instance.dst, [10, 20, 4, 6]);
+/// assert_eq!(instance.src, [3, 1, -2, 2]);
+/// ```
+/// 
+/// [Instance]:     struct.Instance.html
+/// [Tile::mirror]: ../struct.Tile.html#structfield.mirror
+/// [Scalar]:       ../trait.Scalar.html
+#[must_use] pub fn instance<S: RatioScalar>(tile: &Tile<S>) -> Instance<S> {
+    let (dst, src, zero) = (&tile.dst, &tile.src, S::default());
+    let (u, du) = if tile.mirror.horizontal { (src.right,  zero - src.width() ) } else { (src.left, src.width() ) };
+    let (v, dv) = if tile.mirror.vertical   { (src.bottom, zero - src.height()) } else { (src.top,  src.height()) };
+    Instance { dst: [dst.left, dst.top, dst.width(), dst.height()], src: [u, v, du, dv] }
+}
+
+/// Enumerate the [Instance] data of every tile in `layout`.
+/// 
+/// [Instance]:     struct.Instance.html
+pub fn each_instance<S: RatioScalar>(layout: &Layout<ValidDimensions<S>, ValidDimensions<S>>, mut each_instance: impl FnMut(&Instance<S>)) {
+    layout.each_tile(|tile| each_instance(&instance(tile)));
+}
+
+/// Collect the [Instance] data of every tile in `layout`.
+/// 
+/// [Instance]:     struct.Instance.html
+#[cfg(feature = "alloc")]
+pub fn instances<S: RatioScalar>(layout: &Layout<ValidDimensions<S>, ValidDimensions<S>>) -> Vec<Instance<S>> {
+    let mut tiles = layout.tiles();
+    let mut v = Vec::with_capacity(tiles.len());
+    while let Some(tile) = tiles.next_tile() { v.push(instance(&tile)); }
+    v
+}
+
+/// A vertex buffer and triangle list index buffer.  See [build].
+/// 
+/// [build]:    fn.build.html
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mesh<V> {
+    pub vertices:   Vec<V>,
+    pub indices:    Vec<u32>,
+}
+
+/// Tessellate `layout` into a [Mesh] of four vertices and six indices per tile, converting each [Vertex] with `vertex`.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let layout = Layout {
+///     src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+///     dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 5.0, 4.0), inner: Rect::xywh(1.0, 1.0, 3.0, 2.0) },
+///     style: Style::default(),
+/// }.validate().unwrap();
+/// 
+/// // Normalize UVs for a 3x3 texture
+/// let mesh = mesh::build(&layout, |v| (v.dst, [v.src[0] / 3.0, v.src[1] / 3.0]));
+/// assert_eq!(mesh.vertices.len(), 9 * 4);
+/// assert_eq!(mesh.indices.len(),  9 * 6);
+/// assert_eq!(mesh.vertices[3], ([1.0, 1.0], [1.0 / 3.0, 1.0 / 3.0]));
+/// ```
+/// 
+/// [Mesh]:     struct.Mesh.html
+/// [Vertex]:   struct.Vertex.html
+#[cfg(feature = "alloc")]
+pub fn build<S: RatioScalar, V>(layout: &Layout<ValidDimensions<S>, ValidDimensions<S>>, mut vertex: impl FnMut(Vertex<S>) -> V) -> Mesh<V> {
+    let mut tiles = layout.tiles();
+    let n = tiles.len();
+    let mut mesh = Mesh { vertices: Vec::with_capacity(4 * n), indices: Vec::with_capacity(6 * n) };
+    while let Some(tile) = tiles.next_tile() {
+        let base = mesh.vertices.len() as u32;
+        mesh.vertices.extend(quad_vertices(&tile).iter().copied().map(&mut vertex));
+        mesh.indices.extend(QUAD_INDICES.iter().map(|i| base + u32::from(*i)));
+    }
+    mesh
+}
+
+/// A compact, watertight mesh for an all-[Scale::Stretch] layout:  a 4×4 grid of shared vertices, and up to 9 quads
+/// (54 indices) of triangles.  See [grid16].
+/// 
+/// [Scale::Stretch]:   ../enum.Scale.html#variant.Stretch
+/// [grid16]:           fn.grid16.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grid16<S: RatioScalar> {
+    /// The vertices, in row-major order.
+    pub vertices:   [Vertex<S>; 16],
+    indices:        [u16; 54],
+    index_count:    usize,
+}
+
+impl<S: RatioScalar> Grid16<S> {
+    /// The triangle list indices into `vertices`, six per emitted cell, in [Style::order].
+    /// 
+    /// [Style::order]: ../struct.Style.html#structfield.order
+    #[must_use] pub fn indices(&self) -> &[u16] { &self.indices[..self.index_count] }
+}
+
+/// Tessellate an all-[Scale::Stretch] `layout` into a [Grid16], sharing vertices between neighboring cells so seams
+/// can't crack.  Respects [Style::fill], [Style::cells], [Style::order], [Style::skip_empty], [Style::mirror_horizontal],
+/// and [Style::snap].
+/// 
+/// Returns `Err` if any emitted cell uses a [Scale] other than [Scale::Stretch] - use [build] for those.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let layout = Layout {
+///     src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+///     dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+///     style: Style::default(),
+/// }.validate().unwrap();
+/// 
+/// let grid = mesh::grid16(&layout).unwrap();
+/// assert_eq!(grid.vertices[5], mesh::Vertex { dst: [1, 1], src: [1, 1] });
+/// assert_eq!(grid.indices().len(), 54);
+/// assert_eq!(&grid.indices()[..6], &[0, 1, 4, 4, 1, 5]);
+/// ```
+/// 
+/// [Scale]:                ../enum.Scale.html
+/// [Scale::Stretch]:       ../enum.Scale.html#variant.Stretch
+/// [Style::fill]:          ../struct.Style.html#structfield.fill
+/// [Style::cells]:         ../struct.Style.html#structfield.cells
+/// [Style::order]:         ../struct.Style.html#structfield.order
+/// [Style::skip_empty]:    ../struct.Style.html#structfield.skip_empty
+/// [Style::mirror_horizontal]: ../struct.Style.html#structfield.mirror_horizontal
+/// [Style::snap]:          ../struct.Style.html#structfield.snap
+/// [Grid16]:               struct.Grid16.html
+/// [build]:                fn.build.html
+pub fn grid16<S: RatioScalar>(layout: &Layout<ValidDimensions<S>, ValidDimensions<S>>) -> Result<Grid16<S>, Error> {
+    let (dst, src, style) = (&layout.dst, &layout.src, &layout.style);
+    let mut dst_xs = [dst.outer.left, dst.inner.left, dst.inner.right,  dst.outer.right ];
+    let dst_ys = [dst.outer.top,  dst.inner.top,  dst.inner.bottom, dst.outer.bottom];
+    let mut src_xs = [src.outer.left, src.inner.left, src.inner.right,  src.outer.right ];
+    let src_ys = [src.outer.top,  src.inner.top,  src.inner.bottom, src.outer.bottom];
+    if style.mirror_horizontal {
+        // Reflect across dst.outer, keeping vertices in left-to-right order so triangle winding is preserved
+        let sum = dst.outer.left + dst.outer.right;
+        dst_xs = [sum - dst_xs[3], sum - dst_xs[2], sum - dst_xs[1], sum - dst_xs[0]];
+        src_xs.reverse();
+    }
+    let dst_xs = dst_xs.map(|x| style.snap.snap(x, dst.outer.left));
+    let dst_ys = dst_ys.map(|y| style.snap.snap(y, dst.outer.top));
+
+    let mut vertices = [Vertex { dst: [S::default(); 2], src: [S::default(); 2] }; 16];
+    for (i, v) in vertices.iter_mut().enumerate() {
+        let (x, y) = (i % 4, i / 4);
+        *v = Vertex { dst: [dst_xs[x], dst_ys[y]], src: [src_xs[x], src_ys[y]] };
+    }
+
+    let mut indices = [0; 54];
+    let mut index_count = 0;
+    for cell in style.order.cells().iter().copied().filter(|cell| style.emits(*cell)) {
+        let scale = style.cell_scale(style.src_cell(cell));
+        if scale.horizontal != Scale::Stretch || scale.vertical != Scale::Stretch { return err("Expected only Scale::Stretch for grid16"); }
+        let [x, y] = cell.xy();
+        if style.skip_empty && !(dst_xs[x] < dst_xs[x+1] && dst_ys[y] < dst_ys[y+1]) { continue; }
+        let base = (y * 4 + x) as u16;
+        let corners = [base, base + 1, base + 4, base + 5];
+        for (o, i) in indices[index_count..index_count+6].iter_mut().zip(QUAD_INDICES.iter()) { *o = corners[*i as usize]; }
+        index_count += 6;
+    }
+
+    Ok(Grid16 { vertices, indices, index_count })
+}
+
+
+
+#[cfg(feature = "alloc")] #[test] fn mesh_build_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+        style: Style { fill: false, ..Style::new(Scale::MirrorRepeat(Partial::Center)) },
+    }.validate().unwrap();
+
+    let mesh = build(&layout, |v| v);
+    let tiles = layout.to_tile_vec();
+    assert_eq!(mesh.vertices.len(), tiles.len() * 4);
+    assert_eq!(mesh.indices.len(),  tiles.len() * 6);
+    assert_eq!(&mesh.indices[6..12], &[4, 5, 6, 6, 5, 7]);
+    assert!(mesh.indices.iter().all(|i| (*i as usize) < mesh.vertices.len()));
+
+    for (tile, quad) in tiles.iter().zip(mesh.vertices.chunks(4)) {
+        assert_eq!(quad, &quad_vertices(tile)[..]);
+        assert_eq!(quad[0].dst, [tile.dst.left,  tile.dst.top   ]);
+        assert_eq!(quad[3].dst, [tile.dst.right, tile.dst.bottom]);
+    }
+
+    // The second tile of the top border is mirrored horizontally
+    let top = &mesh.vertices[4*2 .. 4*3];
+    assert_eq!(top[0], Vertex { dst: [2, 0], src: [2, 0] });
+    assert_eq!(top[3], Vertex { dst: [3, 1], src: [1, 1] });
+}
+
+#[test] fn mesh_grid16_test() {
+    let layout = |style| Layout {
+        src: Dimensions { outer: Rect::xywh(0, 0, 3, 3), inner: Rect::xywh(1, 1, 1, 1) },
+        dst: Dimensions { outer: Rect::xywh(0, 0, 5, 4), inner: Rect::xywh(1, 1, 3, 2) },
+        style,
+    }.validate().unwrap();
+
+    // Every quad of the grid should match the corresponding tile's quad
+    for mirror_horizontal in [false, true].iter().copied() {
+        let style = Style { order: EmitOrder::CenterFirst, mirror_horizontal, ..Style::default() };
+        let grid = grid16(&layout(style)).unwrap();
+        let mut tiles = layout(style).tiles();
+        let mut quads = grid.indices().chunks(6);
+        while let Some(tile) = tiles.next_tile() {
+            let quad = quads.next().unwrap();
+            let [tl, tr, bl, br] = quad_vertices(&tile);
+            assert_eq!([grid.vertices[quad[0] as usize], grid.vertices[quad[1] as usize], grid.vertices[quad[2] as usize], grid.vertices[quad[5] as usize]], [tl, tr, bl, br]);
+        }
+        assert!(quads.next().is_none());
+        assert_eq!(&grid.indices()[..6], &[5, 6, 9, 9, 6, 10]);
+    }
+
+    assert_eq!(grid16(&layout(Style { fill: false, ..Style::default() })).unwrap().indices().len(), 48);
+    assert!(grid16(&layout(Style::new(Scale::Round))).is_err());
+    assert!(grid16(&layout(Style { fill: false, cells: CellMask::CORNERS, ..Style::new(Scale::Round) })).is_ok());
+}
+
+#[cfg(feature = "alloc")] #[test] fn mesh_instances_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 5.0, 5.0), inner: Rect::xywh(1.0, 1.0, 3.0, 3.0) },
+        style: Style::new(Scale::MirrorRepeat(Partial::Center)),
+    }.validate().unwrap();
+
+    let instances = instances(&layout);
+    let mut each = Vec::new();
+    each_instance(&layout, |i| each.push(*i));
+    assert_eq!(instances, each);
+    assert_eq!(instances.len(), layout.tile_count());
+
+    // Transforming the unit quad's corners should reproduce each tile's mesh quad
+    for (instance, tile) in instances.iter().zip(layout.to_tile_vec()) {
+        let map = |t: &[f64; 4], x: f64, y: f64| [t[0] + x * t[2], t[1] + y * t[3]];
+        let [tl, _, _, br] = quad_vertices(&tile);
+        assert_eq!(tl, Vertex { dst: map(&instance.dst, 0.0, 0.0), src: map(&instance.src, 0.0, 0.0) });
+        assert_eq!(br, Vertex { dst: map(&instance.dst, 1.0, 1.0), src: map(&instance.src, 1.0, 1.0) });
+    }
+    assert!(instances.iter().any(|i| i.src[2] < 0.0));
+    assert!(instances.iter().any(|i| i.src[3] < 0.0));
+}
+
+#[cfg(all(feature = "bytemuck", feature = "alloc"))] #[test] fn mesh_bytemuck_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 5.0, 4.0), inner: Rect::xywh(1.0, 1.0, 3.0, 2.0) },
+        style: Style::default(),
+    }.validate().unwrap();
+
+    let mesh = build(&layout, |v: Vertex<f32>| v);
+    let floats : &[f32] = bytemuck::cast_slice(&mesh.vertices[..]);
+    assert_eq!(floats.len(), mesh.vertices.len() * 4);
+    assert_eq!(&floats[4..8], &[1.0, 0.0, 1.0, 0.0]);
+
+    let instances = instances(&layout);
+    let bytes : &[u8] = bytemuck::cast_slice(&instances[..]);
+    assert_eq!(bytes.len(), instances.len() * 8 * 4);
+    assert_eq!(bytemuck::cast_slice::<u8, Instance<f32>>(bytes), &instances[..]);
+}
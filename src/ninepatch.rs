@@ -0,0 +1,224 @@
+//! Android nine-patch (`.9.png`) import.
+//! 
+//! A nine-patch image is a regular image surrounded by a 1px marker border.  Opaque black pixels along the top and
+//! left edges mark the horizontal and vertical stretch regions, which become the `inner` rect of the imported
+//! [Dimensions].  Opaque black pixels along the bottom and right edges mark the content area ([NinePatch::content]),
+//! and opaque red pixels at the ends of the bottom and right edges inset the optical bounds ([NinePatch::optical]).
+//! The rest of the border must be fully transparent.
+//! 
+//! ```text
+//!     ┊  ┊←stretch→┊  ┊
+//!    ┌──┬──█████████──┬──┐
+//!    │  ┌──┬─────────┬──┐
+//!    │  │  │         │  │
+//! ┈┈┈█  ├──┼─────────┼──┤┈┈┈
+//!  ↑ █  │  │  inner  │  │
+//!  ↓ █  │  │         │  │
+//! ┈┈┈█  ├──┼─────────┼──┤┈┈┈
+//!    │  │  │         │  │
+//!    │  └──┴─────────┴──┘   █
+//!    └──█████████████───┘
+//!       ←─ content ─→
+//! ```
+//! 
+//! Coordinates are in pixels of the full `.9.png` - `outer` excludes the marker border, so the result can be used as
+//! the `src` of a [Layout] sampling the `.9.png` texture directly.
+//! 
+//! [Dimensions]:   ../struct.Dimensions.html
+//! [Layout]:       ../struct.Layout.html
+//! [NinePatch::content]:   struct.NinePatch.html#structfield.content
+//! [NinePatch::optical]:   struct.NinePatch.html#structfield.optical
+
+use super::*;
+use core::convert::TryFrom;
+use core::ops::Range;
+use ::image_crate::{GenericImageView, Rgba};
+
+/// All the markers of an Android nine-patch image.  See [decode].
+/// 
+/// [decode]:   fn.decode.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NinePatch {
+    /// The image (`outer`, excluding the marker border) and its stretch region (`inner`.)
+    pub src:        ValidDimensions<i32>,
+
+    /// The area to place content (e.g. text) within, from the bottom and right markers.  Defaults to `src.inner`.
+    pub content:    ValidRect<i32>,
+
+    /// The optical bounds of the image, for alignment purposes, from red "layout bounds" markers.  Defaults to `src.outer`.
+    pub optical:    ValidRect<i32>,
+}
+
+/// Decode the stretch markers of an Android nine-patch image.  See also [decode].
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let mut image = image_crate::RgbaImage::new(6, 5);
+/// for x in 2 .. 4 { image.put_pixel(x, 0, image_crate::Rgba([0, 0, 0, 255])); }
+/// image.put_pixel(0, 2, image_crate::Rgba([0, 0, 0, 255]));
+/// 
+/// let src = ninepatch::from_image(&image).unwrap();
+/// assert_eq!(src.outer, Rect { left: 1, right: 5, top: 1, bottom: 4 });
+/// assert_eq!(src.inner, Rect { left: 2, right: 4, top: 2, bottom: 3 });
+/// ```
+/// 
+/// [decode]:   fn.decode.html
+pub fn from_image<I: GenericImageView<Pixel = Rgba<u8>>>(image: &I) -> Result<ValidDimensions<i32>, Error> {
+    Ok(decode(image)?.src)
+}
+
+/// Decode the stretch, content padding, and optical bounds markers of an Android nine-patch image.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let (black, red) = (image_crate::Rgba([0, 0, 0, 255]), image_crate::Rgba([255, 0, 0, 255]));
+/// let mut image = image_crate::RgbaImage::new(8, 8);
+/// image.put_pixel(3, 0, black);                               // horizontal stretch
+/// image.put_pixel(0, 3, black);                               // vertical stretch
+/// for x in 2 .. 6 { image.put_pixel(x, 7, black); }          // horizontal content
+/// for y in 2 .. 6 { image.put_pixel(7, y, black); }          // vertical content
+/// image.put_pixel(1, 7, red);                                 // left optical inset
+/// 
+/// let patch = ninepatch::decode(&image).unwrap();
+/// assert_eq!(patch.src.inner, Rect { left: 3, right: 4, top: 3, bottom: 4 });
+/// assert_eq!(patch.content,   Rect { left: 2, right: 6, top: 2, bottom: 6 });
+/// assert_eq!(patch.optical,   Rect { left: 2, right: 7, top: 1, bottom: 7 });
+/// ```
+pub fn decode<I: GenericImageView<Pixel = Rgba<u8>>>(image: &I) -> Result<NinePatch, Error> {
+    let (width, height) = image.dimensions();
+    if width < 3 || height < 3 { return err("Expected a nine-patch image of at least 3x3 pixels, including markers"); }
+    let (right, bottom) = (width-1, height-1);
+
+    let corners = [(0, 0), (right, 0), (0, bottom), (right, bottom)];
+    if corners.iter().any(|&(x, y)| image.get_pixel(x, y).0[3] != 0) { return err("Expected nine-patch corner pixels to be transparent"); }
+
+    let xs = stretch(1 .. right, |x| marker(image.get_pixel(x, 0), false))?;
+    let ys = stretch(1 .. bottom, |y| marker(image.get_pixel(0, y), false))?;
+    let content_xs = run(1 .. right, |x| marker(image.get_pixel(x, bottom), true))?.unwrap_or_else(|| xs.clone());
+    let content_ys = run(1 .. bottom, |y| marker(image.get_pixel(right, y), true))?.unwrap_or_else(|| ys.clone());
+    let optical_xs = optical(1 .. right, |x| marker(image.get_pixel(x, bottom), true))?;
+    let optical_ys = optical(1 .. bottom, |y| marker(image.get_pixel(right, y), true))?;
+
+    Ok(NinePatch {
+        src: Dimensions {
+            outer: Rect { left: 1,                          right: coord(right)?,               top: 1,                             bottom: coord(bottom)?              },
+            inner: Rect { left: coord(xs.start)?,           right: coord(xs.end)?,              top: coord(ys.start)?,              bottom: coord(ys.end)?              },
+        }.validate()?,
+        content: Rect { left: coord(content_xs.start)?,     right: coord(content_xs.end)?,      top: coord(content_ys.start)?,      bottom: coord(content_ys.end)?      }.validate()?,
+        optical: Rect { left: coord(optical_xs.start)?,     right: coord(optical_xs.end)?,      top: coord(optical_ys.start)?,      bottom: coord(optical_ys.end)?      }.validate()?,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Marker { None, Black, Red }
+
+/// Classify `pixel` as a marker.  Red optical bounds markers are only valid along the bottom and right edges.
+fn marker(pixel: Rgba<u8>, allow_red: bool) -> Result<Marker, Error> {
+    match pixel.0 {
+        [0, 0, 0, 255]                  => Ok(Marker::Black),
+        [255, 0, 0, 255] if allow_red   => Ok(Marker::Red),
+        [_, _, _, 0]                    => Ok(Marker::None),
+        _                               => err("Expected nine-patch marker pixels to be opaque black, opaque red, or fully transparent"),
+    }
+}
+
+/// Find the single contiguous run of black markers within `range`.
+fn stretch(range: Range<u32>, is_marker: impl FnMut(u32) -> Result<Marker, Error>) -> Result<Range<u32>, Error> {
+    match run(range, is_marker)? {
+        Some(run)   => Ok(run),
+        None        => err("Expected a nine-patch stretch region"),
+    }
+}
+
+/// Shrink `range` by the red markers at either end of it.
+fn optical(range: Range<u32>, mut is_marker: impl FnMut(u32) -> Result<Marker, Error>) -> Result<Range<u32>, Error> {
+    let mut start = range.start;
+    while start < range.end && is_marker(start)? == Marker::Red { start += 1; }
+    let mut end = range.end;
+    while end > start && is_marker(end-1)? == Marker::Red { end -= 1; }
+    for i in start .. end { if is_marker(i)? == Marker::Red { return err("Expected nine-patch optical bounds markers at the ends of the bottom and right edges"); } }
+    Ok(start .. end)
+}
+
+/// Find the single contiguous run of black markers within `range`, if any.
+fn run(range: Range<u32>, mut is_marker: impl FnMut(u32) -> Result<Marker, Error>) -> Result<Option<Range<u32>>, Error> {
+    let mut run : Option<Range<u32>> = None;
+    for i in range {
+        if is_marker(i)? != Marker::Black { continue; }
+        run = match run {
+            None                                => Some(i .. i+1),
+            Some(run) if run.end == i           => Some(run.start .. i+1),
+            Some(_)                             => return err("Expected a single contiguous nine-patch marker region"),
+        };
+    }
+    Ok(run)
+}
+
+fn coord(value: u32) -> Result<i32, Error> {
+    match i32::try_from(value) {
+        Ok(value)   => Ok(value),
+        Err(_)      => err("Nine-patch image too large"),
+    }
+}
+
+
+
+#[test] fn ninepatch_test() {
+    const BLACK : Rgba<u8> = Rgba([0, 0, 0, 255]);
+    let mut image = image_crate::RgbaImage::new(10, 8);
+    for x in 3 .. 7 { image.put_pixel(x, 0, BLACK); }
+    for y in 2 .. 6 { image.put_pixel(0, y, BLACK); }
+    let src = from_image(&image).unwrap();
+    assert_eq!(src.outer, Rect { left: 1, right: 9, top: 1, bottom: 7 });
+    assert_eq!(src.inner, Rect { left: 3, right: 7, top: 2, bottom: 6 });
+
+    let mut split = image.clone();
+    split.put_pixel(5, 0, Rgba([0, 0, 0, 0]));
+    assert!(from_image(&split).is_err());
+
+    let mut gray = image.clone();
+    gray.put_pixel(5, 0, Rgba([128, 128, 128, 255]));
+    assert!(from_image(&gray).is_err());
+
+    let mut corner = image.clone();
+    corner.put_pixel(0, 0, BLACK);
+    assert!(from_image(&corner).is_err());
+
+    assert!(from_image(&image_crate::RgbaImage::new(10, 8)).is_err());
+    assert!(from_image(&image_crate::RgbaImage::new(2, 8)).is_err());
+}
+
+#[test] fn ninepatch_padding_optical_test() {
+    const BLACK : Rgba<u8> = Rgba([0, 0, 0, 255]);
+    const RED   : Rgba<u8> = Rgba([255, 0, 0, 255]);
+    let mut image = image_crate::RgbaImage::new(10, 8);
+    for x in 3 .. 7 { image.put_pixel(x, 0, BLACK); }
+    for y in 2 .. 6 { image.put_pixel(0, y, BLACK); }
+
+    let patch = decode(&image).unwrap();
+    assert_eq!(patch.content, patch.src.inner);
+    assert_eq!(patch.optical, patch.src.outer);
+
+    for x in 2 .. 8 { image.put_pixel(x, 7, BLACK); }
+    for y in 3 .. 5 { image.put_pixel(9, y, BLACK); }
+    image.put_pixel(1, 7, RED);
+    image.put_pixel(8, 7, RED);
+    image.put_pixel(9, 6, RED);
+    let patch = decode(&image).unwrap();
+    assert_eq!(patch.content, Rect { left: 2, right: 8, top: 3, bottom: 5 });
+    assert_eq!(patch.optical, Rect { left: 2, right: 8, top: 1, bottom: 6 });
+
+    let mut red_stretch = image.clone();
+    red_stretch.put_pixel(3, 0, RED);
+    assert!(decode(&red_stretch).is_err());
+
+    let mut red_middle = image.clone();
+    red_middle.put_pixel(9, 2, RED);
+    assert!(decode(&red_middle).is_err());
+
+    let mut split_content = image.clone();
+    split_content.put_pixel(5, 7, Rgba([0, 0, 0, 0]));
+    assert!(decode(&split_content).is_err());
+}
@@ -0,0 +1,145 @@
+use super::*;
+use core::ops::Range;
+
+/// A fixed or stretchable run of source pixels along one axis of an [NPatch].
+/// 
+/// [NPatch]:   struct.NPatch.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment<S: RatioScalar> {
+    /// Drawn at its source length, unless the destination is too small to fit all fixed segments.
+    Fixed(S),
+
+    /// Stretched to share any extra destination length, in proportion to its source length.
+    Stretch(S),
+}
+
+impl<S: RatioScalar> Segment<S> {
+    /// The source length of this segment.
+    #[must_use] pub fn len(self) -> S { match self { Segment::Fixed(len) | Segment::Stretch(len) => len } }
+
+    #[must_use] pub fn is_stretch(self) -> bool { matches!(self, Segment::Stretch(_)) }
+}
+
+/// A generalized nine-patch, with any number of fixed and stretchable segments per axis - e.g. Android nine-patches
+/// with multiple disjoint stretch regions, which a single [Layout] can't represent.
+/// 
+/// Like Android, extra destination length is shared between stretch segments in proportion to their source lengths.
+/// If the destination is too small to fit all fixed segments (or there are no stretch segments), the fixed segments are
+/// scaled proportionally instead, and stretch segments collapse to nothing.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// use nines::Segment::*;
+/// 
+/// // A tab: fixed ends, fixed center notch, stretched on either side of the notch.
+/// let horizontal = [Fixed(2), Stretch(1), Fixed(4), Stretch(1), Fixed(2)];
+/// let vertical   = [Fixed(2), Stretch(2), Fixed(2)];
+/// let npatch = NPatch::new(Rect::xywh(0, 0, 10, 6).validate().unwrap(), &horizontal, &vertical).unwrap();
+/// 
+/// let mut rects = Vec::new();
+/// npatch.each_dst_src(&Rect::xywh(0, 0, 20, 6).validate().unwrap(), |dst, src| rects.push((Rect::from(dst), Rect::from(src))));
+/// assert_eq!(rects.len(), 5 * 3);
+/// assert_eq!(rects[1], (Rect::xywh(2, 0, 6, 2), Rect::xywh(2, 0, 1, 2)));
+/// assert_eq!(rects[2], (Rect::xywh(8, 0, 4, 2), Rect::xywh(3, 0, 4, 2)));
+/// ```
+/// 
+/// [Layout]:   struct.Layout.html
+#[derive(Clone, Copy, Debug)]
+pub struct NPatch<'a, S: RatioScalar> {
+    src:        ValidRect<S>,
+    horizontal: &'a [Segment<S>],
+    vertical:   &'a [Segment<S>],
+}
+
+impl<'a, S: RatioScalar> NPatch<'a, S> {
+    /// Create a new N-patch.  Returns `Err` if any segment has a negative (or NaN) length, or if the segments of an
+    /// axis don't sum to the width/height of `src`.
+    pub fn new(src: ValidRect<S>, horizontal: &'a [Segment<S>], vertical: &'a [Segment<S>]) -> Result<Self, Error> {
+        let zero = S::default();
+        if !horizontal.iter().all(|s| zero <= s.len()) { return err("Expected 0 ≤ horizontal segment lengths"); }
+        if !vertical  .iter().all(|s| zero <= s.len()) { return err("Expected 0 ≤ vertical segment lengths"); }
+        if horizontal.iter().fold(zero, |sum, s| sum + s.len()) != src.width()  { return err("Expected horizontal segments to sum to src width"); }
+        if vertical  .iter().fold(zero, |sum, s| sum + s.len()) != src.height() { return err("Expected vertical segments to sum to src height"); }
+        Ok(Self { src, horizontal, vertical })
+    }
+
+    #[must_use] pub fn src(&self) -> ValidRect<S> { self.src }
+    #[must_use] pub fn horizontal(&self) -> &'a [Segment<S>] { self.horizontal }
+    #[must_use] pub fn vertical(&self) -> &'a [Segment<S>] { self.vertical }
+
+    /// How many tiles [each_dst_src] will emit.
+    /// 
+    /// [each_dst_src]:     #method.each_dst_src
+    #[must_use] pub fn tile_count(&self) -> usize { self.horizontal.len() * self.vertical.len() }
+
+    /// Enumerate the destination and source rectangles of every segment pair, in row-major order.
+    pub fn each_dst_src(&self, dst: &ValidRect<S>, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) {
+        for (dst_y, src_y) in segment_spans(self.vertical.iter().copied(), self.src.top, dst.top .. dst.bottom) {
+            for (dst_x, src_x) in segment_spans(self.horizontal.iter().copied(), self.src.left, dst.left .. dst.right) {
+                let dst = Rect { left: dst_x.start, right: dst_x.end, top: dst_y.start, bottom: dst_y.end }.debug_assert_valid();
+                let src = Rect { left: src_x.start, right: src_x.end, top: src_y.start, bottom: src_y.end }.debug_assert_valid();
+                each_dst_src(&dst, &src);
+            }
+        }
+    }
+}
+
+/// Distribute `dst` between `segments`, yielding `(dst, src)` ranges.
+/// 
+/// Boundaries are computed from cumulative lengths, so integer rounding never leaves a gap at the end of `dst`.
+pub(crate) fn segment_spans<S: RatioScalar>(segments: impl Iterator<Item = Segment<S>> + Clone, src_start: S, dst: Range<S>) -> impl Iterator<Item = (Range<S>, Range<S>)> {
+    let zero = S::default();
+    let (fixed, stretch) = segments.clone().fold((zero, zero), |(f, s), seg| if seg.is_stretch() { (f, s + seg.len()) } else { (f + seg.len(), s) });
+    let dst_len = dst.end - dst.start;
+    let scale_fixed = !(fixed <= dst_len) || !(zero < stretch);
+
+    let (mut cum_fixed, mut cum_stretch, mut src_pos, mut dst_pos) = (zero, zero, src_start, dst.start);
+    segments.map(move |seg| {
+        if seg.is_stretch() { cum_stretch = cum_stretch + seg.len(); } else { cum_fixed = cum_fixed + seg.len(); }
+        let dst_end = if scale_fixed {
+            if zero < fixed { dst.start + cum_fixed * dst_len / fixed } else { dst.start }
+        } else {
+            dst.start + cum_fixed + cum_stretch * (dst_len - fixed) / stretch
+        };
+        let src_end = src_pos + seg.len();
+        let r = (dst_pos .. dst_end, src_pos .. src_end);
+        dst_pos = dst_end;
+        src_pos = src_end;
+        r
+    })
+}
+
+
+
+#[test] fn npatch_test() {
+    use Segment::*;
+    let horizontal = [Fixed(2), Stretch(1), Fixed(2), Stretch(3), Fixed(2)];
+    let vertical = [Stretch(4)];
+    let npatch = NPatch::new(Rect::xywh(10, 20, 10, 4).validate().unwrap(), &horizontal, &vertical).unwrap();
+    assert_eq!(npatch.tile_count(), 5);
+
+    let collect = |dst: Rect<i32>| {
+        let mut v = Vec::new();
+        npatch.each_dst_src(&dst.validate().unwrap(), |dst, src| v.push((dst.left .. dst.right, src.left .. src.right)));
+        assert_eq!(v.len(), npatch.tile_count());
+        v
+    };
+
+    // Stretch: 12 extra split 1:3
+    assert_eq!(collect(Rect::xywh(0, 0, 18, 4)), vec![(0..2, 10..12), (2..5, 12..13), (5..7, 13..15), (7..16, 15..18), (16..18, 18..20)]);
+    // Natural size
+    assert_eq!(collect(Rect::xywh(0, 0, 10, 4)), vec![(0..2, 10..12), (2..3, 12..13), (3..5, 13..15), (5..8, 15..18), (8..10, 18..20)]);
+    // Too small for the fixed segments:  scale them, collapse stretch
+    assert_eq!(collect(Rect::xywh(0, 0, 3, 4)), vec![(0..1, 10..12), (1..1, 12..13), (1..2, 13..15), (2..2, 15..18), (2..3, 18..20)]);
+
+    assert!(NPatch::new(Rect::xywh(0, 0, 9, 4).validate().unwrap(), &horizontal, &vertical).is_err());
+    assert!(NPatch::new(Rect::xywh(0, 0, 10, 4).validate().unwrap(), &horizontal, &[Stretch(5), Fixed(-1)]).is_err());
+
+    let fixed_only = [Fixed(1.0), Fixed(3.0)];
+    let npatch = NPatch::new(Rect::xywh(0.0, 0.0, 4.0, 4.0).validate().unwrap(), &fixed_only, &fixed_only).unwrap();
+    let mut v = Vec::new();
+    npatch.each_dst_src(&Rect::xywh(0.0, 0.0, 8.0, 2.0).validate().unwrap(), |dst, _| v.push(*dst));
+    assert_eq!(v, vec![Rect::xywh(0.0, 0.0, 2.0, 0.5), Rect::xywh(2.0, 0.0, 6.0, 0.5), Rect::xywh(0.0, 0.5, 2.0, 1.5), Rect::xywh(2.0, 0.5, 6.0, 1.5)]);
+}
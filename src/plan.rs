@@ -0,0 +1,141 @@
+use super::*;
+use crate::tile::CellSpans;
+
+/// A precompiled [Layout] for repeatedly laying out the same skin at different destination sizes.
+/// 
+/// Everything derivable from `src`, `style`, and the destination border sizes is validated once, up front - and the
+/// tiling of the fixed size border columns and rows (corners included) is laid out once, and only moved into place by
+/// [emit](#method.emit).  Only the stretchy middle column and row are laid out per call.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let src = Dimensions {
+///     outer: Rect::xywh(0, 0, 3, 3),
+///     inner: Rect::xywh(1, 1, 1, 1),
+/// }.validate().unwrap();
+/// let plan = LayoutPlan::new(src, src.borders(), Style::default()).unwrap();
+/// 
+/// for width in 2 .. 100 {
+///     let mut rects = Vec::new();
+///     plan.emit(Rect::xywh(0, 0, width, 10).validate().unwrap(), |dst, src| rects.push((*dst, *src))).unwrap();
+///     assert_eq!(rects[2].0, Rect::xywh(width-1, 0, 1, 1));
+/// }
+/// ```
+/// 
+/// [Layout]:   struct.Layout.html
+#[derive(Clone, Debug)]
+pub struct LayoutPlan<S: RatioScalar> {
+    src:        ValidDimensions<S>,
+    borders:    Borders<S>,
+    style:      Style,
+    spans:      CellSpans<S>,
+}
+
+impl<S: RatioScalar> LayoutPlan<S> {
+    /// Create a new plan.  Returns `Err` if `dst_borders` has negative (or NaN) sizes.
+    pub fn new(src: ValidDimensions<S>, dst_borders: Borders<S>, style: Style) -> Result<Self, Error> {
+        let zero = S::default();
+        if !(zero <= dst_borders.left  ) { return err("Expected 0 ≤ dst_borders.left"); }
+        if !(zero <= dst_borders.right ) { return err("Expected 0 ≤ dst_borders.right"); }
+        if !(zero <= dst_borders.top   ) { return err("Expected 0 ≤ dst_borders.top"); }
+        if !(zero <= dst_borders.bottom) { return err("Expected 0 ≤ dst_borders.bottom"); }
+        Ok(Self::new_unchecked(src, dst_borders, style))
+    }
+
+    fn new_unchecked(src: ValidDimensions<S>, borders: Borders<S>, style: Style) -> Self {
+        let zero = S::default();
+        let spans = Cell::ALL.map(|art| {
+            let ([x, y], src, scale) = (art.xy(), src.cell(art), style.cell_scale(art));
+            let fixed = |i: usize, start: S, end: S| match i { 0 => Some(zero .. start), 2 => Some(zero .. end), _ => None };
+            Axises {
+                horizontal: fixed(x, borders.left, borders.right ).map(|dst| scale.horizontal.spans_with(dst, src.left..src.right, style.remainder).saturating(style.saturating)),
+                vertical:   fixed(y, borders.top,  borders.bottom).map(|dst| scale.vertical  .spans_with(dst, src.top..src.bottom, style.remainder).saturating(style.saturating)),
+            }
+        });
+        Self { src, borders, style, spans }
+    }
+
+    #[must_use] pub fn src(&self) -> ValidDimensions<S> { self.src }
+    #[must_use] pub fn dst_borders(&self) -> Borders<S> { self.borders }
+    #[must_use] pub fn style(&self) -> Style { self.style }
+
+    /// Get the full [Layout] for a given destination outer rect.
+    /// 
+    /// Can return Err if the center would have negative bounds.
+    /// 
+    /// [Layout]:   struct.Layout.html
+    pub fn layout(&self, dst_outer: ValidRect<S>) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Error> {
+        let b = self.borders;
+        if b.left + b.right > dst_outer.width()  { return err("Resulting dimensions would have a negative center width"); }
+        if b.top + b.bottom > dst_outer.height() { return err("Resulting dimensions would have a negative center height"); }
+        let dst = Dimensions { inner: dst_outer.shrink(&b), outer: *dst_outer }.debug_assert_valid();
+        Ok(Layout { dst, src: self.src, style: self.style })
+    }
+
+    /// Enumerate the destination and source rectangles for a given destination outer rect.  See [Layout::each_dst_src].
+    /// 
+    /// Can return Err (without enumerating anything) if the center would have negative bounds.
+    /// 
+    /// [Layout::each_dst_src]:     struct.Layout.html#method.each_dst_src
+    pub fn emit(&self, dst_outer: ValidRect<S>, mut each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) -> Result<(), Error> {
+        let mut tiles = self.layout(dst_outer)?.tiles();
+        while let Some(tile) = tiles.next_tile_cached(Some(&self.spans)) {
+            each_dst_src(&tile.dst, &tile.src);
+        }
+        Ok(())
+    }
+}
+
+impl<S: RatioScalar> From<&Layout<ValidDimensions<S>, ValidDimensions<S>>> for LayoutPlan<S> {
+    fn from(layout: &Layout<ValidDimensions<S>, ValidDimensions<S>>) -> Self {
+        Self::new_unchecked(layout.src, layout.dst.borders(), layout.style)
+    }
+}
+
+
+
+#[test] fn layout_plan_test() {
+    let layout = Layout {
+        src: Dimensions {
+            outer: Rect::xywh(0, 0, 3, 3),
+            inner: Rect::xywh(1, 1, 1, 1),
+        },
+        dst: Dimensions {
+            outer: Rect::xywh(0, 0, 5, 4),
+            inner: Rect::xywh(1, 1, 3, 2),
+        },
+        style: Style::new(Scale::Repeat(Partial::Center)),
+    }.validate().unwrap();
+    let plan = LayoutPlan::from(&layout);
+
+    let mut expected = Vec::new();
+    layout.each_dst_src(|dst, src| expected.push((*dst, *src)));
+    let mut actual = Vec::new();
+    plan.emit(layout.dst.outer(), |dst, src| actual.push((*dst, *src))).unwrap();
+    assert_eq!(expected, actual);
+
+    assert!(plan.emit(Rect::xywh(0, 0, 1, 4).validate().unwrap(), |_, _| panic!()).is_err());
+    assert!(plan.emit(Rect::xywh(0, 0, 5, 1).validate().unwrap(), |_, _| panic!()).is_err());
+    assert!(LayoutPlan::new(layout.src, Borders { left: -1, right: 0, top: 0, bottom: 0 }, Style::default()).is_err());
+
+    // Cached border spans must match laying out from scratch, at any size, with tiled borders and corners
+    let src = Dimensions { outer: Rect::xywh(0, 0, 7, 6), inner: Rect::xywh(2, 1, 3, 3) }.validate().unwrap();
+    let mut corners = Style::new(Scale::MirrorRepeat(Partial::Split));
+    corners.corners.top_left = Axises { horizontal: Scale::Repeat(Partial::Center), vertical: Scale::Round };
+    corners.corners.bottom_right = Axises { horizontal: Scale::Fixed(Align::End), vertical: Scale::Space(Justify::Evenly) };
+    let mirrored = Style { mirror_horizontal: true, ..corners };
+    for style in [Style::default(), Style::new(Scale::Round), corners, mirrored].iter().copied() {
+        let plan = LayoutPlan::new(src, Borders { left: 5, right: 3, top: 4, bottom: 2 }, style).unwrap();
+        for (w, h) in [(8, 6), (9, 7), (20, 11), (31, 17)].iter().copied() {
+            let dst_outer = Rect::xywh(3, -2, w, h).validate().unwrap();
+            let mut expected = Vec::new();
+            plan.layout(dst_outer).unwrap().each_dst_src(|dst, src| expected.push((*dst, *src)));
+            let mut actual = Vec::new();
+            plan.emit(dst_outer, |dst, src| actual.push((*dst, *src))).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use super::*;
-use std::convert::TryFrom;
-use std::fmt::Debug;
-use std::ops::{Deref, Range};
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::ops::{Deref, Mul, Range};
 
 /// A rectangle with non-negative & non-NAN dimensions.
 /// 
 /// Do not base soundness assumptions on this definition of validity - `debug_assert_valid` can bypass checks.
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Rect<S>", into = "Rect<S>"))] // Deserialization goes through `validate()`
 pub struct ValidRect<S: Scalar>(Rect<S>);
 
 impl<S: Scalar> Deref for ValidRect<S> { type Target = Rect<S>; fn deref(&self) -> &Self::Target { &self.0 } }
@@ -29,6 +31,7 @@ impl<S: Scalar> AsRef<Rect<S>> for ValidRect<S> { fn as_ref(&self) -> &Rect<S> {
 /// 
 /// [ValidRect]:    struct.ValidRect.html
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect<V: Debug> {
     pub left:   V,
     pub right:  V,
@@ -40,12 +43,149 @@ impl<S: Scalar> ValidRect<S> {
     #[must_use] pub fn width(&self) -> S { self.right - self.left }
     #[must_use] pub fn height(&self) -> S { self.bottom - self.top }
     #[must_use] pub fn size(&self) -> [S; 2] { [self.width(), self.height()] }
+
+    #[must_use] pub fn top_left    (&self) -> [S; 2] { [self.left,  self.top   ] }
+    #[must_use] pub fn top_right   (&self) -> [S; 2] { [self.right, self.top   ] }
+    #[must_use] pub fn bottom_left (&self) -> [S; 2] { [self.left,  self.bottom] }
+    #[must_use] pub fn bottom_right(&self) -> [S; 2] { [self.right, self.bottom] }
+
+    /// The midpoint of this rect.  Integer coordinates round towards the top left.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(1, 1, 4, 5).validate().unwrap().center(), [3, 3]);
+    /// assert_eq!(Rect::xywh(1.0, 1.0, 4.0, 5.0).validate().unwrap().center(), [3.0, 3.5]);
+    /// ```
+    #[must_use] pub fn center(&self) -> [S; 2] where S: RatioScalar {
+        let two = S::from_count(2);
+        [self.left + self.width() / two, self.top + self.height() / two]
+    }
+
+    /// `[width, height]`, or Err if either would overflow `S` - e.g. an `i32` rect spanning `i32::MIN .. i32::MAX`.
+    pub fn try_size(&self) -> Result<[S; 2], Error> {
+        match (self.right.checked_sub(self.left), self.bottom.checked_sub(self.top)) {
+            (Some(width), Some(height)) => Ok([width, height]),
+            _ => err("Expected width and height not to overflow"),
+        }
+    }
+
+    /// Move by `dx` horizontally and `dy` vertically.  See [Rect::translate].
+    /// 
+    /// [Rect::translate]:  struct.Rect.html#method.translate
+    #[must_use] pub fn translate(&self, dx: S, dy: S) -> Self { self.0.translate(dx, dy).debug_assert_valid() }
+
+    /// Move so the top left corner is at `[0, 0]`.  See [Rect::at_origin].  Never underflows, even for unsigned scalars.
+    /// 
+    /// [Rect::at_origin]:  struct.Rect.html#method.at_origin
+    #[must_use] pub fn at_origin(&self) -> Self { self.0.at_origin().debug_assert_valid() }
+
+    /// The smallest rect containing both `self` and `other`.  See [Rect::union].
+    /// 
+    /// [Rect::union]:  struct.Rect.html#method.union
+    #[must_use] pub fn union(&self, other: &Self) -> Self { self.0.union(&other.0).debug_assert_valid() }
+
+    /// Convert to another scalar type, rounding to the nearest `T`.  See [Rect::cast].
+    /// 
+    /// Conversions never reorder coordinates, so the result is still valid.
+    /// 
+    /// [Rect::cast]:   struct.Rect.html#method.cast
+    #[must_use] pub fn cast<T: Scalar>(&self) -> ValidRect<T> { self.0.cast().debug_assert_valid() }
+
+    /// Convert to `f32`.  See [Rect::to_f32].
+    /// 
+    /// [Rect::to_f32]: struct.Rect.html#method.to_f32
+    #[must_use] pub fn to_f32(&self) -> ValidRect<f32> { self.0.to_f32().debug_assert_valid() }
+
+    /// Convert to `i32`, rounding per `rounding`.  See [Rect::to_i32].
+    /// 
+    /// [Rect::to_i32]: struct.Rect.html#method.to_i32
+    #[must_use] pub fn to_i32(&self, rounding: Rounding) -> ValidRect<i32> { self.0.to_i32(rounding).debug_assert_valid() }
+}
+
+impl<V: Debug> Rect<V> {
+    /// Convert every coordinate with `f`.  The result isn't validated - `f` might not preserve ordering.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let rect = Rect::xywh(1, 2, 3, 4).map(|v| v as f32 * 0.5);
+    /// assert_eq!(rect, Rect::xywh(0.5, 1.0, 1.5, 2.0));
+    /// ```
+    #[must_use] pub fn map<T: Debug>(self, mut f: impl FnMut(V) -> T) -> Rect<T> {
+        Rect { left: f(self.left), right: f(self.right), top: f(self.top), bottom: f(self.bottom) }
+    }
 }
 
 impl<S: Scalar> Rect<S> {
     #[must_use] pub fn xywh(x: S, y: S, w: S, h: S) -> Self { Self { left: x, top: y, right: x + w, bottom: y + h } }
 
-    #[must_use] pub fn grow(&self, borders: &Self) -> Self {
+    /// A `[width, height]` rect with its top left corner at `[0, 0]`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::from_size([3, 4]), Rect::xywh(0, 0, 3, 4));
+    /// ```
+    #[must_use] pub fn from_size(size: [S; 2]) -> Self { let [w, h] = size; Self::xywh(S::default(), S::default(), w, h) }
+
+    /// Keep the top left corner, but resize to `[width, height]`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(1, 2, 3, 4).with_size([10, 20]), Rect::xywh(1, 2, 10, 20));
+    /// ```
+    #[must_use] pub fn with_size(&self, size: [S; 2]) -> Self { let [w, h] = size; Self::xywh(self.left, self.top, w, h) }
+
+    /// The top left corner, `[left, top]`.
+    #[must_use] pub fn position(&self) -> [S; 2] { [self.left, self.top] }
+
+    /// `[right - left, bottom - top]`.  Unlike [ValidRect::size], this may be negative (or NaN.)
+    /// 
+    /// [ValidRect::size]:  struct.ValidRect.html#method.size
+    #[must_use] pub fn size(&self) -> [S; 2] { [self.right - self.left, self.bottom - self.top] }
+
+    /// Grow both horizontal edges outwards by `dx`, and both vertical edges by `dy` - or shrink, if negative.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(10, 10, 5, 5).inflate(1, 2), Rect::xywh(9, 8, 7, 9));
+    /// assert_eq!(Rect::xywh(10, 10, 5, 5).inflate(-1, 0), Rect::xywh(11, 10, 3, 5));
+    /// ```
+    #[must_use] pub fn inflate(&self, dx: S, dy: S) -> Self {
+        Self { left: self.left - dx, right: self.right + dx, top: self.top - dy, bottom: self.bottom + dy }
+    }
+
+    /// Move by `dx` horizontally and `dy` vertically, keeping the same size.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(1, 2, 3, 4).translate(10, -2), Rect::xywh(11, 0, 3, 4));
+    /// ```
+    #[must_use] pub fn translate(&self, dx: S, dy: S) -> Self {
+        Self { left: self.left + dx, right: self.right + dx, top: self.top + dy, bottom: self.bottom + dy }
+    }
+
+    /// Move so the top left corner is at `[0, 0]`, keeping the same size.
+    /// 
+    /// Can underflow unsigned scalars if `right < left` or `bottom < top` - [ValidRect::at_origin] never does.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(1, 2, 3, 4).at_origin(), Rect::xywh(0, 0, 3, 4));
+    /// ```
+    /// 
+    /// [ValidRect::at_origin]: struct.ValidRect.html#method.at_origin
+    #[must_use] pub fn at_origin(&self) -> Self {
+        let zero = S::default();
+        Self { left: zero, right: self.right - self.left, top: zero, bottom: self.bottom - self.top }
+    }
+
+    #[must_use] pub fn grow(&self, borders: &Borders<S>) -> Self {
         Self {
             left:   self.left   - borders.left,
             right:  self.right  + borders.right,
@@ -54,7 +194,14 @@ impl<S: Scalar> Rect<S> {
         }
     }
 
-    #[must_use] pub fn shrink(&self, borders: &Self) -> Self {
+    /// Move each edge inwards by the matching border.
+    /// 
+    /// Only the right and bottom edges are subtracted from, so with unsigned scalars this never underflows as long as
+    /// the result is valid - check that `borders` fit first (as [ValidDimensions::with_outer] does), or use [try_shrink].
+    /// 
+    /// [ValidDimensions::with_outer]:  struct.ValidDimensions.html#method.with_outer
+    /// [try_shrink]:                   #method.try_shrink
+    #[must_use] pub fn shrink(&self, borders: &Borders<S>) -> Self {
         Self {
             left:   self.left   + borders.left,
             right:  self.right  - borders.right,
@@ -63,18 +210,169 @@ impl<S: Scalar> Rect<S> {
         }
     }
 
+    /// Like [grow](#method.grow), but returns Err instead of overflowing.
+    pub fn try_grow(&self, borders: &Borders<S>) -> Result<Self, Error> {
+        match (self.left.checked_sub(borders.left), self.right.checked_add(borders.right), self.top.checked_sub(borders.top), self.bottom.checked_add(borders.bottom)) {
+            (Some(left), Some(right), Some(top), Some(bottom)) => Ok(Self { left, right, top, bottom }),
+            _ => err("Expected grow not to overflow"),
+        }
+    }
+
+    /// Like [shrink](#method.shrink), but returns Err instead of overflowing.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let rect = Rect { left: 0, right: 100, top: 0, bottom: 100 };
+    /// assert_eq!(rect.try_shrink(&Borders { left: 1, right: 2, top: 3, bottom: 4 }).unwrap(), Rect { left: 1, right: 98, top: 3, bottom: 96 });
+    /// assert!(rect.try_shrink(&Borders { left: i32::MAX, right: 0, top: 0, bottom: 0 }).is_ok());
+    /// assert!(rect.try_shrink(&Borders { left: 0, right: i32::MIN, top: 0, bottom: 0 }).is_err());
+    /// ```
+    pub fn try_shrink(&self, borders: &Borders<S>) -> Result<Self, Error> {
+        match (self.left.checked_add(borders.left), self.right.checked_sub(borders.right), self.top.checked_add(borders.top), self.bottom.checked_sub(borders.bottom)) {
+            (Some(left), Some(right), Some(top), Some(bottom)) => Ok(Self { left, right, top, bottom }),
+            _ => err("Expected shrink not to overflow"),
+        }
+    }
+
+    /// The smallest rect containing both `self` and `other` - e.g. for damage tracking.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(0, 0, 2, 2).union(&Rect::xywh(5, 1, 1, 3)), Rect { left: 0, right: 6, top: 0, bottom: 4 });
+    /// ```
+    #[must_use] pub fn union(&self, other: &Self) -> Self {
+        let min = |a: S, b: S| if b < a { b } else { a };
+        let max = |a: S, b: S| if a < b { b } else { a };
+        Self { left: min(self.left, other.left), right: max(self.right, other.right), top: min(self.top, other.top), bottom: max(self.bottom, other.bottom) }
+    }
+
+    /// The overlap between `self` and `other` - e.g. for clipping.  Returns `None` if they don't overlap at all, or if
+    /// either is invalid.  Rects that only share an edge overlap with zero width or height.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let a = Rect::xywh(0, 0, 4, 4);
+    /// assert_eq!(a.intersection(&Rect::xywh(2, 3, 5, 5)), Some(Rect::xywh(2, 3, 2, 1).validate().unwrap()));
+    /// assert_eq!(a.intersection(&Rect::xywh(4, 0, 1, 1)), Some(Rect::xywh(4, 0, 0, 1).validate().unwrap()));
+    /// assert_eq!(a.intersection(&Rect::xywh(5, 0, 1, 1)), None);
+    /// ```
+    #[must_use] pub fn intersection(&self, other: &Self) -> Option<ValidRect<S>> {
+        let min = |a: S, b: S| if b < a { b } else { a };
+        let max = |a: S, b: S| if a < b { b } else { a };
+        let (a, b) = (self.validate().ok()?, other.validate().ok()?);
+        Self { left: max(a.left, b.left), right: min(a.right, b.right), top: max(a.top, b.top), bottom: min(a.bottom, b.bottom) }.validate().ok()
+    }
+
+    /// Check if `[x, y]` is within this rect - e.g. for hit testing.  Bounds are half-open:  the right and bottom edges
+    /// aren't included, so a point on a shared edge is only contained by one of two adjacent rects.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let r = Rect::xywh(0, 0, 4, 4);
+    /// assert!( r.contains_point([0, 3]));
+    /// assert!(!r.contains_point([4, 3]));
+    /// ```
+    #[must_use] pub fn contains_point(&self, point: [S; 2]) -> bool {
+        let [x, y] = point;
+        self.left <= x && x < self.right && self.top <= y && y < self.bottom
+    }
+
+    /// Check if `other` is entirely within this rect, edges included.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let r = Rect::xywh(0, 0, 4, 4);
+    /// assert!( r.contains_rect(&Rect::xywh(0, 0, 4, 4)));
+    /// assert!( r.contains_rect(&Rect::xywh(1, 1, 2, 2)));
+    /// assert!(!r.contains_rect(&Rect::xywh(1, 1, 4, 2)));
+    /// ```
+    #[must_use] pub fn contains_rect(&self, other: &Self) -> bool {
+        self.left <= other.left && other.right <= self.right && self.top <= other.top && other.bottom <= self.bottom
+    }
+
     /// Validate this rectangle has non-negative / non-NaN dimensions.  This means:
     /// 
     /// ```text
     /// left ≤ right
     /// top ≤ bottom
     /// ```
-    #[must_use] pub fn validate(&self) -> Result<ValidRect<S>, Error> {
+    pub fn validate(&self) -> Result<ValidRect<S>, Error> {
         if !(self.left <= self.right) { return err("Expected left ≤ right"); }
         if !(self.top <= self.bottom) { return err("Expected top ≤ bottom"); }
         Ok(ValidRect(*self))
     }
 
+    /// Multiply all coordinates by a (e.g. DPI) scale factor, rounding integer coordinates per `rounding`, and validate the result.
+    /// 
+    /// Returns Err if the result is invalid - e.g. if `self` was, or if `factor` is negative or NaN.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let rect = Rect::xywh(1, 1, 3, 3);
+    /// assert_eq!(rect.scale(1.5, Rounding::Floor).unwrap(), Rect { left: 1, top: 1, right: 6, bottom: 6 });
+    /// assert_eq!(rect.scale(1.5, Rounding::Ceil ).unwrap(), Rect { left: 2, top: 2, right: 6, bottom: 6 });
+    /// assert!(rect.scale(-1.0, Rounding::Floor).is_err());
+    /// ```
+    pub fn scale(&self, factor: f64, rounding: Rounding) -> Result<ValidRect<S>, Error> {
+        Rect {
+            left:   self.left  .mul_f64(factor, rounding),
+            right:  self.right .mul_f64(factor, rounding),
+            top:    self.top   .mul_f64(factor, rounding),
+            bottom: self.bottom.mul_f64(factor, rounding),
+        }.validate()
+    }
+
+    /// Convert to another scalar type (via [Scalar::to_f64] and [Scalar::from_f64]), rounding to the nearest `T`.
+    /// Integer results saturate at the limits of `T`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let texels = Rect::xywh(1, 2, 3, 4);
+    /// assert_eq!(texels.cast::<f32>(), Rect::xywh(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(Rect::xywh(0.5, 1.4, 2.0, 2.0).cast::<i32>(), Rect { left: 1, right: 3, top: 1, bottom: 3 });
+    /// ```
+    /// 
+    /// [Scalar::to_f64]:   trait.Scalar.html#tymethod.to_f64
+    /// [Scalar::from_f64]: trait.Scalar.html#tymethod.from_f64
+    #[must_use] pub fn cast<T: Scalar>(&self) -> Rect<T> { self.map(|v| T::from_f64(v.to_f64(), Rounding::Nearest)) }
+
+    /// Convert to `f32`.
+    #[must_use] pub fn to_f32(&self) -> Rect<f32> { self.cast() }
+
+    /// Convert to `i32`, rounding per `rounding`.  Results saturate at the limits of `i32`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let rect = Rect::xywh(0.5, 0.5, 2.0, 2.0);
+    /// assert_eq!(rect.to_i32(Rounding::Floor), Rect { left: 0, right: 2, top: 0, bottom: 2 });
+    /// assert_eq!(rect.to_i32(Rounding::Ceil ), Rect { left: 1, right: 3, top: 1, bottom: 3 });
+    /// ```
+    #[must_use] pub fn to_i32(&self, rounding: Rounding) -> Rect<i32> { self.map(|v| i32::from_f64(v.to_f64(), rounding)) }
+
+    /// Multiply horizontal coordinates by `sx` and vertical coordinates by `sy` - e.g. for a UI zoom level - and
+    /// validate the result.
+    /// 
+    /// Returns Err if the result is invalid - e.g. if `self` was, or if `sx` or `sy` is negative or NaN.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Rect::xywh(1, 2, 3, 4).scaled(2, 3).unwrap(), Rect::xywh(2, 6, 6, 12));
+    /// assert_eq!(Rect::xywh(1, 2, 3, 4) * 2, Rect::xywh(2, 4, 6, 8));
+    /// assert!(Rect::xywh(1, 2, 3, 4).scaled(-1, 1).is_err());
+    /// ```
+    pub fn scaled(&self, sx: S, sy: S) -> Result<ValidRect<S>, Error> where S: RatioScalar {
+        Rect { left: self.left * sx, right: self.right * sx, top: self.top * sy, bottom: self.bottom * sy }.validate()
+    }
+
     #[must_use] pub(crate) fn debug_assert_valid(&self) -> ValidRect<S> {
         if DEBUG {
             assert!(self.left <= self.right, "Expected left ≤ right");
@@ -108,8 +406,16 @@ impl<S: Scalar> From<(Range<S>, Range<S>)> for Rect<S> {
     }
 }
 
+/// Multiply every coordinate by `rhs`.  See [Rect::scaled] to validate the result.
+/// 
+/// [Rect::scaled]: struct.Rect.html#method.scaled
+impl<S: RatioScalar> Mul<S> for Rect<S> {
+    type Output = Self;
+    fn mul(self, rhs: S) -> Self { self.map(|v| v * rhs) }
+}
+
 #[test] fn rect_test() {
-    use std::f32::NAN;
+    const NAN : f32 = f32::NAN;
 
     let r = Rect::xywh(10, 20, 30, 40).validate().unwrap();
     assert_eq!(r.left,      10);
@@ -137,3 +443,73 @@ impl<S: Scalar> From<(Range<S>, Range<S>)> for Rect<S> {
     assert!(Rect::xywh(0.0, 0.0, NAN, 0.0).validate().is_err());
     assert!(Rect::xywh(0.0, 0.0, 0.0, NAN).validate().is_err());
 }
+
+#[test] fn rect_cast_test() {
+    let r = Rect { left: -0.5, right: 2.5, top: 1e20, bottom: f32::INFINITY };
+    assert_eq!(r.to_i32(Rounding::Floor),       Rect { left: -1, right: 2, top: i32::MAX, bottom: i32::MAX });
+    assert_eq!(r.to_i32(Rounding::NearestEven), Rect { left:  0, right: 2, top: i32::MAX, bottom: i32::MAX });
+    assert_eq!(r.cast::<i8>(),                  Rect { left: -1, right: 3, top: i8::MAX,  bottom: i8::MAX  });
+    assert_eq!(r.cast::<f64>().right, 2.5);
+
+    let valid = r.validate().unwrap();
+    let _ : ValidRect<i32> = valid.to_i32(Rounding::Ceil);
+    let _ : ValidRect<f32> = valid.cast::<i16>().to_f32();
+    assert_eq!(Rect::xywh(1, 2, 3, 4).map(|v| v * 2), Rect::xywh(2, 4, 6, 8));
+}
+
+#[test] fn rect_translate_test() {
+    let r = Rect::xywh(10.0, 20.0, 30.0, 40.0).validate().unwrap();
+    assert_eq!(r.translate(-10.0, 5.0), Rect::xywh(0.0, 25.0, 30.0, 40.0));
+    assert_eq!(r.translate(1.5, 0.0).at_origin(), Rect::xywh(0.0, 0.0, 30.0, 40.0));
+    assert_eq!(r.translate(0.0, 0.0), r);
+
+    let inverted = Rect { left: 5, right: 1, top: 0, bottom: 0 };
+    assert_eq!(inverted.at_origin(), Rect { left: 0, right: -4, top: 0, bottom: 0 });
+    assert_eq!(inverted.translate(1, 1), Rect { left: 6, right: 2, top: 1, bottom: 1 });
+}
+
+#[test] fn rect_set_test() {
+    let a = Rect::xywh(0.0, 0.0, 4.0, 4.0).validate().unwrap();
+    let b = Rect::xywh(2.0, -1.0, 4.0, 2.0).validate().unwrap();
+    assert_eq!(a.union(&b), Rect { left: 0.0, right: 6.0, top: -1.0, bottom: 4.0 });
+    assert_eq!(a.intersection(&b), Some(Rect { left: 2.0, right: 4.0, top: 0.0, bottom: 1.0 }.validate().unwrap()));
+    assert_eq!(a.intersection(&b), b.intersection(&a));
+    assert!(a.union(&b).contains_rect(&a) && a.union(&b).contains_rect(&b));
+    assert!(a.contains_rect(&a.intersection(&b).unwrap()));
+    assert!(!a.contains_rect(&b));
+
+    let nan = Rect::xywh(f32::NAN, 0.0, 1.0, 1.0);
+    assert_eq!(a.intersection(&nan), None);
+    assert!(!nan.contains_point([0.0, 0.0]));
+    assert!(!a.contains_point([f32::NAN, 0.0]));
+    assert!(a.contains_point([3.5, 0.0]));
+}
+
+#[test] fn rect_size_test() {
+    let r = Rect::from_size([2.0, 3.0]);
+    assert_eq!(r.position(), [0.0, 0.0]);
+    assert_eq!(r.size(), [2.0, 3.0]);
+    assert_eq!(r.translate(1.0, 1.0).with_size([5.0, 5.0]), Rect::xywh(1.0, 1.0, 5.0, 5.0));
+    assert_eq!(r.inflate(0.5, 0.5).size(), [3.0, 4.0]);
+    assert_eq!(r.inflate(-1.5, 0.0).size(), [-1.0, 3.0]);
+    assert!(r.inflate(-1.5, 0.0).validate().is_err());
+}
+
+#[test] fn rect_corners_test() {
+    let r = Rect::xywh(-3, 10, 4, 6).validate().unwrap();
+    assert_eq!(r.top_left(),        [-3, 10]);
+    assert_eq!(r.top_right(),       [ 1, 10]);
+    assert_eq!(r.bottom_left(),     [-3, 16]);
+    assert_eq!(r.bottom_right(),    [ 1, 16]);
+    assert_eq!(r.center(),          [-1, 13]);
+    assert_eq!(Rect::xywh(-3, -3, 1, 1).validate().unwrap().center(), [-3, -3]);
+}
+
+#[test] fn rect_scaled_test() {
+    let r = Rect::xywh(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(r.scaled(0.5, 2.0).unwrap(), Rect::xywh(0.5, 4.0, 1.5, 8.0));
+    assert_eq!(r.scaled(0.0, 0.0).unwrap(), Rect::xywh(0.0, 0.0, 0.0, 0.0));
+    assert_eq!(r * 1.5, Rect::xywh(1.5, 3.0, 4.5, 6.0));
+    assert!(r.scaled(f32::NAN, 1.0).is_err());
+    assert!(r.scaled(1.0, -2.0).is_err());
+}
@@ -1,28 +1,309 @@
-use std::ops::{Add, Sub};
-use std::fmt::{Debug};
+use core::ops::{Add, Sub, Mul, Div};
+use core::fmt::{Debug};
 
 /// [iNN] or [fNN] intrinsics.  Opt-in to underflow-prone [uNN] support via `"unsigned-scalars"` feature.
 /// 
+/// This is the minimal bound:  enough to validate [Rect]s and [Dimensions], resize 9-slices with
+/// [ValidDimensions::with_outer], and get the 9 stretched cells of a [Layout] via [Layout::to_array].  The tiling
+/// [Scale]s ([Scale::Repeat], [Scale::Round], [Scale::Space], etc.) also multiply, divide, and count tiles, and so
+/// require [RatioScalar].  Since a [Style] picks its scaling at runtime, [Layout::each_dst_src] and friends require
+/// [RatioScalar] too.
+/// 
+/// Your own numeric newtypes (e.g. a strongly typed `Px(i32)`) can implement this too:  implement [RatioScalar]'s
+/// supertraits (`Copy`, `Add`, `Sub`, `Mul`, and `Div` with `Output = Self`, `Debug`, `Default`, and `PartialOrd`)
+/// yourself, then delegate the rest to the wrapped scalar with [impl_scalar!].  Types that can't be multiplied or
+/// divided can implement just this trait by hand.
+/// 
+/// Conversions to and from `f64` - and so [mul_f64] - are exact for [iNN]/[uNN] magnitudes up to 2⁵³.  Larger
+/// `i64`, `u64`, `i128`, etc. values lose precision.
+/// 
+/// [mul_f64]:      #method.mul_f64
+/// [impl_scalar!]: macro.impl_scalar.html
+/// [Rect]:         struct.Rect.html
+/// [Dimensions]:   struct.Dimensions.html
+/// [ValidDimensions::with_outer]:  struct.ValidDimensions.html#method.with_outer
+/// [Layout]:                       struct.Layout.html
+/// [Layout::to_array]:             struct.Layout.html#method.to_array
+/// [Layout::each_dst_src]:         struct.Layout.html#method.each_dst_src
+/// [RatioScalar]:  trait.RatioScalar.html
+/// [Scale]:        enum.Scale.html
+/// [Scale::Repeat]:    enum.Scale.html#variant.Repeat
+/// [Scale::Round]:     enum.Scale.html#variant.Round
+/// [Scale::Space]:     enum.Scale.html#variant.Space
+/// [Style]:        struct.Style.html
 /// [iNN]:      https://doc.rust-lang.org/std/primitive.i32.html
 /// [uNN]:      https://doc.rust-lang.org/std/primitive.u32.html
 /// [fNN]:      https://doc.rust-lang.org/std/primitive.f32.html
-pub trait Scalar : Copy + Add<Output = Self> + Sub<Output = Self> + Debug + Default + PartialOrd {}
+pub trait Scalar : Copy + Add<Output = Self> + Sub<Output = Self> + Debug + Default + PartialOrd {
+    /// Multiply by a (e.g. DPI) scale factor, via `f64`.  Integer results are rounded to a whole number per `rounding`,
+    /// and saturate at the limits of their type.  Floating point types ignore `rounding`.
+    fn mul_f64(self, factor: f64, rounding: Rounding) -> Self { Self::from_f64(self.to_f64() * factor, rounding) }
+
+    /// Convert into an `f64`, e.g. to convert between scalar types.  Integers beyond ±2⁵³ lose precision.
+    fn to_f64(self) -> f64;
+
+    /// Convert from an `f64`, rounding to a whole number per `rounding` and saturating at the limits of integer types.
+    /// Floating point types ignore `rounding`.
+    fn from_f64(value: f64, rounding: Rounding) -> Self;
+
+    /// `self + rhs`, or `None` if that would overflow.  Floating point arithmetic never overflows (it goes infinite instead.)
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// `self - rhs`, or `None` if that would overflow.  Floating point arithmetic never overflows (it goes infinite instead.)
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// `self - rhs`, clamped to the limits of integer types instead of overflowing - e.g. to `0` for unsigned scalars.
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+/// A [Scalar] that can also be multiplied, divided, and converted to and from tile counts - as needed by the tiling
+/// [Scale]s ([Scale::Repeat], [Scale::Round], [Scale::Space], etc.)
+/// 
+/// [Scalar]:           trait.Scalar.html
+/// [Scale]:            enum.Scale.html
+/// [Scale::Repeat]:    enum.Scale.html#variant.Repeat
+/// [Scale::Round]:     enum.Scale.html#variant.Round
+/// [Scale::Space]:     enum.Scale.html#variant.Space
+pub trait RatioScalar : Scalar + Mul<Output = Self> + Div<Output = Self> {
+    /// Convert a tile count into a scalar.  Defaults to converting via `f64`.
+    fn from_count(count: usize) -> Self { Self::from_f64(count as f64, Rounding::Floor) }
+
+    /// Convert a non-negative scalar into a tile count, rounding towards zero.  Defaults to converting via `f64`.
+    fn to_count(self) -> usize { self.to_f64() as usize }
+
+    /// `self * rhs`, or `None` if that would overflow.  Floating point arithmetic never overflows (it goes infinite instead.)
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
 //impl<S: Copy + Add<Output = S> + Sub<Output = S> + Debug + Default + PartialOrd> Scalar for S {}
 
-impl Scalar for i8    {}
-impl Scalar for i16   {}
-impl Scalar for i32   {}
-impl Scalar for i64   {}
-impl Scalar for i128  {}
-impl Scalar for isize {}
+macro_rules! scalars {
+    ( $kind:ident: $($ty:ty),* $(,)? ) => {$(
+        impl Scalar for $ty {
+            fn to_f64(self) -> f64 { self as f64 }
+            fn from_f64(value: f64, rounding: Rounding) -> Self { scalars!(@from_f64 $kind, value, rounding) as Self }
+            fn checked_add(self, rhs: Self) -> Option<Self> { scalars!(@checked $kind $ty, checked_add, self + rhs) }
+            fn checked_sub(self, rhs: Self) -> Option<Self> { scalars!(@checked $kind $ty, checked_sub, self - rhs) }
+            fn saturating_sub(self, rhs: Self) -> Self { scalars!(@saturating $kind $ty, saturating_sub, self - rhs) }
+        }
+
+        impl RatioScalar for $ty {
+            fn from_count(count: usize) -> Self { count as Self }
+            fn to_count(self) -> usize { self as usize }
+            fn checked_mul(self, rhs: Self) -> Option<Self> { scalars!(@checked $kind $ty, checked_mul, self * rhs) }
+        }
+    )*};
+    ( @checked integer $ty:ty, $method:ident, $a:ident $op:tt $b:ident ) => { <$ty>::$method($a, $b) };
+    ( @checked float   $ty:ty, $method:ident, $a:ident $op:tt $b:ident ) => { Some($a $op $b) };
+    ( @from_f64 integer, $value:ident, $rounding:ident ) => { round($value, $rounding) };
+    ( @from_f64 float,   $value:ident, $rounding:ident ) => { { let _ = $rounding; $value } };
+    ( @saturating integer $ty:ty, $method:ident, $a:ident $op:tt $b:ident ) => { <$ty>::$method($a, $b) };
+    ( @saturating float   $ty:ty, $method:ident, $a:ident $op:tt $b:ident ) => { $a $op $b };
+}
+
+scalars!(integer: i8, i16, i32, i64, i128, isize);
 
 // XXX: These are trivial to underflow in UI layout, so I've chosen to discourage these.
-#[cfg(feature = "unsigned-scalar")] impl Scalar for u8    {}
-#[cfg(feature = "unsigned-scalar")] impl Scalar for u16   {}
-#[cfg(feature = "unsigned-scalar")] impl Scalar for u32   {}
-#[cfg(feature = "unsigned-scalar")] impl Scalar for u64   {}
-#[cfg(feature = "unsigned-scalar")] impl Scalar for u128  {}
-#[cfg(feature = "unsigned-scalar")] impl Scalar for usize {}
-
-impl Scalar for f32   {}
-impl Scalar for f64   {}
+#[cfg(feature = "unsigned-scalar")] scalars!(integer: u8, u16, u32, u64, u128, usize);
+
+scalars!(float: f32, f64);
+
+/// Implement [Scalar] and [RatioScalar] for single-field tuple newtypes, by delegating to the wrapped [RatioScalar].
+/// 
+/// The newtype must already implement [RatioScalar]'s supertraits.
+/// 
+/// ```rust
+/// use nines::*;
+/// use core::ops::{Add, Sub, Mul, Div};
+/// 
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)] struct Px(i32);
+/// impl Add for Px { type Output = Px; fn add(self, rhs: Px) -> Px { Px(self.0 + rhs.0) } }
+/// impl Sub for Px { type Output = Px; fn sub(self, rhs: Px) -> Px { Px(self.0 - rhs.0) } }
+/// impl Mul for Px { type Output = Px; fn mul(self, rhs: Px) -> Px { Px(self.0 * rhs.0) } }
+/// impl Div for Px { type Output = Px; fn div(self, rhs: Px) -> Px { Px(self.0 / rhs.0) } }
+/// impl_scalar!(Px(i32));
+/// 
+/// let src = Dimensions { outer: Rect::xywh(Px(0), Px(0), Px(3), Px(3)), inner: Rect::xywh(Px(1), Px(1), Px(1), Px(1)) }.validate().unwrap();
+/// let dst = src.with_outer(Rect::xywh(Px(0), Px(0), Px(10), Px(10)).validate().unwrap()).unwrap();
+/// assert_eq!(dst.inner(), Rect::xywh(Px(1), Px(1), Px(8), Px(8)));
+/// ```
+/// 
+/// [Scalar]:       trait.Scalar.html
+/// [RatioScalar]:  trait.RatioScalar.html
+#[macro_export]
+macro_rules! impl_scalar {
+    ( $( $newtype:ident ( $inner:ty ) ),* $(,)? ) => {$(
+        impl $crate::Scalar for $newtype {
+            fn to_f64(self) -> f64 { <$inner as $crate::Scalar>::to_f64(self.0) }
+            fn from_f64(value: f64, rounding: $crate::Rounding) -> Self { $newtype(<$inner as $crate::Scalar>::from_f64(value, rounding)) }
+            fn checked_add(self, rhs: Self) -> ::core::option::Option<Self> { <$inner as $crate::Scalar>::checked_add(self.0, rhs.0).map($newtype) }
+            fn checked_sub(self, rhs: Self) -> ::core::option::Option<Self> { <$inner as $crate::Scalar>::checked_sub(self.0, rhs.0).map($newtype) }
+            fn saturating_sub(self, rhs: Self) -> Self { $newtype(<$inner as $crate::Scalar>::saturating_sub(self.0, rhs.0)) }
+        }
+
+        impl $crate::RatioScalar for $newtype {
+            fn from_count(count: usize) -> Self { $newtype(<$inner as $crate::RatioScalar>::from_count(count)) }
+            fn to_count(self) -> usize { <$inner as $crate::RatioScalar>::to_count(self.0) }
+            fn checked_mul(self, rhs: Self) -> ::core::option::Option<Self> { <$inner as $crate::RatioScalar>::checked_mul(self.0, rhs.0).map($newtype) }
+        }
+    )*};
+}
+
+/// Convert a tile count into a scalar, or `None` if it doesn't fit.
+pub(crate) fn checked_count<S: RatioScalar>(count: usize) -> Option<S> {
+    let s = S::from_count(count);
+    if s.to_count() == count { Some(s) } else { None }
+}
+
+
+
+/// How to round fractional results to whole numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round to the nearest whole number, with halfway cases rounded away from zero (like [f64::round].)
+    /// 
+    /// [f64::round]:   https://doc.rust-lang.org/std/primitive.f64.html#method.round
+    Nearest,
+
+    /// Round to the nearest whole number, with halfway cases rounded to the nearest even number ("banker's rounding".)
+    NearestEven,
+}
+
+impl core::default::Default for Rounding { fn default() -> Self { Rounding::Nearest } }
+
+/// `f64::{floor, ceil, round}` aren't available in `core`.
+pub(crate) fn round(value: f64, rounding: Rounding) -> f64 {
+    const WHOLE : f64 = 4503599627370496.0; // 2⁵²:  every f64 of this magnitude or larger is already a whole number
+    if !(-WHOLE < value && value < WHOLE) { return value; } // also passes through NaN
+    let trunc = value as i64 as f64;
+    let odd = value as i64 & 1 != 0;
+    match rounding {
+        Rounding::Floor         => if value < trunc { trunc - 1.0 } else { trunc },
+        Rounding::Ceil          => if trunc < value { trunc + 1.0 } else { trunc },
+        Rounding::Nearest       => if 0.5 <= value - trunc { trunc + 1.0 } else if value - trunc <= -0.5 { trunc - 1.0 } else { trunc },
+        Rounding::NearestEven   => match value - trunc {
+            diff if 0.5 < diff || (diff == 0.5 && odd)      => trunc + 1.0,
+            diff if diff < -0.5 || (diff == -0.5 && odd)    => trunc - 1.0,
+            _                                               => trunc,
+        },
+    }
+}
+
+/// Round `value` to a whole number per `rounding`.  Integer scalars are already whole, and are returned as-is instead
+/// of making a (lossy, beyond 2⁵³) round trip through `f64`.
+pub(crate) fn round_scalar<S: Scalar>(value: S, rounding: Rounding) -> S {
+    if S::from_f64(0.5, Rounding::Floor) == S::default() { return value; } // integer
+    S::from_f64(round(value.to_f64(), rounding), rounding)
+}
+
+/// `num / den` for non-negative `num` and positive `den`, with integer results rounded per `rounding`.
+/// Floating point results are returned as-is.
+pub(crate) fn div_rounded<S: RatioScalar>(num: S, den: S, rounding: Rounding) -> S {
+    let one = S::from_count(1);
+    let quotient = num / den;
+    let remainder = num - quotient * den;
+    if !(one <= remainder) { return quotient; } // exact, or floating point
+    let up = match rounding {
+        Rounding::Floor         => false,
+        Rounding::Ceil          => true,
+        Rounding::Nearest       => den <= remainder + remainder,
+        Rounding::NearestEven   => den < remainder + remainder || (den == remainder + remainder && quotient.to_count() & 1 != 0),
+    };
+    if up { quotient + one } else { quotient }
+}
+
+
+
+#[test] fn scalar_mul_f64_test() {
+    assert_eq!(3_i32.mul_f64(1.5, Rounding::Floor),   4);
+    assert_eq!(3_i32.mul_f64(1.5, Rounding::Ceil),    5);
+    assert_eq!(3_i32.mul_f64(1.5, Rounding::Nearest), 5);
+    assert_eq!((-3_i32).mul_f64(1.5, Rounding::Floor),   -5);
+    assert_eq!((-3_i32).mul_f64(1.5, Rounding::Ceil),    -4);
+    assert_eq!((-3_i32).mul_f64(1.5, Rounding::Nearest), -5);
+    assert_eq!(100_i8.mul_f64(2.0, Rounding::Nearest), i8::MAX);
+
+    assert_eq!(1.25_f32.mul_f64(2.0, Rounding::Floor), 2.5);
+    assert_eq!(1.25_f32.mul_f64(2.0, Rounding::Ceil),  2.5);
+    assert_eq!(1e300_f64.mul_f64(2.0, Rounding::Floor), 2e300);
+    assert!(f32::NAN.mul_f64(2.0, Rounding::Nearest).is_nan());
+
+    assert_eq!(5_i32.mul_f64(0.5, Rounding::NearestEven), 2);
+    assert_eq!(7_i32.mul_f64(0.5, Rounding::NearestEven), 4);
+    assert_eq!((-5_i32).mul_f64(0.5, Rounding::NearestEven), -2);
+    assert_eq!(2.75_f64.mul_f64(2.0, Rounding::NearestEven), 5.5);
+}
+
+#[test] fn scalar_round_scalar_test() {
+    assert_eq!(round_scalar(2.5_f32, Rounding::Floor), 2.0);
+    assert_eq!(round_scalar(2.5_f64, Rounding::NearestEven), 2.0);
+    assert_eq!(round_scalar(-2.5_f64, Rounding::Nearest), -3.0);
+    assert_eq!(round_scalar(3_i32, Rounding::Ceil), 3);
+    assert_eq!(round_scalar(i64::MAX, Rounding::Nearest), i64::MAX); // no lossy f64 round trip
+    assert_eq!(i64::MAX.mul_f64(1.0, Rounding::Nearest), i64::MAX);  // saturates instead of wrapping
+}
+
+#[test] fn scalar_div_rounded_test() {
+    let all = |num: i32, den: i32| [Rounding::Floor, Rounding::Ceil, Rounding::Nearest, Rounding::NearestEven].map(|r| div_rounded(num, den, r));
+    assert_eq!(all(10, 5), [2, 2, 2, 2]);
+    assert_eq!(all(11, 5), [2, 3, 2, 2]);
+    assert_eq!(all(13, 5), [2, 3, 3, 3]);
+    assert_eq!(all( 5, 2), [2, 3, 3, 2]);
+    assert_eq!(all( 7, 2), [3, 4, 4, 4]);
+    assert_eq!(div_rounded(7.0, 2.0, Rounding::Floor), 3.5);
+}
+
+#[test] fn scalar_checked_test() {
+    assert_eq!(Scalar::checked_add(100_i8, 27), Some(127));
+    assert_eq!(Scalar::checked_add(100_i8, 28), None);
+    assert_eq!(Scalar::checked_sub(-100_i8, 29), None);
+    assert_eq!(RatioScalar::checked_mul(16_i8, 8), None);
+    assert_eq!(Scalar::checked_add(f32::MAX, f32::MAX), Some(f32::INFINITY));
+    assert_eq!(checked_count::<i8>(127), Some(127));
+    assert_eq!(checked_count::<i8>(128), None);
+    assert_eq!(checked_count::<f32>(128), Some(128.0));
+}
+
+#[test] fn scalar_f64_test() {
+    assert_eq!(i32::from_f64(2.5, Rounding::Floor), 2);
+    assert_eq!(i32::from_f64(2.5, Rounding::NearestEven), 2);
+    assert_eq!(i8::from_f64(1000.0, Rounding::Nearest), i8::MAX);
+    assert_eq!(f32::from_f64(2.5, Rounding::Floor), 2.5);
+    assert_eq!(Scalar::to_f64(-3_i32), -3.0);
+}
+
+#[test] fn scalar_saturating_sub_test() {
+    assert_eq!(Scalar::saturating_sub(-100_i8, 29), i8::MIN);
+    assert_eq!(Scalar::saturating_sub(3_i32, 5), -2);
+    assert_eq!(Scalar::saturating_sub(3.0_f32, 5.0), -2.0);
+    #[cfg(feature = "unsigned-scalar")] assert_eq!(Scalar::saturating_sub(3_u32, 5), 0);
+}
+
+#[test] fn scalar_minimal_test() {
+    use crate::{Dimensions, Layout, Rect, Style};
+    use core::ops::{Add, Sub};
+
+    // No Mul or Div:  only usable for stretching
+    #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)] struct Px(i32);
+    impl Add for Px { type Output = Px; fn add(self, rhs: Px) -> Px { Px(self.0 + rhs.0) } }
+    impl Sub for Px { type Output = Px; fn sub(self, rhs: Px) -> Px { Px(self.0 - rhs.0) } }
+    impl Scalar for Px {
+        fn to_f64(self) -> f64 { self.0.to_f64() }
+        fn from_f64(value: f64, rounding: Rounding) -> Self { Px(i32::from_f64(value, rounding)) }
+        fn checked_add(self, rhs: Self) -> Option<Self> { Scalar::checked_add(self.0, rhs.0).map(Px) }
+        fn checked_sub(self, rhs: Self) -> Option<Self> { Scalar::checked_sub(self.0, rhs.0).map(Px) }
+        fn saturating_sub(self, rhs: Self) -> Self { Px(Scalar::saturating_sub(self.0, rhs.0)) }
+    }
+
+    let src = Dimensions { outer: Rect::xywh(Px(0), Px(0), Px(3), Px(3)), inner: Rect::xywh(Px(1), Px(1), Px(1), Px(1)) }.validate().unwrap();
+    let layout = Layout { dst: src.with_outer(Rect::xywh(Px(0), Px(0), Px(10), Px(10)).validate().unwrap()).unwrap(), src, style: Style::default() };
+    let [_, _, _, _, (dst, src), ..] = layout.to_array();
+    assert_eq!(dst, Rect::xywh(Px(1), Px(1), Px(8), Px(8)));
+    assert_eq!(src, Rect::xywh(Px(1), Px(1), Px(1), Px(1)));
+}
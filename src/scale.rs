@@ -1,25 +1,603 @@
+use super::*;
+use crate::scalar::{checked_count, div_rounded};
+use core::ops::Range;
+
 /// How to scale images.  Based roughly off of the CSS3 [border-image-repeat] property values.
 /// 
 /// [border-image-repeat]:  https://www.w3.org/TR/css-backgrounds-3/#the-border-image-repeat
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scale {
     /// The border image is used exactly once, scaled as far up/down as necessary.
     Stretch,
 
-    /// The border image is used `floor(image_size / element_edge_size)` times.
-    /// Additionally, a fractional image will be inserted in the middle.
-    Repeat,
+    /// The border image is used `floor(element_edge_size / image_size)` times, unscaled.
+    /// Additionally, fractional image(s) will be inserted where specified by [Partial].
+    /// 
+    /// [Partial]:          enum.Partial.html
+    Repeat(Partial),
+
+    /// Like [Repeat](#variant.Repeat), but every other image is mirrored, so seamless borders can be made from non-tileable art.
+    /// Mirrored images are flagged via [Tile::mirror].
+    /// 
+    /// [Tile::mirror]:     struct.Tile.html#structfield.mirror
+    MirrorRepeat(Partial),
 
-    /// The border image is used `max(1,round(image_size / element_edge_size))` times.
+    /// The border image is used `max(1,round(element_edge_size / image_size))` times, scaled to exactly fill the element edge.
+    /// If the image has no size, nothing is emitted.
     Round,
 
-    /// The border image is used `floor(image_size / element_edge_size)` times.
-    /// Gaps in the border are left, so this really only makes sense for dashed borders.
-    Space,
+    /// The border image is used `floor(element_edge_size / image_size)` times, unscaled.
+    /// The leftover space is split into gaps as specified by [Justify], so this really only makes sense for dashed borders.
+    /// If not even a single image fits, nothing is emitted.
+    /// 
+    /// [Justify]:          enum.Justify.html
+    Space(Justify),
+
+    /// The border image is used exactly once, unscaled, and aligned within the element edge.
+    /// If the image is larger than the element edge, it will extend past it.
+    Fixed(Align),
+
+    /// The border image is used at most once, unscaled, and aligned within the element edge - like [Fixed](#variant.Fixed).
+    /// If the image is larger than the element edge, it's cropped to fit, with the src span reduced to match.
+    Crop(Align),
+}
+
+/// Where to place the fractional image(s) of [Scale::Repeat].
+/// 
+/// Fractional images use whichever end of the source image keeps the seam with their whole neighbor(s) intact.
+/// 
+/// [Scale::Repeat]:    enum.Scale.html#variant.Repeat
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Partial {
+    /// A single fractional image before all the whole images.
+    Start,
+
+    /// A single fractional image in the middle of the whole images.
+    Center,
+
+    /// A single fractional image after all the whole images.
+    End,
+
+    /// Two fractional images of (roughly) equal size, one at each end.  This matches CSS's `repeat`.
+    Split,
+}
+
+impl core::default::Default for Partial { fn default() -> Self { Partial::Center } }
+
+/// How to distribute the leftover space of [Scale::Space].  Named after the equivalent CSS `justify-content` values.
+/// 
+/// [Scale::Space]:     enum.Scale.html#variant.Space
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Justify {
+    /// `space-between`:  Gaps only between images, none at the ends.  A lone image is placed at the start.
+    Between,
+
+    /// `space-around`:  Equal space around each image, so the gaps at the ends are half the size of those between images.
+    Around,
+
+    /// `space-evenly`:  Equal gaps between images and at both ends.
+    Evenly,
+}
+
+impl core::default::Default for Justify { fn default() -> Self { Justify::Evenly } }
+
+/// How to distribute integer division leftovers when splitting a length into several parts - the tiles of [Scale::Round],
+/// the gaps of [Scale::Space], or the two fractional images of [Partial::Split].
+/// 
+/// Whichever policy is used, the parts always exactly cover the whole length - only their sizes vary, by at most 1.
+/// Floating point scalars have no leftovers, so they're laid out identically under every policy.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// // 4 tiles across 14:  3 each, 2 leftover
+/// let lens = |remainder| Scale::Round.spans_with(0..14, 0..4, remainder).map(|span| span.dst.end - span.dst.start).collect::<Vec<_>>();
+/// assert_eq!(lens(Remainder::Spread(Rounding::Floor)),    vec![3, 4, 3, 4]);
+/// assert_eq!(lens(Remainder::Spread(Rounding::Nearest)),  vec![4, 3, 4, 3]);
+/// assert_eq!(lens(Remainder::Start),                      vec![4, 4, 3, 3]);
+/// assert_eq!(lens(Remainder::End),                        vec![3, 3, 4, 4]);
+/// ```
+/// 
+/// [Scale::Round]:     enum.Scale.html#variant.Round
+/// [Scale::Space]:     enum.Scale.html#variant.Space
+/// [Partial::Split]:   enum.Partial.html#variant.Split
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Remainder {
+    /// Spread leftovers evenly between the parts (error diffusion), by rounding each boundary from its exact position.
+    /// [Rounding::Floor] matches CSS and most engines.
+    /// 
+    /// [Rounding::Floor]:  enum.Rounding.html#variant.Floor
+    Spread(Rounding),
+
+    /// Give leftovers to the first parts.
+    Start,
+
+    /// Give leftovers to the last parts.
+    End,
+}
+
+impl core::default::Default for Remainder { fn default() -> Self { Remainder::Spread(Rounding::Floor) } }
+
+/// How to align an unscaled image within a larger (or smaller) span.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Align {
+    /// Align to the left/top.
+    Start,
+
+    /// Center within the span.
+    Center,
+
+    /// Align to the right/bottom.
+    End,
 }
 
-impl std::default::Default for Scale { fn default() -> Self { Scale::Stretch } }
+impl core::default::Default for Scale { fn default() -> Self { Scale::Stretch } }
 
 impl Scale {
-    // ...
+    /// Enumerate the `(dst, src)` spans used to tile `src` across `dst` along a single axis.
+    /// 
+    /// This is the same math used by [Layout] for each axis of each cell, exposed for 1D use cases such as scrollbar tracks or progress bars.
+    /// 
+    /// ### Example
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let spans = Scale::Round.spans(0..10, 0..3).map(|span| span.dst).collect::<Vec<_>>();
+    /// assert_eq!(spans, vec![0..3, 3..6, 6..10]);
+    /// ```
+    /// 
+    /// [Layout]:   struct.Layout.html
+    pub fn spans<S: RatioScalar>(self, dst: Range<S>, src: Range<S>) -> Spans<S> {
+        self.spans_with(dst, src, Remainder::default())
+    }
+
+    /// Enumerate the `(dst, src)` spans used to tile `src` across `dst` along a single axis, distributing integer
+    /// leftovers per `remainder`.  See [Remainder].
+    /// 
+    /// [Remainder]:    enum.Remainder.html
+    pub fn spans_with<S: RatioScalar>(self, dst: Range<S>, src: Range<S>, remainder: Remainder) -> Spans<S> {
+        let dst_len = dst.end - dst.start;
+        let src_len = src.end - src.start;
+        let zero = S::default();
+        let whole = if src_len > zero { (dst_len / src_len).to_count() } else { 0 };
+        let partial = if src_len > zero { dst_len - S::from_count(whole) * src_len } else { zero };
+        let (head, tail) = match self {
+            Scale::Repeat(p) | Scale::MirrorRepeat(p) => split_partial(p, partial, remainder),
+            _ => (zero, zero),
+        };
+        let count = match self {
+            Scale::Stretch      => 1,
+            Scale::Repeat(_) | Scale::MirrorRepeat(_) => whole + if head > zero { 1 } else { 0 } + if tail > zero { 1 } else { 0 },
+            Scale::Round        => if src_len > zero { core::cmp::max(1, ((dst_len + dst_len + src_len) / (src_len + src_len)).to_count()) } else { 0 },
+            Scale::Space(_)     => whole,
+            Scale::Fixed(_)     => 1,
+            Scale::Crop(_)      => 1,
+        };
+        Spans { scale: self, remainder, saturating: false, dst_start: dst.start, dst_len, src_start: src.start, src_len, whole, head, tail, count, next: 0 }
+    }
+
+    /// How many tiles [spans](#method.spans) would emit to tile `src_len` across `dst_len`, e.g. to preallocate vertex buffers.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Scale::Stretch                   .tile_count(10, 3), 1);
+    /// assert_eq!(Scale::Repeat(Partial::Split)    .tile_count(10, 3), 4);
+    /// assert_eq!(Scale::Round                     .tile_count(10, 3), 3);
+    /// assert_eq!(Scale::Space(Justify::Evenly)    .tile_count(10, 3), 3);
+    /// ```
+    pub fn tile_count<S: RatioScalar>(self, dst_len: S, src_len: S) -> usize {
+        self.spans(S::default()..dst_len, S::default()..src_len).len()
+    }
+
+    /// The length of a whole (non-fractional, uncropped) dst tile when tiling `src_len` across `dst_len`.
+    /// 
+    /// For integer [Round](#variant.Round)ing, individual tiles may be up to 1 longer to exactly cover `dst_len`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Scale::Stretch                   .tile_len(10.0, 3.0), 10.0);
+    /// assert_eq!(Scale::Round                     .tile_len(11.0, 3.0), 2.75);
+    /// assert_eq!(Scale::Space(Justify::Evenly)    .tile_len(11.0, 3.0), 3.0);
+    /// ```
+    pub fn tile_len<S: RatioScalar>(self, dst_len: S, src_len: S) -> S {
+        match self {
+            Scale::Stretch  => dst_len,
+            Scale::Round    => match self.tile_count(dst_len, src_len) {
+                0 => S::default(),
+                n => dst_len / S::from_count(n),
+            },
+            _               => src_len,
+        }
+    }
+
+    /// The length of the gap between [Space](#variant.Space)d tiles when tiling `src_len` across `dst_len`.  Zero for all other scaling modes.
+    /// 
+    /// For integer scalars, individual gaps may be up to 1 longer to exactly cover `dst_len`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// assert_eq!(Scale::Space(Justify::Between)   .gap_len(11.0, 3.0), 1.0);
+    /// assert_eq!(Scale::Space(Justify::Around)    .gap_len(11.0, 3.0), 2.0/3.0);
+    /// assert_eq!(Scale::Space(Justify::Evenly)    .gap_len(11.0, 3.0), 0.5);
+    /// assert_eq!(Scale::Round                     .gap_len(11.0, 3.0), 0.0);
+    /// ```
+    pub fn gap_len<S: RatioScalar>(self, dst_len: S, src_len: S) -> S {
+        let zero = S::default();
+        let justify = match self { Scale::Space(justify) => justify, _ => return zero };
+        let n = self.tile_count(dst_len, src_len);
+        let leftover = dst_len - S::from_count(n) * src_len;
+        match justify {
+            _ if n == 0         => zero,
+            Justify::Between    => if n == 1 { zero } else { leftover / S::from_count(n-1) },
+            Justify::Around     => leftover / S::from_count(n),
+            Justify::Evenly     => leftover / S::from_count(n+1),
+        }
+    }
+
+    /// Check that [spans_with](#method.spans_with) can tile `src_len` across `dst_start .. dst_start + dst_len`
+    /// (made [saturating](struct.Spans.html#method.saturating) per `saturating`) without any intermediate result
+    /// overflowing `S`.  Conservative:  assumes the worst case [Remainder].
+    pub(crate) fn check_overflow<S: RatioScalar>(self, dst_start: S, dst_len: S, src_len: S, saturating: bool) -> Result<(), Error> {
+        let zero = S::default();
+        let ok = match self {
+            Scale::Stretch => true,
+            Scale::Repeat(_) | Scale::MirrorRepeat(_) => !(zero < src_len) || (dst_len / src_len).to_count().checked_add(2).is_some(), // whole + head + tail
+            Scale::Round => !(zero < src_len) || (
+                dst_len.checked_add(dst_len).and_then(|d| d.checked_add(src_len)).is_some() &&
+                src_len.checked_add(src_len).is_some() &&
+                checked_count(self.tile_count(dst_len, src_len)).and_then(|n| dst_len.checked_mul(n)).is_some()
+            ),
+            Scale::Space(_) => match self.tile_count(dst_len, src_len) {
+                0 => true,
+                n => n.checked_mul(2).and_then(|m| m.checked_add(2)).and_then(checked_count).and_then(|m| (dst_len - S::from_count(n) * src_len).checked_mul(m)).is_some(),
+            },
+            Scale::Fixed(_) | Scale::Crop(_) => {
+                let dst_end = dst_start + dst_len;
+                let underflows = dst_len.checked_sub(src_len).is_none() || dst_end.checked_sub(src_len).is_none();
+                (saturating || !underflows) && dst_start.checked_add(src_len).is_some()
+            },
+        };
+        if ok { Ok(()) } else { err("Expected scaling not to overflow") }
+    }
+}
+
+/// Split `partial` into the fractional image sizes before and after the whole images.
+/// [Partial::Center] is treated as coming after the whole images - it's relocated by [Spans::span].
+fn split_partial<S: RatioScalar>(p: Partial, partial: S, remainder: Remainder) -> (S, S) {
+    let zero = S::default();
+    match p {
+        Partial::Start  => (partial, zero),
+        Partial::Center => (zero, partial),
+        Partial::End    => (zero, partial),
+        Partial::Split  => {
+            let head = share(partial, 1, 2, remainder);
+            (head, partial - head)
+        },
+    }
+}
+
+/// The first `k` of `m` equal parts of `total`, with integer leftovers distributed per `remainder`.
+fn share<S: RatioScalar>(total: S, k: usize, m: usize, remainder: Remainder) -> S {
+    if k >= m { return total; }
+    let (k, m) = (S::from_count(k), S::from_count(m));
+    let base = total / m;
+    let leftover = total - base * m;
+    if !(S::from_count(1) <= leftover) { return total * k / m; } // exact, or floating point
+    match remainder {
+        // Multiplying before dividing keeps integer remainders from accumulating.
+        Remainder::Spread(rounding) => div_rounded(total * k, m, rounding),
+        Remainder::Start            => base * k + if k < leftover { k } else { leftover },
+        Remainder::End              => base * k + if m - leftover < k { k - (m - leftover) } else { S::default() },
+    }
+}
+
+/// A single `(dst, src)` span along one axis.  See [Scale::spans].
+/// 
+/// [Scale::spans]:     enum.Scale.html#method.spans
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span<S: RatioScalar> {
+    /// Where to draw.
+    pub dst:    Range<S>,
+
+    /// What to draw.
+    pub src:    Range<S>,
+
+    /// If `src` should be flipped when drawn into `dst`.
+    pub mirror: bool,
+}
+
+impl<S: RatioScalar> Span<S> {
+    /// Check if `dst` has no length, e.g. for a zero-size border.
+    #[must_use] pub fn is_empty(&self) -> bool { !(self.dst.start < self.dst.end) }
+}
+
+/// An iterator over the [Span]s of a single axis.  See [Scale::spans].
+/// 
+/// [Span]:             struct.Span.html
+/// [Scale::spans]:     enum.Scale.html#method.spans
+#[derive(Clone, Debug)]
+pub struct Spans<S: RatioScalar> {
+    scale:      Scale,
+    remainder:  Remainder,
+    saturating: bool,
+    dst_start:  S,
+    dst_len:    S,
+    src_start:  S,
+    src_len:    S,
+    whole:      usize,
+    head:       S,
+    tail:       S,
+    count:      usize,
+    next:       usize,
+}
+
+impl<S: RatioScalar> Spans<S> {
+    /// If `saturating`, subtractions that would underflow clamp instead of panicking - e.g. a [Scale::Fixed] source
+    /// longer than its `u32` destination is aligned to the start of the destination, instead of underflowing.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// # #[cfg(feature = "unsigned-scalar")] {
+    /// let spans = Scale::Fixed(Align::Center).spans(10..12_u32, 0..6).saturating(true).map(|span| span.dst).collect::<Vec<_>>();
+    /// assert_eq!(spans, vec![10..16]);
+    /// # }
+    /// ```
+    /// 
+    /// [Scale::Fixed]: enum.Scale.html#variant.Fixed
+    #[must_use] pub fn saturating(self, saturating: bool) -> Self { Self { saturating, ..self } }
+
+    /// These (unstarted) spans, moved to start at `dst.start` - or `None` if `dst` has a different length, and would
+    /// need to be laid out anew.
+    pub(crate) fn rebased(&self, dst: Range<S>) -> Option<Self> {
+        if dst.end - dst.start == self.dst_len { Some(Self { dst_start: dst.start, ..self.clone() }) } else { None }
+    }
+
+    fn aligned_start(&self, align: Align) -> S {
+        let slack = if self.saturating { self.dst_len.saturating_sub(self.src_len) } else { self.dst_len - self.src_len };
+        self.dst_start + match align {
+            Align::Start    => S::default(),
+            Align::Center   => slack / S::from_count(2),
+            Align::End      => slack,
+        }
+    }
+
+    fn span(&self, i: usize) -> Span<S> {
+        let src = self.src_start .. self.src_start + self.src_len;
+        match self.scale {
+            Scale::Stretch => Span { dst: self.dst_start .. self.dst_start + self.dst_len, src, mirror: false },
+            Scale::Repeat(p) | Scale::MirrorRepeat(p) => {
+                let mirror  = if let Scale::MirrorRepeat(_) = self.scale { i % 2 == 1 } else { false };
+                let zero    = S::default();
+                let has_head= self.head > zero;
+                let has_tail= self.tail > zero;
+
+                // Where the trailing fractional image gets inserted amongst the whole images.
+                let tail_at = if p == Partial::Center { self.whole / 2 } else { self.whole } + if has_head { 1 } else { 0 };
+
+                if has_head && i == 0 {
+                    // Seam is on the right:  use the end of src, unless mirrored (never, as i == 0)
+                    Span { dst: self.dst_start .. self.dst_start + self.head, src: src.end - self.head .. src.end, mirror }
+                } else if has_tail && i == tail_at {
+                    // Seam is on the left:  use the start of src, unless mirrored
+                    let start = self.dst_start + self.head + S::from_count(i - if has_head { 1 } else { 0 }) * self.src_len;
+                    let src = if mirror { src.end - self.tail .. src.end } else { src.start .. src.start + self.tail };
+                    Span { dst: start .. start + self.tail, src, mirror }
+                } else {
+                    let whole_before = i - if has_head { 1 } else { 0 } - if has_tail && i > tail_at { 1 } else { 0 };
+                    let tail_before = if has_tail && i > tail_at { self.tail } else { zero };
+                    let start = self.dst_start + self.head + tail_before + S::from_count(whole_before) * self.src_len;
+                    Span { dst: start .. start + self.src_len, src, mirror }
+                }
+            },
+            Scale::Round => {
+                let n       = self.count;
+                let start   = self.dst_start + share(self.dst_len, i,   n, self.remainder);
+                let end     = self.dst_start + share(self.dst_len, i+1, n, self.remainder);
+                Span { dst: start .. end, src, mirror: false }
+            },
+            Scale::Space(justify) => {
+                let n       = self.count;
+                let leftover= self.dst_len - S::from_count(n) * self.src_len;
+                let gaps    = match justify {
+                    Justify::Between if n == 1  => S::default(),
+                    Justify::Between            => share(leftover, i,     n-1, self.remainder),
+                    Justify::Around             => share(leftover, 2*i+1, 2*n, self.remainder),
+                    Justify::Evenly             => share(leftover, i+1,   n+1, self.remainder),
+                };
+                let start   = self.dst_start + S::from_count(i) * self.src_len + gaps;
+                Span { dst: start .. start + self.src_len, src, mirror: false }
+            },
+            Scale::Fixed(align) => {
+                let start = self.aligned_start(align);
+                Span { dst: start .. start + self.src_len, src, mirror: false }
+            },
+            Scale::Crop(align) => {
+                let start   = self.aligned_start(align);
+                let end     = start + self.src_len;
+                let dst_end = self.dst_start + self.dst_len;
+                let dst0    = if start < self.dst_start { self.dst_start } else { start };
+                let dst1    = if end   > dst_end        { dst_end        } else { end   };
+                Span { dst: dst0 .. dst1, src: src.start + (dst0 - start) .. src.start + (dst1 - start), mirror: false }
+            },
+        }
+    }
+}
+
+impl<S: RatioScalar> Iterator for Spans<S> {
+    type Item = Span<S>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.count { return None; }
+        let span = self.span(self.next);
+        self.next += 1;
+        Some(span)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<S: RatioScalar> ExactSizeIterator for Spans<S> {}
+
+
+
+#[test] fn scale_round_test() {
+    let spans = |dst: Range<i32>, src: Range<i32>| Scale::Round.spans(dst, src).map(|s| (s.dst, s.src)).collect::<Vec<_>>();
+
+    assert_eq!(spans(0..9,  5..8), vec![(0..3, 5..8), (3..6, 5..8), (6..9, 5..8)]);
+    assert_eq!(spans(0..10, 5..8), vec![(0..3, 5..8), (3..6, 5..8), (6..10, 5..8)]);
+    assert_eq!(spans(0..11, 5..8), vec![(0..2, 5..8), (2..5, 5..8), (5..8, 5..8), (8..11, 5..8)]);
+    assert_eq!(spans(0..1,  5..8), vec![(0..1, 5..8)]);
+    assert_eq!(spans(0..0,  5..8), vec![(0..0, 5..8)]);
+    assert_eq!(spans(0..5,  5..5), vec![]);
+
+    let mut spans = Scale::Round.spans(0..11, 5..8);
+    assert_eq!(spans.len(), 4);
+    spans.next();
+    assert_eq!(spans.size_hint(), (3, Some(3)));
+
+    let spans = Scale::Round.spans(0.0..11.0, 0.0..3.0).map(|s| s.dst).collect::<Vec<_>>();
+    assert_eq!(spans, vec![0.0..2.75, 2.75..5.5, 5.5..8.25, 8.25..11.0]);
+}
+
+#[test] fn scale_space_test() {
+    let spans = |dst: Range<i32>, src: Range<i32>| Scale::Space(Justify::Evenly).spans(dst, src).map(|s| (s.dst, s.src)).collect::<Vec<_>>();
+
+    // 3 tiles of 5 in 19 → 4 leftover split into 4 gaps of 1
+    assert_eq!(spans(0..19, 5..10), vec![(1..6, 5..10), (7..12, 5..10), (13..18, 5..10)]);
+
+    // Exact fit → no gaps
+    assert_eq!(spans(10..19, 0..3), vec![(10..13, 0..3), (13..16, 0..3), (16..19, 0..3)]);
+
+    // Uneven leftovers are spread out instead of piling up at the end
+    assert_eq!(spans(0..8, 0..3), vec![(0..3, 0..3), (4..7, 0..3)]);
+
+    // Nothing fits / degenerate src → nothing emitted
+    assert_eq!(spans(0..2, 0..3), vec![]);
+    assert_eq!(spans(0..2, 0..0), vec![]);
+
+    let spans = Scale::Space(Justify::Evenly).spans(0.0..10.0, 0.0..3.0).map(|s| (s.dst, s.src)).collect::<Vec<_>>();
+    assert_eq!(spans, vec![(0.25..3.25, 0.0..3.0), (3.5..6.5, 0.0..3.0), (6.75..9.75, 0.0..3.0)]);
+}
+
+#[test] fn scale_space_justify_test() {
+    let spans = |justify: Justify, dst: Range<i32>| Scale::Space(justify).spans(dst, 0..5).map(|s| s.dst).collect::<Vec<_>>();
+
+    // 3 tiles of 5 in 19 → 4 leftover
+    assert_eq!(spans(Justify::Between, 0..19), vec![0..5, 7..12, 14..19]);
+    assert_eq!(spans(Justify::Around,  0..19), vec![0..5, 7..12, 13..18]);
+    assert_eq!(spans(Justify::Evenly,  0..19), vec![1..6, 7..12, 13..18]);
+
+    // Lone tile
+    assert_eq!(spans(Justify::Between, 0..8), vec![0..5]);
+    assert_eq!(spans(Justify::Around,  0..8), vec![1..6]);
+    assert_eq!(spans(Justify::Evenly,  0..8), vec![1..6]);
+
+    let spans = Scale::Space(Justify::Around).spans(0.0..10.0, 0.0..3.0).map(|s| s.dst).collect::<Vec<_>>();
+    assert_eq!(spans, vec![1.0/6.0 .. 1.0/6.0 + 3.0, 3.5 .. 6.5, 6.0 + 5.0/6.0 .. 6.0 + 5.0/6.0 + 3.0]);
+}
+
+#[test] fn scale_repeat_test() {
+    let spans = |scale: Scale, dst: Range<i32>, src: Range<i32>| scale.spans(dst, src).map(|s| (s.dst, s.src, s.mirror)).collect::<Vec<_>>();
+
+    let repeat          = Scale::Repeat(Partial::Center);
+    let mirror_repeat   = Scale::MirrorRepeat(Partial::Center);
+
+    // Exact fit
+    assert_eq!(spans(repeat, 0..6, 5..8), vec![(0..3, 5..8, false), (3..6, 5..8, false)]);
+    assert_eq!(spans(Scale::Repeat(Partial::Split), 0..6, 5..8), vec![(0..3, 5..8, false), (3..6, 5..8, false)]);
+
+    // 3 whole tiles + a partial tile in the middle
+    assert_eq!(spans(repeat, 0..11, 5..8), vec![(0..3, 5..8, false), (3..5, 5..7, false), (5..8, 5..8, false), (8..11, 5..8, false)]);
+
+    // Every other tile is mirrored, partial tiles keep the seam with the previous tile
+    assert_eq!(spans(mirror_repeat, 0..11, 5..8), vec![(0..3, 5..8, false), (3..5, 6..8, true), (5..8, 5..8, false), (8..11, 5..8, true)]);
+    assert_eq!(spans(mirror_repeat, 0..13, 5..8), vec![(0..3, 5..8, false), (3..6, 5..8, true), (6..7, 5..6, false), (7..10, 5..8, true), (10..13, 5..8, false)]);
+
+    // Degenerate src → nothing emitted
+    assert_eq!(spans(repeat, 0..2, 0..0), vec![]);
+    assert_eq!(spans(repeat, 0..0, 0..3), vec![]);
+}
+
+#[test] fn scale_repeat_partial_test() {
+    let spans = |scale: Scale, dst: Range<i32>, src: Range<i32>| scale.spans(dst, src).map(|s| (s.dst, s.src, s.mirror)).collect::<Vec<_>>();
+
+    assert_eq!(spans(Scale::Repeat(Partial::Start), 0..8, 5..8), vec![(0..2, 6..8, false), (2..5, 5..8, false), (5..8, 5..8, false)]);
+    assert_eq!(spans(Scale::Repeat(Partial::End),   0..8, 5..8), vec![(0..3, 5..8, false), (3..6, 5..8, false), (6..8, 5..7, false)]);
+    assert_eq!(spans(Scale::Repeat(Partial::Split), 0..8, 5..8), vec![(0..1, 7..8, false), (1..4, 5..8, false), (4..7, 5..8, false), (7..8, 5..6, false)]);
+
+    // Too small to split → only the trailing partial remains
+    assert_eq!(spans(Scale::Repeat(Partial::Split), 0..7, 5..8), vec![(0..3, 5..8, false), (3..6, 5..8, false), (6..7, 5..6, false)]);
+
+    // Mirrored partials still keep their seams intact
+    assert_eq!(spans(Scale::MirrorRepeat(Partial::Split), 0..8, 5..8), vec![(0..1, 7..8, false), (1..4, 5..8, true), (4..7, 5..8, false), (7..8, 7..8, true)]);
+    assert_eq!(spans(Scale::MirrorRepeat(Partial::End),   0..8, 5..8), vec![(0..3, 5..8, false), (3..6, 5..8, true), (6..8, 5..7, false)]);
+}
+
+#[test] fn scale_fixed_test() {
+    let spans = |scale: Scale, dst: Range<i32>, src: Range<i32>| scale.spans(dst, src).map(|s| (s.dst, s.src)).collect::<Vec<_>>();
+
+    assert_eq!(spans(Scale::Fixed(Align::Start),  10..20, 5..9), vec![(10..14, 5..9)]);
+    assert_eq!(spans(Scale::Fixed(Align::Center), 10..20, 5..9), vec![(13..17, 5..9)]);
+    assert_eq!(spans(Scale::Fixed(Align::End),    10..20, 5..9), vec![(16..20, 5..9)]);
+
+    // Larger than dst → overflows
+    assert_eq!(spans(Scale::Fixed(Align::Center), 10..12, 0..6), vec![( 8..14, 0..6)]);
+    assert_eq!(spans(Scale::Fixed(Align::End),    10..12, 0..6), vec![( 6..12, 0..6)]);
+}
+
+#[test] fn scale_crop_test() {
+    let spans = |scale: Scale, dst: Range<i32>, src: Range<i32>| scale.spans(dst, src).map(|s| (s.dst, s.src)).collect::<Vec<_>>();
+
+    // Smaller than dst → same as Fixed
+    assert_eq!(spans(Scale::Crop(Align::Center), 10..20, 5..9), vec![(13..17, 5..9)]);
+
+    // Larger than dst → src cropped to match
+    assert_eq!(spans(Scale::Crop(Align::Start),  10..12, 0..6), vec![(10..12, 0..2)]);
+    assert_eq!(spans(Scale::Crop(Align::Center), 10..12, 0..6), vec![(10..12, 2..4)]);
+    assert_eq!(spans(Scale::Crop(Align::End),    10..12, 0..6), vec![(10..12, 4..6)]);
+
+    let spans = Scale::Crop(Align::Center).spans(0.0..1.0, 0.0..4.0).map(|s| (s.dst, s.src)).collect::<Vec<_>>();
+    assert_eq!(spans, vec![(0.0..1.0, 1.5..2.5)]);
+}
+
+#[test] fn scale_remainder_test() {
+    let spans = |scale: Scale, dst: Range<i32>, src: Range<i32>, remainder| scale.spans_with(dst, src, remainder).map(|s| s.dst).collect::<Vec<_>>();
+
+    // 3 tiles of 5 in 19 → 4 leftover split into 4 gaps of 1:  nothing left to distribute
+    for remainder in [Remainder::Spread(Rounding::Floor), Remainder::Start, Remainder::End].iter().copied() {
+        assert_eq!(spans(Scale::Space(Justify::Evenly), 0..19, 5..10, remainder), vec![1..6, 7..12, 13..18]);
+    }
+
+    // 2 tiles of 3 in 8 → 2 leftover split into 3 gaps
+    assert_eq!(spans(Scale::Space(Justify::Evenly), 0..8, 0..3, Remainder::Spread(Rounding::Floor)), vec![0..3, 4..7]);
+    assert_eq!(spans(Scale::Space(Justify::Evenly), 0..8, 0..3, Remainder::Start ), vec![1..4, 5..8]);
+    assert_eq!(spans(Scale::Space(Justify::Evenly), 0..8, 0..3, Remainder::End   ), vec![0..3, 4..7]);
+
+    // 1 leftover pixel split between the fractional images
+    assert_eq!(spans(Scale::Repeat(Partial::Split), 0..7, 0..3, Remainder::Spread(Rounding::Floor)), vec![0..3, 3..6, 6..7]);
+    assert_eq!(spans(Scale::Repeat(Partial::Split), 0..7, 0..3, Remainder::Start ), vec![0..1, 1..4, 4..7]);
+
+    // Every policy covers the whole span exactly
+    for dst_len in 0 .. 40 {
+        for remainder in [Remainder::Spread(Rounding::Floor), Remainder::Spread(Rounding::Ceil), Remainder::Spread(Rounding::Nearest), Remainder::Spread(Rounding::NearestEven), Remainder::Start, Remainder::End].iter().copied() {
+            for scale in [Scale::Round, Scale::Repeat(Partial::Split)].iter().copied() {
+                let dst = spans(scale, 3..3+dst_len, 0..7, remainder);
+                assert_eq!(dst.first().map_or(3, |d| d.start), 3);
+                assert_eq!(dst.last().map_or(3+dst_len, |d| d.end), 3+dst_len);
+                for pair in dst.windows(2) { assert_eq!(pair[0].end, pair[1].start); }
+            }
+        }
+    }
 }
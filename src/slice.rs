@@ -0,0 +1,211 @@
+use super::*;
+
+/// A single [border-image-slice] offset:  either an absolute length, or a percentage of the source image's size.
+/// 
+/// Use a [Borders]`<SliceSpec<S>>` to specify all four sides, then [resolve] it against a source image.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let slices = Borders { left: SliceSpec::Px(4), right: SliceSpec::Px(4), top: SliceSpec::Percent(25), bottom: SliceSpec::Percent(25) };
+/// let src = slices.resolve(Rect::xywh(0, 0, 32, 64).validate().unwrap()).unwrap();
+/// assert_eq!(src.inner, Rect { left: 4, right: 28, top: 16, bottom: 48 });
+/// ```
+/// 
+/// [border-image-slice]:   https://www.w3.org/TR/css-backgrounds-3/#border-image-slice
+/// [Borders]:              struct.Borders.html
+/// [resolve]:              struct.Borders.html#method.resolve
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SliceSpec<S: RatioScalar> {
+    /// An absolute offset from the edge of the source image.
+    Px(S),
+
+    /// An offset from the edge of the source image, as a percentage (`0 ..= 100`) of its width (for `left`/`right`) or
+    /// height (for `top`/`bottom`.)
+    Percent(S),
+}
+
+impl<S: RatioScalar> SliceSpec<S> {
+    /// Resolve this offset against a source length.
+    #[must_use] pub fn resolve(self, len: S) -> S {
+        match self {
+            SliceSpec::Px(px)           => px,
+            SliceSpec::Percent(percent) => len * percent / S::from_count(100),
+        }
+    }
+}
+
+impl<S: RatioScalar> core::default::Default for SliceSpec<S> { fn default() -> Self { SliceSpec::Px(S::default()) } }
+
+impl<S: RatioScalar> From<Borders<S>> for Borders<SliceSpec<S>> {
+    fn from(px: Borders<S>) -> Self {
+        Borders { left: SliceSpec::Px(px.left), right: SliceSpec::Px(px.right), top: SliceSpec::Px(px.top), bottom: SliceSpec::Px(px.bottom) }
+    }
+}
+
+impl<S: RatioScalar> Borders<SliceSpec<S>> {
+    /// Resolve these slice offsets into [Dimensions] within `src_outer`.
+    /// 
+    /// Returns `Err` if any offset resolves to a negative (or NaN) length, or if opposing offsets overlap.
+    /// 
+    /// [Dimensions]:   struct.Dimensions.html
+    pub fn resolve(&self, src_outer: ValidRect<S>) -> Result<ValidDimensions<S>, Error> {
+        let (width, height) = (src_outer.width(), src_outer.height());
+        let borders = Borders {
+            left:   self.left  .resolve(width),
+            right:  self.right .resolve(width),
+            top:    self.top   .resolve(height),
+            bottom: self.bottom.resolve(height),
+        };
+        let zero = S::default();
+        if !(zero <= borders.left  ) { return err("Expected 0 ≤ left slice"); }
+        if !(zero <= borders.right ) { return err("Expected 0 ≤ right slice"); }
+        if !(zero <= borders.top   ) { return err("Expected 0 ≤ top slice"); }
+        if !(zero <= borders.bottom) { return err("Expected 0 ≤ bottom slice"); }
+        Dimensions { outer: *src_outer, inner: src_outer.shrink(&borders) }.validate()
+    }
+}
+
+/// A single [border-image-width]:  how wide a destination border is.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let src = Dimensions { outer: Rect::xywh(0, 0, 30, 30), inner: Rect::xywh(10, 10, 10, 10) }.validate().unwrap();
+/// let border_box = Rect::xywh(0, 0, 200, 100).validate().unwrap();
+/// let border_widths = Borders::uniform(5);
+/// let width = Borders { left: BorderImageWidth::Multiple(2), right: BorderImageWidth::Auto, top: BorderImageWidth::Percent(10), bottom: BorderImageWidth::Px(1) };
+/// let outset = Borders { left: BorderImageOutset::Px(0), right: BorderImageOutset::Px(0), top: BorderImageOutset::Multiple(1), bottom: BorderImageOutset::Px(0) };
+/// 
+/// let dst = src.with_border_image(border_box, &border_widths, &width, &outset).unwrap();
+/// assert_eq!(dst.outer, Rect { left: 0, right: 200, top: -5, bottom: 100 });
+/// assert_eq!(dst.borders(), Borders { left: 10, right: 10, top: 10, bottom: 1 });
+/// ```
+/// 
+/// [border-image-width]:   https://www.w3.org/TR/css-backgrounds-3/#border-image-width
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BorderImageWidth<S: RatioScalar> {
+    /// An absolute width.
+    Px(S),
+
+    /// A percentage (`0 ..= 100`) of the border image area's width (for `left`/`right`) or height (for `top`/`bottom`.)
+    Percent(S),
+
+    /// A multiple of the corresponding `border-width`.  CSS's initial value is `Multiple(1)`.
+    Multiple(S),
+
+    /// The width (or height) of the corresponding source slice.
+    Auto,
+}
+
+impl<S: RatioScalar> core::default::Default for BorderImageWidth<S> { fn default() -> Self { BorderImageWidth::Multiple(S::from_count(1)) } }
+
+/// A single [border-image-outset]:  how far the border image area extends beyond the border box.
+/// 
+/// [border-image-outset]:  https://www.w3.org/TR/css-backgrounds-3/#border-image-outset
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BorderImageOutset<S: RatioScalar> {
+    /// An absolute distance.
+    Px(S),
+
+    /// A multiple of the corresponding `border-width`.
+    Multiple(S),
+}
+
+impl<S: RatioScalar> core::default::Default for BorderImageOutset<S> { fn default() -> Self { BorderImageOutset::Px(S::default()) } }
+
+impl<S: RatioScalar> Borders<BorderImageOutset<S>> {
+    /// Resolve these outsets into absolute distances.
+    #[must_use] pub fn resolve(&self, border_widths: &Borders<S>) -> Borders<S> {
+        let resolve = |outset, border_width| match outset {
+            BorderImageOutset::Px(px)           => px,
+            BorderImageOutset::Multiple(n)      => n * border_width,
+        };
+        Borders {
+            left:   resolve(self.left,   border_widths.left  ),
+            right:  resolve(self.right,  border_widths.right ),
+            top:    resolve(self.top,    border_widths.top   ),
+            bottom: resolve(self.bottom, border_widths.bottom),
+        }
+    }
+}
+
+impl<S: RatioScalar> Borders<BorderImageWidth<S>> {
+    /// Resolve these widths into absolute destination border sizes, given the border image `area` (the border box
+    /// grown by any outset), the element's `border_widths`, and the `src` slices.
+    #[must_use] pub fn resolve(&self, area: ValidRect<S>, border_widths: &Borders<S>, src: &ValidDimensions<S>) -> Borders<S> {
+        let hundred = S::from_count(100);
+        let src_borders = src.borders();
+        let resolve = |width, area_len: S, border_width, src_border| match width {
+            BorderImageWidth::Px(px)            => px,
+            BorderImageWidth::Percent(percent)  => area_len * percent / hundred,
+            BorderImageWidth::Multiple(n)       => n * border_width,
+            BorderImageWidth::Auto              => src_border,
+        };
+        Borders {
+            left:   resolve(self.left,   area.width(),  border_widths.left,   src_borders.left  ),
+            right:  resolve(self.right,  area.width(),  border_widths.right,  src_borders.right ),
+            top:    resolve(self.top,    area.height(), border_widths.top,    src_borders.top   ),
+            bottom: resolve(self.bottom, area.height(), border_widths.bottom, src_borders.bottom),
+        }
+    }
+}
+
+impl<S: RatioScalar> ValidDimensions<S> {
+    /// Derive destination dimensions for these source slices per CSS `border-image-width` and `border-image-outset`.
+    /// 
+    /// `outer` is the border box grown by `outset`, and the border sizes are resolved from `width`.
+    /// 
+    /// Can return Err if:
+    /// * The outset or widths resolve to negative (or NaN) sizes
+    /// * The center would have negative bounds
+    pub fn with_border_image(&self, border_box: ValidRect<S>, border_widths: &Borders<S>, width: &Borders<BorderImageWidth<S>>, outset: &Borders<BorderImageOutset<S>>) -> Result<Self, Error> {
+        let zero = S::default();
+        let outset = outset.resolve(border_widths);
+        if !(zero <= outset.left && zero <= outset.right && zero <= outset.top && zero <= outset.bottom) { return err("Expected 0 ≤ border-image-outset"); }
+        let area = border_box.grow(&outset).validate()?;
+
+        let widths = width.resolve(area, border_widths, self);
+        if !(zero <= widths.left && zero <= widths.right && zero <= widths.top && zero <= widths.bottom) { return err("Expected 0 ≤ border-image-width"); }
+        Dimensions { outer: *area, inner: area.shrink(&widths) }.validate()
+    }
+}
+
+
+
+#[test] fn slice_spec_test() {
+    use SliceSpec::*;
+    let src = Rect::xywh(100.0, 200.0, 40.0, 80.0).validate().unwrap();
+    let slices = Borders { left: Percent(10.0), right: Px(2.0), top: Percent(50.0), bottom: Percent(25.0) };
+    assert_eq!(slices.resolve(src).unwrap().borders(), Borders { left: 4.0, right: 2.0, top: 40.0, bottom: 20.0 });
+    assert_eq!(Borders::<SliceSpec<i32>>::default().resolve(Rect::xywh(0, 0, 4, 4).validate().unwrap()).unwrap().inner, Rect::xywh(0, 0, 4, 4));
+    assert_eq!(Borders::<SliceSpec<i32>>::from(Borders::uniform(1)).left, Px(1));
+
+    assert!(Borders { left: Percent(60.0), right: Percent(50.0), top: Px(0.0), bottom: Px(0.0) }.resolve(src).is_err());
+    assert!(Borders { left: Px(-1.0), right: Px(0.0), top: Px(0.0), bottom: Px(0.0) }.resolve(src).is_err());
+    assert!(Borders { left: Percent(f32::NAN), right: Px(0.0), top: Px(0.0), bottom: Px(0.0) }.resolve(src).is_err());
+}
+
+#[test] fn border_image_width_outset_test() {
+    use BorderImageWidth as W;
+    use BorderImageOutset as O;
+    let src = Dimensions { outer: Rect::xywh(0.0, 0.0, 12.0, 12.0), inner: Rect::xywh(3.0, 4.0, 6.0, 4.0) }.validate().unwrap();
+    let border_box = Rect::xywh(10.0, 10.0, 100.0, 50.0).validate().unwrap();
+    let border_widths = Borders { left: 1.0, right: 2.0, top: 3.0, bottom: 4.0 };
+
+    let initial = src.with_border_image(border_box, &border_widths, &Borders::default(), &Borders::default()).unwrap();
+    assert_eq!(initial.outer, *border_box);
+    assert_eq!(initial.borders(), border_widths);
+
+    let width = Borders { left: W::Auto, right: W::Percent(25.0), top: W::Multiple(2.0), bottom: W::Px(5.0) };
+    let outset = Borders { left: O::Px(2.0), right: O::Multiple(1.0), top: O::Px(0.0), bottom: O::Multiple(0.5) };
+    let dst = src.with_border_image(border_box, &border_widths, &width, &outset).unwrap();
+    assert_eq!(dst.outer, Rect { left: 8.0, right: 112.0, top: 10.0, bottom: 62.0 });
+    assert_eq!(dst.borders(), Borders { left: 3.0, right: 26.0, top: 6.0, bottom: 5.0 });
+
+    assert!(src.with_border_image(border_box, &border_widths, &Borders { left: W::Px(60.0), right: W::Px(60.0), top: W::Auto, bottom: W::Auto }, &Borders::default()).is_err());
+    assert!(src.with_border_image(border_box, &border_widths, &Borders::default(), &Borders { left: O::Px(-1.0), right: O::Px(0.0), top: O::Px(0.0), bottom: O::Px(0.0) }).is_err());
+}
@@ -1,8 +1,9 @@
 use super::*;
+use crate::scalar::round_scalar;
 
 /// The scaling style of a nine-square layout.
 /// 
-/// Corners, and the short axis of borders, are hardcoded against `Scale::Stretch`.
+/// Borders are scaled along their long axis by `border`, and along their short axis by `border_cross`.
 /// 
 /// ```text
 ///    ┈┬───────────┬┈
@@ -16,7 +17,7 @@ use super::*;
 /// | axis | direction  | value |
 /// | ---- | ---------- | ----- |
 /// | `x` | `horizontal`    | `border.top`
-/// | `y` | `vertical`      | `Scale::Stretch`
+/// | `y` | `vertical`      | `border_cross.top`
 /// 
 /// ```text
 ///  ┌──┬┈
@@ -29,8 +30,8 @@ use super::*;
 /// 
 /// | axis | direction  | value |
 /// | ---- | ---------- | ----- |
-/// | `x` | `horizontal`    | `Scale::Stretch`
-/// | `y` | `vertical`      | `Scale::Stretch`
+/// | `x` | `horizontal`    | `corners.top_left.horizontal`
+/// | `y` | `vertical`      | `corners.top_left.vertical`
 /// 
 /// The center gets its own values:
 /// 
@@ -50,14 +51,57 @@ use super::*;
 /// | ---- | ---------- | ----- |
 /// | `x` | `horizontal`    | `center.horizontal`
 /// | `y` | `vertical`      | `center.vertical`
-#[derive(Clone, Copy, Debug, Default)]
+/// 
+/// Unless `fill` is `false`, in which case the center is omitted entirely, like CSS [border-image-slice] without the `fill` keyword.
+/// 
+/// Cells not in `cells` are omitted entirely as well, e.g. to only draw the top row of a "header bar" skin.
+/// The remaining cells are emitted in `order`.
+/// 
+/// If `skip_empty` is `true`, tiles with a zero-width or zero-height destination (e.g. from zero-size borders) are
+/// omitted too, instead of wasting draw calls on degenerate rectangles.
+/// 
+/// If `mirror_horizontal` is `true`, the whole layout is flipped left-to-right across the destination's outer rect -
+/// e.g. for right-to-left locales.  The source's right cells end up drawn on the left (and vice versa), and every tile is
+/// reported as horizontally mirrored.  `border`, `border_cross`, `corners`, and `center` still describe the source art,
+/// while `cells` and `order` refer to where cells end up being drawn.
+/// 
+/// Destination edges are snapped to whole pixels per `snap`, e.g. to avoid shimmering seams with floating point layouts.
+/// See [Snap].
+/// 
+/// Integer leftovers from splitting cells into tiles or gaps are distributed per `remainder`.  See [Remainder].
+/// 
+/// If `saturating` is `true`, subtractions that would underflow clamp instead - e.g. with `u32` texture coordinates, a
+/// [Scale::Fixed] or [Scale::Crop] source longer than its destination is aligned to the start of the destination,
+/// instead of panicking.  See [Spans::saturating].
+/// 
+/// [border-image-slice]:   https://www.w3.org/TR/css-backgrounds-3/#border-image-slice
+/// [Snap]:                 enum.Snap.html
+/// [Remainder]:            enum.Remainder.html
+/// [Scale::Fixed]:         enum.Scale.html#variant.Fixed
+/// [Scale::Crop]:          enum.Scale.html#variant.Crop
+/// [Spans::saturating]:    struct.Spans.html#method.saturating
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Style {
-    pub border:     Rect<Scale>,
-    pub center:     Axises<Scale>,
+    pub border:         Rect<Scale>,
+    pub border_cross:   Rect<Scale>,
+    pub center:         Axises<Scale>,
+    pub corners:        Corners<Axises<Scale>>,
+    pub fill:           bool,
+    pub cells:          CellMask,
+    pub order:          EmitOrder,
+    pub skip_empty:     bool,
+    pub mirror_horizontal: bool,
+    pub snap:           Snap,
+    pub remainder:      Remainder,
+    pub saturating:     bool,
 }
 
+impl core::default::Default for Style { fn default() -> Self { Style::new(Scale::Stretch) } }
+
 impl Style {
-    /// Create a new style with the same scaling on all axises.
+    /// Create a new style with the same scaling on all axises.  Corners, and the short axis of borders, are stretched.
     /// 
     /// ```text
     ///  ┊←→┊←─ scale ─→┊←→┊
@@ -75,7 +119,7 @@ impl Style {
         Self::new_horizontal_vertical(scale, scale)
     }
 
-    /// Create a new style with uniform scaling along each axis.
+    /// Create a new style with uniform scaling along each axis.  Corners, and the short axis of borders, are stretched.
     /// 
     /// ```text
     ///  ┊←→┊←horizontal→┊←→┊
@@ -97,10 +141,181 @@ impl Style {
                 top:    horizontal,
                 bottom: horizontal,
             },
+            border_cross: Rect {
+                left:   Scale::Stretch,
+                right:  Scale::Stretch,
+                top:    Scale::Stretch,
+                bottom: Scale::Stretch,
+            },
             center: Axises {
                 horizontal,
                 vertical,
             },
+            corners: Corners {
+                top_left:       Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch },
+                top_right:      Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch },
+                bottom_left:    Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch },
+                bottom_right:   Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch },
+            },
+            fill:   true,
+            cells:  CellMask::ALL,
+            order:  EmitOrder::RowMajor,
+            skip_empty: false,
+            mirror_horizontal: false,
+            snap:   Snap::None,
+            remainder:  Remainder::Spread(Rounding::Floor),
+            saturating: false,
+        }
+    }
+
+    /// Parse a CSS [border-image-repeat] value - one or two of `stretch`, `repeat`, `round`, or `space` - into a style.
+    /// A single keyword applies to both axises, otherwise the first is horizontal and the second vertical.
+    /// 
+    /// `repeat` maps to [Scale::Repeat]`(`[Partial::Split]`)` and `space` to [Scale::Space]`(`[Justify::Evenly]`)`.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let style = Style::from_css_repeat("round space").unwrap();
+    /// assert_eq!(style.center.horizontal, Scale::Round);
+    /// assert_eq!(style.center.vertical,   Scale::Space(Justify::Evenly));
+    /// assert!(Style::from_css_repeat("round round round").is_err());
+    /// ```
+    /// 
+    /// [border-image-repeat]:  https://www.w3.org/TR/css-backgrounds-3/#the-border-image-repeat
+    /// [Scale::Repeat]:        enum.Scale.html#variant.Repeat
+    /// [Scale::Space]:         enum.Scale.html#variant.Space
+    /// [Partial::Split]:       enum.Partial.html#variant.Split
+    /// [Justify::Evenly]:      enum.Justify.html#variant.Evenly
+    pub fn from_css_repeat(value: &str) -> Result<Self, Error> {
+        let mut keywords = value.split_ascii_whitespace().map(|keyword| {
+            let is = |k: &str| keyword.eq_ignore_ascii_case(k);
+            if      is("stretch")   { Ok(Scale::Stretch) }
+            else if is("repeat")    { Ok(Scale::Repeat(Partial::Split)) }
+            else if is("round")     { Ok(Scale::Round) }
+            else if is("space")     { Ok(Scale::Space(Justify::Evenly)) }
+            else                    { err("Expected border-image-repeat keyword: stretch, repeat, round, or space") }
+        });
+        let horizontal  = match keywords.next() { Some(scale) => scale?, None => return err("Expected at least one border-image-repeat keyword") };
+        let vertical    = match keywords.next() { Some(scale) => scale?, None => horizontal };
+        if keywords.next().is_some() { return err("Expected at most two border-image-repeat keywords"); }
+        Ok(Self::new_horizontal_vertical(horizontal, vertical))
+    }
+
+    /// The horizontal and vertical scaling used for `cell`.
+    pub fn cell_scale(&self, cell: Cell) -> Axises<Scale> {
+        let (border, cross, corners) = (self.border, self.border_cross, self.corners);
+        let (horizontal, vertical) = match cell {
+            Cell::TopLeft       => (corners.top_left.horizontal,        corners.top_left.vertical       ),
+            Cell::Top           => (border.top,                         cross.top                       ),
+            Cell::TopRight      => (corners.top_right.horizontal,       corners.top_right.vertical      ),
+            Cell::Left          => (cross.left,                         border.left                     ),
+            Cell::Center        => (self.center.horizontal,             self.center.vertical            ),
+            Cell::Right         => (cross.right,                        border.right                    ),
+            Cell::BottomLeft    => (corners.bottom_left.horizontal,     corners.bottom_left.vertical    ),
+            Cell::Bottom        => (border.bottom,                      cross.bottom                    ),
+            Cell::BottomRight   => (corners.bottom_right.horizontal,    corners.bottom_right.vertical   ),
+        };
+        Axises { horizontal, vertical }
+    }
+
+    /// The source cell that ends up drawn as `cell` - the same cell, unless `mirror_horizontal` is set.
+    pub fn src_cell(&self, cell: Cell) -> Cell {
+        if self.mirror_horizontal { cell.mirror_horizontal() } else { cell }
+    }
+
+    /// Check if `cell` should be emitted at all, based on `fill` and `cells`.
+    pub fn emits(&self, cell: Cell) -> bool {
+        self.cells.contains(cell) && (self.fill || cell != Cell::Center)
+    }
+
+    /// Check if every one of `cells` is emitted as a single stretched tile, unmirrored and unsnapped - that is, if a
+    /// fixed-size `to_array` describes this style exactly.
+    pub(crate) fn stretches(&self, cells: &[Cell]) -> bool {
+        let stretch = Axises { horizontal: Scale::Stretch, vertical: Scale::Stretch };
+        !self.mirror_horizontal && self.snap == Snap::None && cells.iter().all(|cell| self.emits(*cell) && self.cell_scale(*cell) == stretch)
+    }
+}
+
+
+
+#[test] fn style_from_css_repeat_test() {
+    assert_eq!(Style::from_css_repeat("stretch").unwrap(), Style::new(Scale::Stretch));
+    assert_eq!(Style::from_css_repeat("  REPEAT ").unwrap(), Style::new(Scale::Repeat(Partial::Split)));
+    assert_eq!(Style::from_css_repeat("round\tspace").unwrap(), Style::new_horizontal_vertical(Scale::Round, Scale::Space(Justify::Evenly)));
+    assert!(Style::from_css_repeat("").is_err());
+    assert!(Style::from_css_repeat("repeat-x").is_err());
+    assert!(Style::from_css_repeat("round, space").is_err());
+}
+
+#[cfg(feature = "serde")] #[test] fn style_serde_test() {
+    let mut style = Style::new_horizontal_vertical(Scale::Repeat(Partial::Split), Scale::Space(Justify::Around));
+    style.corners.top_left.horizontal = Scale::Fixed(Align::End);
+    style.cells = CellMask::TOP_ROW | Cell::Center;
+    style.order = EmitOrder::CenterFirst;
+
+    let json = serde_json::to_string(&style).unwrap();
+    assert_eq!(serde_json::from_str::<Style>(&json).unwrap(), style);
+
+    let partial : Style = serde_json::from_str(r#"{ "fill": false, "cells": ["Top", "Center", "Top"] }"#).unwrap();
+    assert!(!partial.fill);
+    assert_eq!(partial.cells, CellMask::from(Cell::Top) | Cell::Center);
+    assert_eq!(partial.border, Style::default().border);
+
+    let dims = Dimensions { outer: Rect { left: 0, right: 10, top: 0, bottom: 10 }, inner: Rect { left: 2, right: 8, top: 3, bottom: 7 } };
+    let json = serde_json::to_string(&dims).unwrap();
+    assert_eq!(serde_json::from_str::<Dimensions<i32>>(&json).unwrap(), dims);
+}
+
+
+
+/// How to snap destination edges to whole pixels.  See [Style::snap].
+/// 
+/// Either way, tiles that shared an edge before snapping still share it afterwards, so snapping can't open up seams.
+/// Integer layouts are unaffected.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let layout = |snap| Layout {
+///     src: Dimensions { outer: Rect::xywh(0.0, 0.0, 3.0, 3.0), inner: Rect::xywh(1.0, 1.0, 1.0, 1.0) },
+///     dst: Dimensions { outer: Rect::xywh(0.25, 0.0, 5.5, 3.0), inner: Rect::xywh(1.25, 1.0, 3.5, 1.0) },
+///     style: Style { snap, ..Style::default() },
+/// }.validate().unwrap();
+/// 
+/// let top = |snap| layout(snap).tiles().take(3).map(|(dst, _src)| (dst.left, dst.right)).collect::<Vec<_>>();
+/// assert_eq!(top(Snap::None),   vec![(0.25, 1.25), (1.25, 4.75), (4.75, 5.75)]);
+/// assert_eq!(top(Snap::Edges),  vec![(0.0,  1.0 ), (1.0,  5.0 ), (5.0,  6.0 )]);
+/// assert_eq!(top(Snap::Origin), vec![(0.0,  1.0 ), (1.0,  4.5 ), (4.5,  5.5 )]);
+/// ```
+/// 
+/// [Style::snap]:  struct.Style.html#structfield.snap
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Snap {
+    /// Emit destination edges as-is.
+    None,
+
+    /// Round every destination edge to the nearest whole pixel.  Tile sizes may change by up to a pixel.
+    Edges,
+
+    /// Translate the whole layout so the destination's outer top left corner lands on the nearest whole pixel.
+    /// Tile sizes are preserved exactly, so this only fully snaps layouts whose borders are already whole pixels.
+    Origin,
+}
+
+impl core::default::Default for Snap { fn default() -> Self { Snap::None } }
+
+impl Snap {
+    /// Snap a single destination coordinate `v`, where `origin` is the destination's outer left (or top) edge.
+    pub(crate) fn snap<S: Scalar>(self, v: S, origin: S) -> S {
+        match self {
+            Snap::None      => v,
+            Snap::Edges     => round_scalar(v, Rounding::Nearest),
+            Snap::Origin    => {
+                let snapped = round_scalar(origin, Rounding::Nearest);
+                if snapped == origin { v } else { v - origin + snapped }
+            },
         }
     }
 }
@@ -0,0 +1,368 @@
+use super::*;
+use core::convert::TryFrom;
+use core::ops::Deref;
+
+/// Horizontal 3-slice dimensions with non-negative & non-NAN dimensions.  See [HSlice].
+/// 
+/// [HSlice]:   struct.HSlice.html
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "HSlice<S>", into = "HSlice<S>"))] // Deserialization goes through `validate()`
+pub struct ValidHSlice<S: Scalar>(HSlice<S>);
+
+impl<S: Scalar> Deref for ValidHSlice<S> { type Target = HSlice<S>; fn deref(&self) -> &Self::Target { &self.0 } }
+// NOTE:  Do *NOT* implement DerefMut!  That would allow validation to be bypassed, mooting the point of this type!
+
+impl<S: Scalar> PartialEq<ValidHSlice<S>> for ValidHSlice<S> { fn eq(&self, other: &ValidHSlice<S>) -> bool { self.0 == other.0 } }
+impl<S: Scalar> TryFrom<HSlice<S>> for ValidHSlice<S> { type Error = Error; fn try_from(value: HSlice<S>) -> Result<Self, Error> { value.validate() } }
+impl<S: Scalar> From<ValidHSlice<S>> for HSlice<S> { fn from(value: ValidHSlice<S>) -> Self { value.0 } }
+impl<S: Scalar> From<ValidHSlice<S>> for ValidDimensions<S> { fn from(value: ValidHSlice<S>) -> Self { value.to_dimensions().debug_assert_valid() } }
+
+/// The dimensions of a horizontal 3-slice:  a left cap, a stretchable center, and a right cap - e.g. for progress bars,
+/// sliders, and tab headers.  See also [ValidHSlice].
+/// 
+/// ```text
+/// left                        right
+///  ┊←─────────── outer ─────────→┊
+///  ┊    inner_left  inner_right  ┊
+///  ┊        ┊           ┊        ┊
+///  ┌────────┬───────────┬────────┐┈┈ top
+///  │  left  │  center   │ right  │
+///  └────────┴───────────┴────────┘┈┈ bottom
+/// ```
+/// 
+/// [ValidHSlice]:  struct.ValidHSlice.html
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HSlice<S: Scalar> {
+    pub outer:          Rect<S>,
+    pub inner_left:     S,
+    pub inner_right:    S,
+}
+
+impl<S: Scalar> HSlice<S> {
+    /// Validate these dimensions are non-negative / non-NaN.  This means:
+    /// 
+    /// ```text
+    /// outer.left ≤ inner_left ≤ inner_right ≤ outer.right
+    /// outer.top ≤ outer.bottom
+    /// ```
+    pub fn validate(&self) -> Result<ValidHSlice<S>, Error> {
+        if !(self.outer.left  <= self.inner_left ) { return err("Expected outer.left ≤ inner_left"); }
+        if !(self.inner_left  <= self.inner_right) { return err("Expected inner_left ≤ inner_right"); }
+        if !(self.inner_right <= self.outer.right) { return err("Expected inner_right ≤ outer.right"); }
+        if !(self.outer.top   <= self.outer.bottom) { return err("Expected outer.top ≤ outer.bottom"); }
+        Ok(ValidHSlice(*self))
+    }
+
+    /// The equivalent 9-slice [Dimensions], with empty top and bottom rows.
+    /// 
+    /// [Dimensions]:   struct.Dimensions.html
+    #[must_use] pub fn to_dimensions(&self) -> Dimensions<S> {
+        let inner = Rect { left: self.inner_left, right: self.inner_right, top: self.outer.top, bottom: self.outer.bottom };
+        Dimensions { outer: self.outer, inner }
+    }
+}
+
+impl<S: Scalar> ThreeSlice for HSlice<S> {
+    type Scalar = S;
+    type Valid = ValidHSlice<S>;
+    const CELLS : [Cell; 3] = [Cell::Left, Cell::Center, Cell::Right];
+    fn validate(&self) -> Result<ValidHSlice<S>, Error> { HSlice::validate(self) }
+    fn to_dimensions(&self) -> Dimensions<S> { HSlice::to_dimensions(self) }
+}
+
+impl<S: Scalar> ThreeSlice for ValidHSlice<S> {
+    type Scalar = S;
+    type Valid = ValidHSlice<S>;
+    const CELLS : [Cell; 3] = [Cell::Left, Cell::Center, Cell::Right];
+    fn validate(&self) -> Result<ValidHSlice<S>, Error> { Ok(*self) }
+    fn to_dimensions(&self) -> Dimensions<S> { self.0.to_dimensions() }
+}
+
+/// Describe a horizontal 3-slice layout to render with.  Like [Layout], but emits only the left, center, and right cells.
+/// 
+/// `style` is interpreted as for the middle row of a [Layout]:  `border.left`/`border_cross.left` scale the left cap,
+/// `center` scales the center, and `border.right`/`border_cross.right` scale the right cap.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let bar = HSliceLayout {
+///     src:    HSlice { outer: Rect::xywh(0, 0, 6, 4), inner_left: 2, inner_right: 4 },
+///     dst:    HSlice { outer: Rect::xywh(0, 0, 20, 4), inner_left: 2, inner_right: 18 },
+///     style:  Style::default(),
+/// }.validate().unwrap();
+/// 
+/// let [left, center, right] = bar.to_array();
+/// assert_eq!(left,   (Rect::xywh( 0, 0,  2, 4).validate().unwrap(), Rect::xywh(0, 0, 2, 4).validate().unwrap()));
+/// assert_eq!(center, (Rect::xywh( 2, 0, 16, 4).validate().unwrap(), Rect::xywh(2, 0, 2, 4).validate().unwrap()));
+/// assert_eq!(right,  (Rect::xywh(18, 0,  2, 4).validate().unwrap(), Rect::xywh(4, 0, 2, 4).validate().unwrap()));
+/// ```
+/// 
+/// [Layout]:   struct.Layout.html
+pub type HSliceLayout<Dst, Src> = ThreeSliceLayout<Dst, Src>;
+
+
+
+/// Vertical 3-slice dimensions with non-negative & non-NAN dimensions.  See [VSlice].
+/// 
+/// [VSlice]:   struct.VSlice.html
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "VSlice<S>", into = "VSlice<S>"))] // Deserialization goes through `validate()`
+pub struct ValidVSlice<S: Scalar>(VSlice<S>);
+
+impl<S: Scalar> Deref for ValidVSlice<S> { type Target = VSlice<S>; fn deref(&self) -> &Self::Target { &self.0 } }
+// NOTE:  Do *NOT* implement DerefMut!  That would allow validation to be bypassed, mooting the point of this type!
+
+impl<S: Scalar> PartialEq<ValidVSlice<S>> for ValidVSlice<S> { fn eq(&self, other: &ValidVSlice<S>) -> bool { self.0 == other.0 } }
+impl<S: Scalar> TryFrom<VSlice<S>> for ValidVSlice<S> { type Error = Error; fn try_from(value: VSlice<S>) -> Result<Self, Error> { value.validate() } }
+impl<S: Scalar> From<ValidVSlice<S>> for VSlice<S> { fn from(value: ValidVSlice<S>) -> Self { value.0 } }
+impl<S: Scalar> From<ValidVSlice<S>> for ValidDimensions<S> { fn from(value: ValidVSlice<S>) -> Self { value.to_dimensions().debug_assert_valid() } }
+
+/// The dimensions of a vertical 3-slice:  a top cap, a stretchable center, and a bottom cap - e.g. for scrollbar thumbs
+/// and vertical gauges.  See also [ValidVSlice].
+/// 
+/// ```text
+/// left     right
+///  ┌────────┐┈┈┈┈┈┈┈┈┈┈┈┈┈┈┈┈ top
+///  │  top   │              ↑
+///  ├────────┤┈┈ inner_top  │
+///  │        │            outer
+///  │ center │              │
+///  │        │              │
+///  ├────────┤┈┈ inner_bottom
+///  │ bottom │              ↓
+///  └────────┘┈┈┈┈┈┈┈┈┈┈┈┈ bottom
+/// ```
+/// 
+/// [ValidVSlice]:  struct.ValidVSlice.html
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VSlice<S: Scalar> {
+    pub outer:          Rect<S>,
+    pub inner_top:      S,
+    pub inner_bottom:   S,
+}
+
+impl<S: Scalar> VSlice<S> {
+    /// Validate these dimensions are non-negative / non-NaN.  This means:
+    /// 
+    /// ```text
+    /// outer.top ≤ inner_top ≤ inner_bottom ≤ outer.bottom
+    /// outer.left ≤ outer.right
+    /// ```
+    pub fn validate(&self) -> Result<ValidVSlice<S>, Error> {
+        if !(self.outer.top    <= self.inner_top   ) { return err("Expected outer.top ≤ inner_top"); }
+        if !(self.inner_top    <= self.inner_bottom) { return err("Expected inner_top ≤ inner_bottom"); }
+        if !(self.inner_bottom <= self.outer.bottom) { return err("Expected inner_bottom ≤ outer.bottom"); }
+        if !(self.outer.left   <= self.outer.right ) { return err("Expected outer.left ≤ outer.right"); }
+        Ok(ValidVSlice(*self))
+    }
+
+    /// The equivalent 9-slice [Dimensions], with empty left and right columns.
+    /// 
+    /// [Dimensions]:   struct.Dimensions.html
+    #[must_use] pub fn to_dimensions(&self) -> Dimensions<S> {
+        let inner = Rect { left: self.outer.left, right: self.outer.right, top: self.inner_top, bottom: self.inner_bottom };
+        Dimensions { outer: self.outer, inner }
+    }
+}
+
+impl<S: Scalar> ThreeSlice for VSlice<S> {
+    type Scalar = S;
+    type Valid = ValidVSlice<S>;
+    const CELLS : [Cell; 3] = [Cell::Top, Cell::Center, Cell::Bottom];
+    fn validate(&self) -> Result<ValidVSlice<S>, Error> { VSlice::validate(self) }
+    fn to_dimensions(&self) -> Dimensions<S> { VSlice::to_dimensions(self) }
+}
+
+impl<S: Scalar> ThreeSlice for ValidVSlice<S> {
+    type Scalar = S;
+    type Valid = ValidVSlice<S>;
+    const CELLS : [Cell; 3] = [Cell::Top, Cell::Center, Cell::Bottom];
+    fn validate(&self) -> Result<ValidVSlice<S>, Error> { Ok(*self) }
+    fn to_dimensions(&self) -> Dimensions<S> { self.0.to_dimensions() }
+}
+
+/// Describe a vertical 3-slice layout to render with.  Like [Layout], but emits only the top, center, and bottom cells.
+/// 
+/// `style` is interpreted as for the middle column of a [Layout]:  `border.top`/`border_cross.top` scale the top cap,
+/// `center` scales the center, and `border.bottom`/`border_cross.bottom` scale the bottom cap.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let thumb = VSliceLayout {
+///     src:    VSlice { outer: Rect::xywh(0, 0, 4, 6), inner_top: 2, inner_bottom: 4 },
+///     dst:    VSlice { outer: Rect::xywh(0, 0, 4, 20), inner_top: 2, inner_bottom: 18 },
+///     style:  Style::default(),
+/// }.validate().unwrap();
+/// 
+/// let [top, center, bottom] = thumb.to_array();
+/// assert_eq!(top,    (Rect::xywh(0,  0, 4,  2).validate().unwrap(), Rect::xywh(0, 0, 4, 2).validate().unwrap()));
+/// assert_eq!(center, (Rect::xywh(0,  2, 4, 16).validate().unwrap(), Rect::xywh(0, 2, 4, 2).validate().unwrap()));
+/// assert_eq!(bottom, (Rect::xywh(0, 18, 4,  2).validate().unwrap(), Rect::xywh(0, 4, 4, 2).validate().unwrap()));
+/// ```
+/// 
+/// [Layout]:   struct.Layout.html
+pub type VSliceLayout<Dst, Src> = ThreeSliceLayout<Dst, Src>;
+
+
+
+/// A 3-slice along a single axis - [HSlice] or [VSlice], or their validated forms.  See [ThreeSliceLayout].
+/// 
+/// [HSlice]:           struct.HSlice.html
+/// [VSlice]:           struct.VSlice.html
+/// [ThreeSliceLayout]: struct.ThreeSliceLayout.html
+pub trait ThreeSlice : Copy {
+    /// The coordinate type.
+    type Scalar : Scalar;
+
+    /// The validated form of this 3-slice, e.g. [ValidHSlice] for [HSlice].
+    /// 
+    /// [ValidHSlice]:  struct.ValidHSlice.html
+    /// [HSlice]:       struct.HSlice.html
+    type Valid : ThreeSlice<Scalar = Self::Scalar, Valid = Self::Valid>;
+
+    /// The leading cap, center, and trailing cap cells of the equivalent 9-slice.
+    const CELLS : [Cell; 3];
+
+    /// Validate these dimensions are non-negative / non-NaN.
+    fn validate(&self) -> Result<Self::Valid, Error>;
+
+    /// The equivalent 9-slice [Dimensions], with empty rows or columns across the unsliced axis.
+    /// 
+    /// [Dimensions]:   struct.Dimensions.html
+    fn to_dimensions(&self) -> Dimensions<Self::Scalar>;
+}
+
+/// Describe a 3-slice layout to render with.  Like [Layout], but emits only the [ThreeSlice::CELLS] of the slices'
+/// axis.  Usually spelled [HSliceLayout] or [VSliceLayout].
+/// 
+/// [Layout]:               struct.Layout.html
+/// [ThreeSlice::CELLS]:    trait.ThreeSlice.html#associatedconstant.CELLS
+/// [HSliceLayout]:         type.HSliceLayout.html
+/// [VSliceLayout]:         type.VSliceLayout.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThreeSliceLayout<Dst, Src> {
+    pub dst:    Dst,
+    pub src:    Src,
+    pub style:  Style,
+}
+
+impl<T: ThreeSlice> ThreeSliceLayout<T, T> {
+    /// Validate that dst and src contain valid (non-negative sized, non-NAN) dimensions.
+    pub fn validate(&self) -> Result<ThreeSliceLayout<T::Valid, T::Valid>, Error> {
+        Ok(ThreeSliceLayout {
+            dst:    self.dst.validate()?,
+            src:    self.src.validate()?,
+            style:  self.style,
+        })
+    }
+}
+
+impl<S: Scalar, T: ThreeSlice<Scalar = S, Valid = T>> ThreeSliceLayout<T, T> {
+    /// The equivalent 9-slice [Layout], restricted to the [ThreeSlice::CELLS] of the slices' axis.
+    /// 
+    /// [Layout]:               struct.Layout.html
+    /// [ThreeSlice::CELLS]:    trait.ThreeSlice.html#associatedconstant.CELLS
+    #[must_use] pub fn to_layout(&self) -> Layout<ValidDimensions<S>, ValidDimensions<S>> {
+        let [start, center, end] = T::CELLS;
+        let cells = self.style.cells & (CellMask::from(start) | center | end);
+        Layout { dst: self.dst.to_dimensions().debug_assert_valid(), src: self.src.to_dimensions().debug_assert_valid(), style: Style { cells, ..self.style } }
+    }
+
+    /// Enumerate the destination and source rectangles for a layout.  See [Layout::each_dst_src].
+    /// 
+    /// [Layout::each_dst_src]: struct.Layout.html#method.each_dst_src
+    pub fn each_dst_src(&self, each_dst_src: impl FnMut(&ValidRect<S>, &ValidRect<S>)) where S: RatioScalar { self.to_layout().each_dst_src(each_dst_src) }
+
+    /// Enumerate the [Tile]s for a layout.  Tiles belong to one of the [ThreeSlice::CELLS].
+    /// 
+    /// [Tile]:                 struct.Tile.html
+    /// [ThreeSlice::CELLS]:    trait.ThreeSlice.html#associatedconstant.CELLS
+    pub fn each_tile(&self, each_tile: impl FnMut(&Tile<S>)) where S: RatioScalar { self.to_layout().each_tile(each_tile) }
+
+    /// Iterate over the destination and source rectangles for a layout.  See [Layout::tiles].
+    /// 
+    /// [Layout::tiles]:    struct.Layout.html#method.tiles
+    pub fn tiles(&self) -> Tiles<S> where S: RatioScalar { self.to_layout().tiles() }
+
+    /// Get the destination and source rectangles of the [ThreeSlice::CELLS] - e.g. left, center, and right - stretched.
+    /// 
+    /// This is intended for the common all-[Stretch] case.  All three cells are reported (even if empty), so `style`
+    /// must emit them as unmirrored, unsnapped [Stretch]es for this to match [each_dst_src] - debug builds assert as much.
+    /// 
+    /// [ThreeSlice::CELLS]:    trait.ThreeSlice.html#associatedconstant.CELLS
+    /// [Stretch]:              enum.Scale.html#variant.Stretch
+    /// [each_dst_src]:         #method.each_dst_src
+    pub fn to_array(&self) -> [(ValidRect<S>, ValidRect<S>); 3] {
+        debug_assert!(self.style.stretches(&T::CELLS), "ThreeSliceLayout::to_array expects a style that stretches every cell");
+        let (dst, src) = (self.dst.to_dimensions().debug_assert_valid(), self.src.to_dimensions().debug_assert_valid());
+        T::CELLS.map(|cell| (dst.cell(cell), src.cell(cell)))
+    }
+}
+
+
+
+#[test] fn hslice_test() {
+    let layout = HSliceLayout {
+        src:    HSlice { outer: Rect::xywh(10, 0, 4, 3), inner_left: 11, inner_right: 13 },
+        dst:    HSlice { outer: Rect::xywh(0, 5, 9, 6), inner_left: 1, inner_right: 8 },
+        style:  Style::new_horizontal_vertical(Scale::Repeat(Partial::Split), Scale::Stretch),
+    }.validate().unwrap();
+
+    let mut v = Vec::new();
+    layout.each_tile(|tile| v.push((tile.cell, tile.dst.left .. tile.dst.right, tile.src.left .. tile.src.right)));
+    assert_eq!(v, vec![
+        (Cell::Left,   0..1, 10..11),
+        (Cell::Center, 1..3, 11..13),
+        (Cell::Center, 3..5, 11..13),
+        (Cell::Center, 5..7, 11..13),
+        (Cell::Center, 7..8, 11..12),
+        (Cell::Right,  8..9, 13..14),
+    ]);
+    assert_eq!(layout.tiles().count(), v.len());
+    assert!(layout.tiles().all(|(dst, src)| dst.top == 5 && dst.bottom == 11 && src.top == 0 && src.bottom == 3));
+
+    let no_center = HSliceLayout { style: Style { fill: false, ..Style::default() }, ..layout };
+    assert_eq!(no_center.tiles().count(), 2);
+
+    assert!(HSlice { outer: Rect::xywh(0, 0, 4, 3), inner_left: 3, inner_right: 2 }.validate().is_err());
+    assert!(HSlice { outer: Rect::xywh(0, 0, 4, 3), inner_left: 0, inner_right: 5 }.validate().is_err());
+    assert!(HSlice { outer: Rect::xywh(0, 0, 4, -1), inner_left: 0, inner_right: 4 }.validate().is_err());
+    assert!(HSlice { outer: Rect::xywh(0.0, 0.0, 4.0, 1.0), inner_left: f32::NAN, inner_right: 4.0 }.validate().is_err());
+}
+
+#[test] fn vslice_test() {
+    let layout = VSliceLayout {
+        src:    VSlice { outer: Rect::xywh(0, 10, 3, 4), inner_top: 11, inner_bottom: 13 },
+        dst:    VSlice { outer: Rect::xywh(5, 0, 6, 9), inner_top: 1, inner_bottom: 8 },
+        style:  Style::new_horizontal_vertical(Scale::Stretch, Scale::Repeat(Partial::Split)),
+    }.validate().unwrap();
+
+    let mut v = Vec::new();
+    layout.each_tile(|tile| v.push((tile.cell, tile.dst.top .. tile.dst.bottom, tile.src.top .. tile.src.bottom)));
+    assert_eq!(v, vec![
+        (Cell::Top,    0..1, 10..11),
+        (Cell::Center, 1..3, 11..13),
+        (Cell::Center, 3..5, 11..13),
+        (Cell::Center, 5..7, 11..13),
+        (Cell::Center, 7..8, 11..12),
+        (Cell::Bottom, 8..9, 13..14),
+    ]);
+    assert!(layout.tiles().all(|(dst, src)| dst.left == 5 && dst.right == 11 && src.left == 0 && src.right == 3));
+
+    let stretched = VSliceLayout { style: Style::default(), ..layout };
+    assert_eq!(stretched.to_array().iter().map(|(dst, src)| (dst.top .. dst.bottom, src.top .. src.bottom)).collect::<Vec<_>>(), vec![(0..1, 10..11), (1..8, 11..13), (8..9, 13..14)]);
+
+    assert!(VSlice { outer: Rect::xywh(0, 0, 3, 4), inner_top: 3, inner_bottom: 2 }.validate().is_err());
+    assert!(VSlice { outer: Rect::xywh(0, 0, 3, 4), inner_top: 0, inner_bottom: 5 }.validate().is_err());
+    assert!(VSlice { outer: Rect::xywh(0, 0, -1, 4), inner_top: 0, inner_bottom: 4 }.validate().is_err());
+}
@@ -0,0 +1,203 @@
+use super::*;
+
+/// A single destination/source rectangle pair emitted by a [Layout].
+/// 
+/// [Layout]:   struct.Layout.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tile<S: RatioScalar> {
+    /// Which logical part of the layout this tile belongs to.
+    pub cell:   Cell,
+
+    /// Where to draw.
+    pub dst:    ValidRect<S>,
+
+    /// What to draw.
+    pub src:    ValidRect<S>,
+
+    /// If `src` should be flipped along an axis when drawn into `dst`, e.g. by [Scale::MirrorRepeat].
+    /// 
+    /// [Scale::MirrorRepeat]:  enum.Scale.html#variant.MirrorRepeat
+    pub mirror: Axises<bool>,
+}
+
+impl<S: RatioScalar> Tile<S> {
+    /// The source pixel to nearest-neighbor sample for the destination pixel at `[x, y]`, with mirroring applied.
+    /// 
+    /// Samples at pixel centers, so stretching or shrinking picks evenly spaced source pixels.
+    /// 
+    /// ```rust
+    /// use nines::*;
+    /// 
+    /// let tile = Tile {
+    ///     cell:   Cell::Top,
+    ///     dst:    Rect::xywh(10, 0, 4, 1).validate().unwrap(),
+    ///     src:    Rect::xywh(1, 0, 2, 1).validate().unwrap(),
+    ///     mirror: Axises { horizontal: true, vertical: false },
+    /// };
+    /// let xs = (10 .. 14).map(|x| tile.nearest_src(x, 0)[0]).collect::<Vec<_>>();
+    /// assert_eq!(xs, vec![2, 2, 1, 1]);
+    /// ```
+    #[must_use] pub fn nearest_src(&self, x: S, y: S) -> [S; 2] {
+        let (one, two) = (S::from_count(1), S::from_count(2));
+        let axis = |v: S, dst_start: S, dst_end: S, src_start: S, src_end: S, mirror: bool| {
+            let offset = ((v - dst_start) * two + one) * (src_end - src_start) / ((dst_end - dst_start) * two);
+            src_at(src_start, src_end, offset, mirror)
+        };
+        [
+            axis(x, self.dst.left, self.dst.right,  self.src.left, self.src.right,  self.mirror.horizontal),
+            axis(y, self.dst.top,  self.dst.bottom, self.src.top,  self.src.bottom, self.mirror.vertical),
+        ]
+    }
+}
+
+
+
+/// The source coordinate `offset` into `src_start .. src_end` - counted back from the end instead, if `mirror`ed.
+/// 
+/// Integer scalars address whole pixels, so a mirrored offset of `0` is the last pixel (`src_end - 1`), not the
+/// exclusive `src_end`.  Floating point scalars are continuous, so a mirrored offset of `0` is `src_end` itself.
+pub(crate) fn src_at<S: RatioScalar>(src_start: S, src_end: S, offset: S, mirror: bool) -> S {
+    if !mirror { return src_start + offset; }
+    let (zero, one, two) = (S::default(), S::from_count(1), S::from_count(2));
+    let pixel = if one / two == zero { one } else { zero };
+    src_end - pixel - offset
+}
+
+
+
+/// Precomputed [Spans] for each source [Cell] (in [Cell::ALL] order), along whichever axises they don't depend on
+/// the destination size - i.e. the fixed size border columns and rows.  See [LayoutPlan].
+pub(crate) type CellSpans<S> = [Axises<Option<Spans<S>>>; 9];
+
+
+
+/// An iterator over the destination and source rectangles of a [Layout].  See [Layout::tiles].
+/// 
+/// [Layout]:           struct.Layout.html
+/// [Layout::tiles]:    struct.Layout.html#method.tiles
+#[derive(Clone, Debug)]
+pub struct Tiles<S: RatioScalar> {
+    dst:        ValidDimensions<S>,
+    src:        ValidDimensions<S>,
+    style:      Style,
+    cells:      [Cell; 9],
+    next_cell:  usize,
+    counts:     [usize; 9], // tiles per entry of `cells`, counted once up front, since `skip_empty` makes counting walk the spans
+    current:    Option<CellTiles<S>>,
+}
+
+/// The remaining tiles of a single cell.
+#[derive(Clone, Debug)]
+struct CellTiles<S: RatioScalar> {
+    cell:   Cell,
+    xs:     Spans<S>,
+    x:      Spans<S>,
+    y:      Option<Span<S>>,
+    ys:     Spans<S>,
+    skip_empty: bool,
+    place:  Axises<Place<S>>,
+    remaining: usize,
+}
+
+/// How to position destination spans along one axis, after laying them out.
+#[derive(Clone, Copy, Debug)]
+struct Place<S: RatioScalar> {
+    flip:   Option<S>, // `start + end` of the destination's outer rect to reflect spans across, if mirroring
+    snap:   Snap,
+    origin: S,
+}
+
+impl<S: RatioScalar> Place<S> {
+    fn span(&self, span: Span<S>) -> Span<S> {
+        let dst = match self.flip { Some(sum) => sum - span.dst.end .. sum - span.dst.start, None => span.dst };
+        let dst = self.snap.snap(dst.start, self.origin) .. self.snap.snap(dst.end, self.origin);
+        Span { dst, mirror: span.mirror != self.flip.is_some(), ..span }
+    }
+}
+
+impl<S: RatioScalar> Tiles<S> {
+    pub(crate) fn new(dst: ValidDimensions<S>, src: ValidDimensions<S>, style: Style) -> Self {
+        let mut tiles = Self { dst, src, style, cells: style.order.cells(), next_cell: 0, counts: [0; 9], current: None };
+        for i in 0 .. 9 {
+            let cell = tiles.cells[i];
+            if style.emits(cell) { tiles.counts[i] = tiles.cell_tiles(cell, 0, None).count(); }
+        }
+        tiles
+    }
+
+    pub(crate) fn next_tile(&mut self) -> Option<Tile<S>> {
+        self.next_tile_cached(None)
+    }
+
+    /// Like [next_tile](#method.next_tile), but reusing `cache`d spans (indexed by source [Cell]) where they apply.
+    pub(crate) fn next_tile_cached(&mut self, cache: Option<&CellSpans<S>>) -> Option<Tile<S>> {
+        loop {
+            if let Some(current) = self.current.as_mut() {
+                loop {
+                    let (skip_empty, place) = (current.skip_empty, current.place);
+                    if let Some(y) = current.y.as_ref() {
+                        if let Some(x) = current.x.by_ref().map(|x| place.horizontal.span(x)).find(|x| !skip_empty || !x.is_empty()) {
+                            current.remaining -= 1;
+                            return Some(Tile {
+                                cell:   current.cell,
+                                dst:    Rect::<S>::from([x.dst, y.dst.clone()]).debug_assert_valid(),
+                                src:    Rect::<S>::from([x.src, y.src.clone()]).debug_assert_valid(),
+                                mirror: Axises { horizontal: x.mirror, vertical: y.mirror },
+                            });
+                        }
+                    }
+                    current.y = current.ys.by_ref().map(|y| place.vertical.span(y)).find(|y| !skip_empty || !y.is_empty());
+                    if current.y.is_none() { break; }
+                    current.x = current.xs.clone();
+                }
+            }
+
+            let cell = *self.cells.get(self.next_cell)?;
+            let remaining = self.counts[self.next_cell];
+            self.next_cell += 1;
+            self.current = if self.style.emits(cell) { Some(self.cell_tiles(cell, remaining, cache)) } else { None };
+        }
+    }
+
+    fn cell_tiles(&self, cell: Cell, remaining: usize, cache: Option<&CellSpans<S>>) -> CellTiles<S> {
+        let art = self.style.src_cell(cell);
+        let dst = self.dst.cell(art);
+        let src = self.src.cell(art);
+        let scale = self.style.cell_scale(art);
+        let cached = cache.map(|cache| &cache[art as usize]);
+        let xs = cached.and_then(|c| c.horizontal.as_ref()?.rebased(dst.left..dst.right)).unwrap_or_else(||
+            scale.horizontal.spans_with(dst.left..dst.right, src.left..src.right, self.style.remainder).saturating(self.style.saturating));
+        let ys = cached.and_then(|c| c.vertical.as_ref()?.rebased(dst.top..dst.bottom)).unwrap_or_else(||
+            scale.vertical  .spans_with(dst.top..dst.bottom, src.top..src.bottom, self.style.remainder).saturating(self.style.saturating));
+        let (outer, snap) = (self.dst.outer, self.style.snap);
+        let place = Axises {
+            horizontal: Place { flip: if self.style.mirror_horizontal { Some(outer.left + outer.right) } else { None }, snap, origin: outer.left },
+            vertical:   Place { flip: None, snap, origin: outer.top },
+        };
+        CellTiles { cell, x: xs.clone(), xs, y: None, ys, skip_empty: self.style.skip_empty, place, remaining }
+    }
+}
+
+impl<S: RatioScalar> CellTiles<S> {
+    /// Count every tile of this cell, ignoring `remaining`.
+    fn count(&self) -> usize {
+        let skip_empty = self.skip_empty;
+        let count = |spans: &Spans<S>, place: &Place<S>| if skip_empty { spans.clone().filter(|span| !place.span(span.clone()).is_empty()).count() } else { spans.len() };
+        count(&self.ys, &self.place.vertical) * count(&self.xs, &self.place.horizontal)
+    }
+}
+
+impl<S: RatioScalar> Iterator for Tiles<S> {
+    type Item = (ValidRect<S>, ValidRect<S>);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_tile().map(|tile| (tile.dst, tile.src))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let current = self.current.as_ref().map_or(0, |c| c.remaining);
+        let remaining = self.counts[self.next_cell..].iter().sum::<usize>();
+        (current + remaining, Some(current + remaining))
+    }
+}
+
+impl<S: RatioScalar> ExactSizeIterator for Tiles<S> {}
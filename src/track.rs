@@ -0,0 +1,183 @@
+use super::*;
+use crate::fill::fraction_of;
+
+/// Which axis a [Track] runs along.
+/// 
+/// [Track]:    struct.Track.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The track runs left to right, e.g. a horizontal slider.
+    Horizontal,
+
+    /// The track runs top to bottom, e.g. a vertical scrollbar.
+    Vertical,
+}
+
+impl core::default::Default for Orientation { fn default() -> Self { Orientation::Horizontal } }
+
+/// Which part of a [Track] a tile belongs to.
+/// 
+/// [Track]:    struct.Track.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TrackPart {
+    /// The 3-slice background the thumb slides along.
+    Track,
+
+    /// The draggable thumb, drawn after the track.
+    Thumb,
+}
+
+/// A slider or scrollbar:  a 3-slice track, with a thumb positioned along it by a value.
+/// 
+/// 3-slice skins can be passed via [ValidHSlice] / [ValidVSlice]'s conversions into [ValidDimensions].
+/// Only the 3 cells along `orientation` are emitted for the track - the thumb may be a full 9-slice.
+/// 
+/// ### Example
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// let track = HSlice { outer: Rect::xywh(0, 0, 6, 4), inner_left: 2, inner_right: 4 }.validate().unwrap();
+/// let thumb = Dimensions { outer: Rect::xywh(0, 4, 6, 6), inner: Rect::xywh(2, 6, 2, 2) }.validate().unwrap();
+/// let slider = Track {
+///     orientation:    Orientation::Horizontal,
+///     track:          track.into(),
+///     thumb,
+///     thumb_length:   6,
+///     style:          Style::default(),
+/// };
+/// 
+/// let dst = Rect::xywh(0, 0, 106, 4).validate().unwrap();
+/// assert_eq!(slider.thumb_rect(&dst, 25, 100).unwrap(), Rect::xywh(25, 0, 6, 4));
+/// 
+/// let mut parts = Vec::new();
+/// slider.each_dst_src(&dst, 25, 100, |part, _dst, _src| parts.push(part)).unwrap();
+/// assert_eq!(parts.iter().filter(|p| **p == TrackPart::Track).count(), 3);
+/// assert_eq!(parts.iter().filter(|p| **p == TrackPart::Thumb).count(), 9);
+/// ```
+/// 
+/// [ValidHSlice]:      struct.ValidHSlice.html
+/// [ValidVSlice]:      struct.ValidVSlice.html
+/// [ValidDimensions]:  struct.ValidDimensions.html
+#[derive(Clone, Copy, Debug)]
+pub struct Track<S: RatioScalar> {
+    /// Which axis the track runs along.
+    pub orientation:    Orientation,
+
+    /// The track's skin.
+    pub track:          ValidDimensions<S>,
+
+    /// The thumb's skin.
+    pub thumb:          ValidDimensions<S>,
+
+    /// The destination length of the thumb along `orientation` - e.g. its natural size for a slider, or proportional
+    /// to the visible fraction of content for a scrollbar.  The thumb always spans the full width of the track.
+    pub thumb_length:   S,
+
+    /// How to scale both the track and the thumb.
+    pub style:          Style,
+}
+
+impl<S: RatioScalar> Track<S> {
+    /// Get where the thumb goes on a track occupying `dst`, for a value of `value / max`.  `value` is clamped to `0 ..= max`.
+    /// 
+    /// Can return Err if `thumb_length` is negative, NaN, or longer than the track.
+    pub fn thumb_rect(&self, dst: &ValidRect<S>, value: S, max: S) -> Result<ValidRect<S>, Error> {
+        let zero = S::default();
+        let length = match self.orientation { Orientation::Horizontal => dst.width(), Orientation::Vertical => dst.height() };
+        if !(zero <= self.thumb_length) { return err("Expected 0 ≤ thumb_length"); }
+        if !(self.thumb_length <= length) { return err("Expected thumb_length ≤ track length"); }
+
+        let start = fraction_of(length - self.thumb_length, value, max);
+        let mut r = **dst;
+        match self.orientation {
+            Orientation::Horizontal => { r.left = dst.left + start; r.right  = r.left + self.thumb_length; },
+            Orientation::Vertical   => { r.top  = dst.top  + start; r.bottom = r.top  + self.thumb_length; },
+        }
+        Ok(r.debug_assert_valid())
+    }
+
+    /// Get the [Layout] of the track, occupying `dst`.
+    /// 
+    /// Can return Err if the track's borders don't fit within `dst`.
+    /// 
+    /// [Layout]:   struct.Layout.html
+    pub fn track_layout(&self, dst: &ValidRect<S>) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Error> {
+        let cells = match self.orientation {
+            Orientation::Horizontal => CellMask::from(Cell::Left) | Cell::Center | Cell::Right,
+            Orientation::Vertical   => CellMask::from(Cell::Top)  | Cell::Center | Cell::Bottom,
+        };
+        Ok(Layout { dst: self.track.with_outer(*dst)?, src: self.track, style: Style { cells: self.style.cells & cells, ..self.style } })
+    }
+
+    /// Get the [Layout] of the thumb, for a track occupying `dst` and a value of `value / max`.
+    /// 
+    /// Can return Err if the thumb doesn't fit per [thumb_rect], or if the thumb's borders don't fit.
+    /// 
+    /// [Layout]:       struct.Layout.html
+    /// [thumb_rect]:   #method.thumb_rect
+    pub fn thumb_layout(&self, dst: &ValidRect<S>, value: S, max: S) -> Result<Layout<ValidDimensions<S>, ValidDimensions<S>>, Error> {
+        Ok(Layout { dst: self.thumb.with_outer(self.thumb_rect(dst, value, max)?)?, src: self.thumb, style: self.style })
+    }
+
+    /// Enumerate the [Tile]s of the track, followed by those of the thumb.
+    /// 
+    /// Can return Err (without enumerating anything) per [track_layout] and [thumb_layout].
+    /// 
+    /// [Tile]:         struct.Tile.html
+    /// [track_layout]: #method.track_layout
+    /// [thumb_layout]: #method.thumb_layout
+    pub fn each_tile(&self, dst: &ValidRect<S>, value: S, max: S, mut each_tile: impl FnMut(TrackPart, &Tile<S>)) -> Result<(), Error> {
+        let (track, thumb) = (self.track_layout(dst)?, self.thumb_layout(dst, value, max)?);
+        track.each_tile(|tile| each_tile(TrackPart::Track, tile));
+        thumb.each_tile(|tile| each_tile(TrackPart::Thumb, tile));
+        Ok(())
+    }
+
+    /// Enumerate the destination and source rectangles of the track, followed by those of the thumb.
+    /// 
+    /// Can return Err (without enumerating anything) per [track_layout] and [thumb_layout].
+    /// 
+    /// [track_layout]: #method.track_layout
+    /// [thumb_layout]: #method.thumb_layout
+    pub fn each_dst_src(&self, dst: &ValidRect<S>, value: S, max: S, mut each_dst_src: impl FnMut(TrackPart, &ValidRect<S>, &ValidRect<S>)) -> Result<(), Error> {
+        self.each_tile(dst, value, max, |part, tile| each_dst_src(part, &tile.dst, &tile.src))
+    }
+}
+
+
+
+#[test] fn track_test() {
+    let track = VSlice { outer: Rect::xywh(0.0, 0.0, 4.0, 6.0), inner_top: 2.0, inner_bottom: 4.0 }.validate().unwrap();
+    let thumb = VSlice { outer: Rect::xywh(4.0, 0.0, 4.0, 4.0), inner_top: 1.0, inner_bottom: 3.0 }.validate().unwrap();
+    let scrollbar = Track {
+        orientation:    Orientation::Vertical,
+        track:          track.into(),
+        thumb:          thumb.into(),
+        thumb_length:   25.0,
+        style:          Style { skip_empty: true, ..Style::default() },
+    };
+    let dst = Rect::xywh(10.0, 0.0, 4.0, 100.0).validate().unwrap();
+
+    assert_eq!(scrollbar.thumb_rect(&dst,  0.0, 1.0).unwrap(), Rect::xywh(10.0,  0.0, 4.0, 25.0));
+    assert_eq!(scrollbar.thumb_rect(&dst,  0.5, 1.0).unwrap(), Rect::xywh(10.0, 37.5, 4.0, 25.0));
+    assert_eq!(scrollbar.thumb_rect(&dst,  2.0, 1.0).unwrap(), Rect::xywh(10.0, 75.0, 4.0, 25.0));
+    assert_eq!(scrollbar.thumb_rect(&dst, -1.0, 1.0).unwrap(), Rect::xywh(10.0,  0.0, 4.0, 25.0));
+
+    let mut tiles = Vec::new();
+    scrollbar.each_tile(&dst, 1.0, 1.0, |part, tile| tiles.push((part, tile.cell, Rect::from(tile.dst)))).unwrap();
+    assert_eq!(tiles, vec![
+        (TrackPart::Track, Cell::Top,       Rect::xywh(10.0,  0.0, 4.0,  2.0)),
+        (TrackPart::Track, Cell::Center,    Rect::xywh(10.0,  2.0, 4.0, 96.0)),
+        (TrackPart::Track, Cell::Bottom,    Rect::xywh(10.0, 98.0, 4.0,  2.0)),
+        (TrackPart::Thumb, Cell::Top,       Rect::xywh(10.0, 75.0, 4.0,  1.0)),
+        (TrackPart::Thumb, Cell::Center,    Rect::xywh(10.0, 76.0, 4.0, 23.0)),
+        (TrackPart::Thumb, Cell::Bottom,    Rect::xywh(10.0, 99.0, 4.0,  1.0)),
+    ]);
+
+    let too_long = Track { thumb_length: 101.0, ..scrollbar };
+    assert!(too_long.each_tile(&dst, 0.0, 1.0, |_, _| panic!()).is_err());
+    let too_short = Track { thumb_length: 1.0, ..scrollbar };
+    assert!(too_short.each_tile(&dst, 0.0, 1.0, |_, _| panic!()).is_err());
+}
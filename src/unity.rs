@@ -0,0 +1,57 @@
+//! [Unity](https://docs.unity3d.com/Manual/9SliceSprites.html) sprite border import.
+//! 
+//! Unity texture coordinates have their origin in the bottom left (+y is up), while nines uses a top left origin (+y is
+//! down), so converting requires the height of the sprite's texture.
+
+use super::*;
+
+/// Convert a Unity sprite's `rect` (`Sprite.rect` - `[x, y, width, height]`) and `border` (`Sprite.border` -
+/// `[left, bottom, right, top]`), both in pixels, into top-left-origin [Dimensions] in pixels.
+/// 
+/// ```rust
+/// use nines::*;
+/// 
+/// // A 32x16 sprite at the bottom left of a 64x64 texture, with 4px side borders and a 2px top/bottom border.
+/// let src = unity::from_sprite(64.0, [0.0, 0.0, 32.0, 16.0], [4.0, 2.0, 4.0, 2.0]).unwrap();
+/// assert_eq!(src.outer, Rect { left: 0.0, right: 32.0, top: 48.0, bottom: 64.0 });
+/// assert_eq!(src.inner, Rect { left: 4.0, right: 28.0, top: 50.0, bottom: 62.0 });
+/// ```
+/// 
+/// Returns `Err` if the rect or borders are negative (or NaN), or if the borders overlap.
+/// 
+/// [Dimensions]:   ../struct.Dimensions.html
+pub fn from_sprite(texture_height: f32, rect: [f32; 4], border: [f32; 4]) -> Result<ValidDimensions<f32>, Error> {
+    let [x, y, width, height] = rect;
+    let [left, bottom, right, top] = border;
+    if !(0.0 <= width) || !(0.0 <= height) { return err("Expected non-negative sprite rect size"); }
+    if !border.iter().all(|b| 0.0 <= *b) { return err("Expected non-negative sprite borders"); }
+
+    let outer = Rect { left: x, right: x + width, top: texture_height - (y + height), bottom: texture_height - y };
+    Dimensions {
+        outer,
+        inner: Rect { left: outer.left + left, right: outer.right - right, top: outer.top + top, bottom: outer.bottom - bottom },
+    }.validate()
+}
+
+/// Convert top-left-origin [Dimensions] back into a Unity sprite's `(rect, border)`.  See [from_sprite].
+/// 
+/// [Dimensions]:   ../struct.Dimensions.html
+/// [from_sprite]:  fn.from_sprite.html
+pub fn to_sprite(texture_height: f32, src: &ValidDimensions<f32>) -> ([f32; 4], [f32; 4]) {
+    let outer = src.outer;
+    let b = src.borders();
+    ([outer.left, texture_height - outer.bottom, outer.right - outer.left, outer.bottom - outer.top], [b.left, b.bottom, b.right, b.top])
+}
+
+
+
+#[test] fn unity_test() {
+    let src = from_sprite(128.0, [16.0, 8.0, 32.0, 24.0], [1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(src.outer, Rect { left: 16.0, right: 48.0, top: 96.0, bottom: 120.0 });
+    assert_eq!(src.borders(), Borders { left: 1.0, bottom: 2.0, right: 3.0, top: 4.0 });
+    assert_eq!(to_sprite(128.0, &src), ([16.0, 8.0, 32.0, 24.0], [1.0, 2.0, 3.0, 4.0]));
+
+    assert!(from_sprite(128.0, [0.0, 0.0, 4.0, 4.0], [2.0, 0.0, 3.0, 0.0]).is_err());
+    assert!(from_sprite(128.0, [0.0, 0.0, -4.0, 4.0], [0.0; 4]).is_err());
+    assert!(from_sprite(128.0, [0.0, 0.0, 4.0, 4.0], [f32::NAN, 0.0, 0.0, 0.0]).is_err());
+}
@@ -0,0 +1,127 @@
+//! [wgpu](https://docs.rs/wgpu/) vertex and index buffer helpers.
+//! 
+//! Vertices are [mesh::Vertex]`<f32>`s:  `@location(0) dst: vec2<f32>` in destination space, and
+//! `@location(1) uv: vec2<f32>` normalized by the texture size.  Indices are `u32` triangle lists.
+//! 
+//! ```rust,no_run
+//! use nines::*;
+//! # fn draw(device: &::wgpu_crate::Device, pass: &mut ::wgpu_crate::RenderPass, layouts: &[Layout<ValidDimensions<f32>, ValidDimensions<f32>>]) {
+//! let mut batcher = nines::wgpu::NineSliceBatcher::new();
+//! for layout in layouts { batcher.push(layout, [256.0, 256.0]); }
+//! let (vertices, indices) = batcher.create_buffers(device);
+//! pass.set_vertex_buffer(0, vertices.slice(..));
+//! pass.set_index_buffer(indices.slice(..), nines::wgpu::INDEX_FORMAT);
+//! pass.draw_indexed(0 .. batcher.index_count(), 0, 0 .. 1);
+//! # }
+//! ```
+//! 
+//! [mesh::Vertex]: ../mesh/struct.Vertex.html
+
+use crate::*;
+use ::wgpu_crate::util::DeviceExt;
+use alloc::vec::Vec;
+
+const ATTRIBUTES : [::wgpu_crate::VertexAttribute; 2] = ::wgpu_crate::vertex_attr_array![0 => Float32x2, 1 => Float32x2];
+
+/// The [mesh::Vertex]`<f32>` buffer layout, for `wgpu::VertexState::buffers`.
+/// 
+/// [mesh::Vertex]: ../mesh/struct.Vertex.html
+pub const VERTEX_LAYOUT : ::wgpu_crate::VertexBufferLayout<'static> = ::wgpu_crate::VertexBufferLayout {
+    array_stride:   core::mem::size_of::<mesh::Vertex<f32>>() as ::wgpu_crate::BufferAddress,
+    step_mode:      ::wgpu_crate::VertexStepMode::Vertex,
+    attributes:     &ATTRIBUTES,
+};
+
+/// The index format of [NineSliceBatcher]'s index buffer.
+/// 
+/// [NineSliceBatcher]: struct.NineSliceBatcher.html
+pub const INDEX_FORMAT : ::wgpu_crate::IndexFormat = ::wgpu_crate::IndexFormat::Uint32;
+
+/// Accumulates the meshes of many [Layout]s into a single vertex/index buffer pair, for drawing in one call.
+/// 
+/// [Layout]:   ../struct.Layout.html
+#[derive(Clone, Debug, Default)]
+pub struct NineSliceBatcher {
+    vertices:   Vec<mesh::Vertex<f32>>,
+    indices:    Vec<u32>,
+}
+
+impl NineSliceBatcher {
+    #[must_use] pub fn new() -> Self { Self::default() }
+
+    /// Append the tiles of `layout`, normalizing source coordinates by `texture_size` (`[width, height]` in texels).
+    pub fn push(&mut self, layout: &Layout<ValidDimensions<f32>, ValidDimensions<f32>>, texture_size: [f32; 2]) {
+        let [w, h] = texture_size;
+        let mut tiles = layout.tiles();
+        while let Some(tile) = tiles.next_tile() {
+            let base = self.vertices.len() as u32;
+            self.vertices.extend(mesh::quad_vertices(&tile).iter().map(|v| mesh::Vertex { dst: v.dst, src: [v.src[0] / w, v.src[1] / h] }));
+            self.indices.extend(mesh::QUAD_INDICES.iter().map(|i| base + u32::from(*i)));
+        }
+    }
+
+    /// Remove all accumulated tiles, keeping the allocations for reuse.
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+
+    #[must_use] pub fn is_empty(&self) -> bool { self.indices.is_empty() }
+    #[must_use] pub fn vertices(&self) -> &[mesh::Vertex<f32>] { &self.vertices }
+    #[must_use] pub fn indices(&self) -> &[u32] { &self.indices }
+
+    /// The number of indices to pass to `RenderPass::draw_indexed`.
+    #[must_use] pub fn index_count(&self) -> u32 { self.indices.len() as u32 }
+
+    /// Create new `(vertex, index)` buffers containing the accumulated tiles.
+    pub fn create_buffers(&self, device: &::wgpu_crate::Device) -> (::wgpu_crate::Buffer, ::wgpu_crate::Buffer) {
+        let vertices = device.create_buffer_init(&::wgpu_crate::util::BufferInitDescriptor {
+            label:      Some("nines vertices"),
+            contents:   bytemuck::cast_slice(&self.vertices),
+            usage:      ::wgpu_crate::BufferUsages::VERTEX | ::wgpu_crate::BufferUsages::COPY_DST,
+        });
+        let indices = device.create_buffer_init(&::wgpu_crate::util::BufferInitDescriptor {
+            label:      Some("nines indices"),
+            contents:   bytemuck::cast_slice(&self.indices),
+            usage:      ::wgpu_crate::BufferUsages::INDEX | ::wgpu_crate::BufferUsages::COPY_DST,
+        });
+        (vertices, indices)
+    }
+
+    /// Overwrite the start of existing `COPY_DST` buffers with the accumulated tiles.
+    /// 
+    /// Returns `Err` (without writing anything) if either buffer is too small.
+    pub fn write_buffers(&self, queue: &::wgpu_crate::Queue, vertices: &::wgpu_crate::Buffer, indices: &::wgpu_crate::Buffer) -> Result<(), Error> {
+        let (vertex_bytes, index_bytes) : (&[u8], &[u8]) = (bytemuck::cast_slice(&self.vertices), bytemuck::cast_slice(&self.indices));
+        if vertices.size() < vertex_bytes.len() as u64 { return err("Vertex buffer too small for batched tiles"); }
+        if indices .size() < index_bytes .len() as u64 { return err("Index buffer too small for batched tiles"); }
+        queue.write_buffer(vertices, 0, vertex_bytes);
+        queue.write_buffer(indices,  0, index_bytes);
+        Ok(())
+    }
+}
+
+
+
+#[test] fn wgpu_batcher_test() {
+    let layout = Layout {
+        src: Dimensions { outer: Rect::xywh(0.0, 0.0, 4.0, 4.0), inner: Rect::xywh(1.0, 1.0, 2.0, 2.0) },
+        dst: Dimensions { outer: Rect::xywh(0.0, 0.0, 8.0, 8.0), inner: Rect::xywh(1.0, 1.0, 6.0, 6.0) },
+        style: Style::default(),
+    }.validate().unwrap();
+
+    let mut batcher = NineSliceBatcher::new();
+    assert!(batcher.is_empty());
+    batcher.push(&layout, [4.0, 8.0]);
+    batcher.push(&layout, [4.0, 8.0]);
+    assert_eq!(batcher.vertices().len(), 2 * 9 * 4);
+    assert_eq!(batcher.index_count(), 2 * 9 * 6);
+    assert_eq!(&batcher.indices()[9*6 .. 9*6+6], &[36, 37, 38, 38, 37, 39]);
+    assert_eq!(batcher.vertices()[4*9 + 3], mesh::Vertex { dst: [1.0, 1.0], src: [0.25, 0.125] });
+
+    assert_eq!(VERTEX_LAYOUT.array_stride, 16);
+    assert_eq!(VERTEX_LAYOUT.attributes[1].offset, 8);
+
+    batcher.clear();
+    assert!(batcher.is_empty());
+}